@@ -2,4 +2,15 @@ library;
 
 export 'src/rust/api/simple.dart';
 export 'src/rust/api/search.dart';
+export 'src/rust/api/query.dart';
+export 'src/rust/api/sort.dart';
+export 'src/rust/api/aggregation.dart';
+export 'src/rust/api/facet.dart';
+export 'src/rust/api/geo.dart';
+export 'src/rust/api/group.dart';
+export 'src/rust/api/more_like_this.dart';
+export 'src/rust/api/explain.dart';
+export 'src/rust/api/scoring.dart';
+export 'src/rust/api/projection.dart';
+export 'src/rust/api/variant.dart';
 export 'src/rust/frb_generated.dart' show RustLib;
@@ -0,0 +1,186 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'aggregation.freezed.dart';
+
+            // These functions are ignored because they are not marked as `pub`: `aggregation_collector`, `entries`, `key_to_string`, `request_to_json`, `to_aggregation_results`, `to_aggregation_value`, `to_buckets`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+
+
+            
+
+            /// 버킷 집계 결과 항목
+class AggregationBucket  {
+                /// 버킷 키 (terms 는 값, histogram 은 구간 시작 값, date_histogram 은 RFC 3339 날짜)
+final String key;
+final BigInt docCount;
+/// range 집계의 구간
+final double? from;
+final double? to;
+
+                const AggregationBucket({required this.key ,required this.docCount ,this.from ,this.to ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => key.hashCode^docCount.hashCode^from.hashCode^to.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is AggregationBucket &&
+                runtimeType == other.runtimeType
+                && key == other.key&& docCount == other.docCount&& from == other.from&& to == other.to;
+        
+            }
+
+@freezed
+                sealed class AggregationKind with _$AggregationKind  {
+                    const AggregationKind._();
+
+                     /// 값별 문서 수 (문서 수 내림차순 상위 `size` 개)
+const factory AggregationKind.terms({   required String field ,  required int size , }) = AggregationKind_Terms;
+ /// 지정한 구간별 문서 수 (from 이상, to 미만)
+const factory AggregationKind.range({   required String field ,  required List<AggregationRange> ranges , }) = AggregationKind_Range;
+ /// 고정 간격 숫자 히스토그램
+const factory AggregationKind.histogram({   required String field ,  required double interval , }) = AggregationKind_Histogram;
+ /// 고정 간격 날짜 히스토그램 (예: "1d", "12h", "30m")
+const factory AggregationKind.dateHistogram({   required String field ,  required String fixedInterval , }) = AggregationKind_DateHistogram;
+ const factory AggregationKind.min({   required String field , }) = AggregationKind_Min;
+ const factory AggregationKind.max({   required String field , }) = AggregationKind_Max;
+ const factory AggregationKind.avg({   required String field , }) = AggregationKind_Avg;
+ /// 개수, 합계, 최솟값, 최댓값, 평균
+const factory AggregationKind.stats({   required String field , }) = AggregationKind_Stats;
+
+                    
+
+                    
+                }
+
+/// 범위 집계 구간 (None 이면 해당 방향으로 제한 없음)
+class AggregationRange  {
+                final double? from;
+final double? to;
+
+                const AggregationRange({this.from ,this.to ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => from.hashCode^to.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is AggregationRange &&
+                runtimeType == other.runtimeType
+                && from == other.from&& to == other.to;
+        
+            }
+
+/// 집계 요청 (결과는 같은 `name` 으로 요청 순서대로 반환됩니다)
+class AggregationRequest  {
+                final String name;
+final AggregationKind kind;
+
+                const AggregationRequest({required this.name ,required this.kind ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => name.hashCode^kind.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is AggregationRequest &&
+                runtimeType == other.runtimeType
+                && name == other.name&& kind == other.kind;
+        
+            }
+
+/// 집계 결과
+class AggregationResult  {
+                final String name;
+final AggregationValue value;
+
+                const AggregationResult({required this.name ,required this.value ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => name.hashCode^value.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is AggregationResult &&
+                runtimeType == other.runtimeType
+                && name == other.name&& value == other.value;
+        
+            }
+
+/// stats 집계 결과
+class AggregationStats  {
+                final BigInt count;
+final double sum;
+final double? min;
+final double? max;
+final double? avg;
+
+                const AggregationStats({required this.count ,required this.sum ,this.min ,this.max ,this.avg ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => count.hashCode^sum.hashCode^min.hashCode^max.hashCode^avg.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is AggregationStats &&
+                runtimeType == other.runtimeType
+                && count == other.count&& sum == other.sum&& min == other.min&& max == other.max&& avg == other.avg;
+        
+            }
+
+@freezed
+                sealed class AggregationValue with _$AggregationValue  {
+                    const AggregationValue._();
+
+                     /// terms, range, histogram, date_histogram
+const factory AggregationValue.buckets(  List<AggregationBucket> field0,) = AggregationValue_Buckets;
+ /// min, max, avg (값이 있는 문서가 없으면 None)
+const factory AggregationValue.metric([  double? field0,]) = AggregationValue_Metric;
+ const factory AggregationValue.stats(  AggregationStats field0,) = AggregationValue_Stats;
+
+                    
+
+                    
+                }
+            
\ No newline at end of file
@@ -0,0 +1,47 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `to_score_explanation`, `write_pretty`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `ExplanationFields`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`, `from`
+
+
+            
+
+            /// 점수 계산 설명 트리 (BM25 구성 요소, 색인어 빈도, 필드 길이, 가중치 등)
+class ScoreExplanation  {
+                final double value;
+final String description;
+final List<ScoreExplanation> details;
+/// 추가 정보 (예: 매칭된 색인어)
+final List<String> context;
+
+                const ScoreExplanation({required this.value ,required this.description ,required this.details ,required this.context ,});
+
+                /// 들여쓰기한 텍스트로 출력합니다 (디버그 로그용)
+ Future<String>  toPrettyString()=>RustLib.instance.api.crateApiExplainScoreExplanationToPrettyString(that: this, );
+
+
+                
+
+                
+        @override
+        int get hashCode => value.hashCode^description.hashCode^details.hashCode^context.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ScoreExplanation &&
+                runtimeType == other.runtimeType
+                && value == other.value&& description == other.description&& details == other.details&& context == other.context;
+        
+            }
+            
\ No newline at end of file
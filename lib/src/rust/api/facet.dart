@@ -0,0 +1,67 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `category_collector`, `metadata_categories`, `parse_category`, `to_category_counts`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `fmt`, `fmt`
+
+
+            
+
+            /// 카테고리별 문서 수
+class CategoryCount  {
+                final String path;
+final BigInt count;
+
+                const CategoryCount({required this.path ,required this.count ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => path.hashCode^count.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CategoryCount &&
+                runtimeType == other.runtimeType
+                && path == other.path&& count == other.count;
+        
+            }
+
+/// 하위 카테고리 문서 수 집계 결과
+class CategoryCounts  {
+                /// 요청한 상위 카테고리 경로 (예: "/travel/asia")
+final String parent;
+/// 바로 아래 하위 카테고리별 문서 수 (경로 순)
+final List<CategoryCount> children;
+
+                const CategoryCounts({required this.parent ,required this.children ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => parent.hashCode^children.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CategoryCounts &&
+                runtimeType == other.runtimeType
+                && parent == other.parent&& children == other.children;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,57 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'geo.freezed.dart';
+
+            // These functions are ignored because they are not marked as `pub`: `bounds`, `candidate_query`, `contains`, `distance_km`, `is_valid`, `metadata_location`, `new`, `open`, `point`, `validate`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `GeoColumns`, `GeoDocSet`, `GeoQuery`, `GeoWeight`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `advance`, `clone`, `clone`, `clone`, `doc`, `eq`, `explain`, `fmt`, `fmt`, `fmt`, `scorer`, `size_hint`, `weight`
+
+
+            
+
+            @freezed
+                sealed class GeoFilter with _$GeoFilter  {
+                    const GeoFilter._();
+
+                     /// 북서쪽(top_left)과 남동쪽(bottom_right) 꼭짓점으로 정한 영역
+/// (top_left.lon > bottom_right.lon 이면 날짜 변경선을 넘는 영역)
+const factory GeoFilter.boundingBox({   required GeoPoint topLeft ,  required GeoPoint bottomRight , }) = GeoFilter_BoundingBox;
+ /// 중심에서 distance_km 이내
+const factory GeoFilter.radius({   required GeoPoint center ,  required double distanceKm , }) = GeoFilter_Radius;
+
+                    
+
+                    
+                }
+
+/// 위도/경도 좌표 (도 단위)
+class GeoPoint  {
+                final double lat;
+final double lon;
+
+                const GeoPoint({required this.lat ,required this.lon ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => lat.hashCode^lon.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is GeoPoint &&
+                runtimeType == other.runtimeType
+                && lat == other.lat&& lon == other.lon;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,45 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `new`, `new`, `push_top`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `CollectedGroup`, `DistinctValueCollector`, `DistinctValueSegmentCollector`, `DistinctValue`, `GroupCollector`, `GroupKey`, `GroupSegmentCollector`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `collect`, `collect`, `eq`, `fmt`, `for_segment`, `for_segment`, `harvest`, `harvest`, `hash`, `merge_fruits`, `merge_fruits`, `requires_scoring`, `requires_scoring`
+
+
+            
+
+            /// 그룹별 검색 옵션
+class GroupByOptions  {
+                /// 그룹으로 묶을 fast field 이름 또는 메타데이터 JSON 경로 (예: `metadata.city`)
+final String field;
+/// 반환할 그룹 수 (그룹의 최상위 문서 순)
+final BigInt groupsLimit;
+/// 그룹마다 반환할 문서 수
+final BigInt hitsPerGroup;
+
+                const GroupByOptions({required this.field ,required this.groupsLimit ,required this.hitsPerGroup ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => field.hashCode^groupsLimit.hashCode^hitsPerGroup.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is GroupByOptions &&
+                runtimeType == other.runtimeType
+                && field == other.field&& groupsLimit == other.groupsLimit&& hitsPerGroup == other.hitsPerGroup;
+        
+            }
+            
\ No newline at end of file
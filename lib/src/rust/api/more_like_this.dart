@@ -0,0 +1,61 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'more_like_this.freezed.dart';
+
+            // These functions are ignored because they are not marked as `pub`: `more_like_this_query`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `fmt`, `fmt`
+
+
+            
+
+            /// 비슷한 문서 검색 옵션 (TF-IDF 로 기준의 중요 색인어를 골라 검색합니다)
+class MoreLikeThisOptions  {
+                /// 이 수보다 적은 문서에 나오는 색인어는 무시합니다
+final BigInt minDocFreq;
+/// 기준에서 이 횟수보다 적게 나오는 색인어는 무시합니다
+final BigInt minTermFreq;
+/// 쿼리에 사용할 최대 색인어 수
+final BigInt maxQueryTerms;
+
+                const MoreLikeThisOptions({required this.minDocFreq ,required this.minTermFreq ,required this.maxQueryTerms ,});
+
+                static Future<MoreLikeThisOptions>  default_()=>RustLib.instance.api.crateApiMoreLikeThisMoreLikeThisOptionsDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => minDocFreq.hashCode^minTermFreq.hashCode^maxQueryTerms.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is MoreLikeThisOptions &&
+                runtimeType == other.runtimeType
+                && minDocFreq == other.minDocFreq&& minTermFreq == other.minTermFreq&& maxQueryTerms == other.maxQueryTerms;
+        
+            }
+
+@freezed
+                sealed class MoreLikeThisSource with _$MoreLikeThisSource  {
+                    const MoreLikeThisSource._();
+
+                     /// 색인된 문서 ID (결과에서 이 문서는 제외됩니다)
+const factory MoreLikeThisSource.documentId(  String field0,) = MoreLikeThisSource_DocumentId;
+ /// 임의의 텍스트 (제목/본문 형태소 분석기로 분석)
+const factory MoreLikeThisSource.text(  String field0,) = MoreLikeThisSource_Text;
+
+                    
+
+                    
+                }
+            
\ No newline at end of file
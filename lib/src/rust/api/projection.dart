@@ -0,0 +1,38 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'projection.freezed.dart';
+
+            // These functions are ignored because they are not marked as `pub`: `body`, `id`, `new`, `open`, `point`, `timestamps`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `FastHitFields`, `Projection`, `SegmentHitFields`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+
+
+            
+
+            @freezed
+                sealed class FieldSelection with _$FieldSelection  {
+                    const FieldSelection._();
+
+                     /// 모든 필드 (기본값)
+const factory FieldSelection.all() = FieldSelection_All;
+ /// 문서 ID, 점수, 정렬 값, 시스템 시각, 거리만
+/// (fast field 만 읽고 문서 저장소는 읽지 않으므로 스니펫과 함께 쓸 수 없습니다, metadata 는 "{}")
+const factory FieldSelection.idOnly() = FieldSelection_IdOnly;
+ /// 지정한 필드만 ("title", "body", "metadata")
+/// 빠진 title, body 는 빈 문자열, metadata 는 "{}" 이고 ID 와 시스템 필드는 항상 포함합니다
+/// (문서 저장소에서 문서 전체를 읽으므로 응답 크기만 줄고 읽는 비용은 같습니다)
+const factory FieldSelection.fields(  List<String> field0,) = FieldSelection_Fields;
+
+                    
+
+                    static Future<FieldSelection>  default_()=>RustLib.instance.api.crateApiProjectionFieldSelectionDefault();
+
+
+                }
+            
\ No newline at end of file
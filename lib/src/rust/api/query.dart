@@ -0,0 +1,89 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'geo.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'query.freezed.dart';
+
+            // These functions are ignored because they are not marked as `pub`: `analyze`, `build_query`, `date_value`, `json_number_range`, `resolve_field`, `term_query`, `text_term`, `value_term`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`
+
+
+            
+
+            @freezed
+                sealed class QueryNode with _$QueryNode  {
+                    const QueryNode._();
+
+                     /// 모든 문서
+const factory QueryNode.matchAll() = QueryNode_MatchAll;
+ /// 필드 토크나이저로 분석한 모든 색인어를 포함하는 문서
+const factory QueryNode.term({   required String field ,  required String text , }) = QueryNode_Term;
+ /// 구문 검색 (slop: 색인어 사이에 허용하는 간격)
+const factory QueryNode.phrase({   required String field ,  required String text ,  required int slop , }) = QueryNode_Phrase;
+ /// 불리언 조합 (must 와 should 가 모두 비어 있으면 전체 문서에서 must_not 을 제외)
+const factory QueryNode.boolean({   required List<QueryNode> must ,  required List<QueryNode> should ,  required List<QueryNode> mustNot , }) = QueryNode_Boolean;
+ /// 하위 쿼리 점수에 가중치(0 이상)를 곱합니다
+const factory QueryNode.boost({   required QueryNode query ,  required double boost , }) = QueryNode_Boost;
+ /// 레벤슈타인 거리(1 ~ 2) 이내의 색인어
+const factory QueryNode.fuzzy({   required String field ,  required String text ,  required int distance ,  required bool transposition , }) = QueryNode_Fuzzy;
+ /// 마지막 색인어가 접두어로 일치하는 문서
+const factory QueryNode.prefix({   required String field ,  required String prefix , }) = QueryNode_Prefix;
+ /// 범위 검색 (lower, upper 중 하나 이상 필요)
+///
+/// 메타데이터 JSON 경로의 숫자는 정수로 색인되었든 실수로 색인되었든 Integer, Float 경계와 비교합니다.
+const factory QueryNode.range({   required String field ,  RangeBound? lower ,  RangeBound? upper , }) = QueryNode_Range;
+ /// 계층형 카테고리 경로와 그 하위 카테고리에 속한 문서 (예: `category`, `/travel/asia`)
+const factory QueryNode.facet({   required String field ,  required String path , }) = QueryNode_Facet;
+ /// 좌표(기본값 `metadata.location`)가 영역 또는 반경 안에 있는 문서
+const factory QueryNode.geo({   required GeoFilter filter , }) = QueryNode_Geo;
+
+                    
+
+                    
+                }
+
+/// 범위 검색 경계
+class RangeBound  {
+                final RangeValue value;
+final bool inclusive;
+
+                const RangeBound({required this.value ,required this.inclusive ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => value.hashCode^inclusive.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is RangeBound &&
+                runtimeType == other.runtimeType
+                && value == other.value&& inclusive == other.inclusive;
+        
+            }
+
+@freezed
+                sealed class RangeValue with _$RangeValue  {
+                    const RangeValue._();
+
+                     const factory RangeValue.text(  String field0,) = RangeValue_Text;
+ const factory RangeValue.integer(  PlatformInt64 field0,) = RangeValue_Integer;
+ const factory RangeValue.float(  double field0,) = RangeValue_Float;
+ /// RFC 3339 또는 `YYYY-MM-DD` 날짜 (`dates.<경로>` 같은 날짜 필드용)
+const factory RangeValue.date(  String field0,) = RangeValue_Date;
+
+                    
+
+                    
+                }
+            
\ No newline at end of file
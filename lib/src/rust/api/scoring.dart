@@ -0,0 +1,45 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'scoring.freezed.dart';
+
+            // These functions are ignored because they are not marked as `pub`: `apply`, `field`, `for_segment`, `multiplier`, `new`, `validate`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `ScoreModifiers`, `SegmentScoreModifiers`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `eq`, `fmt`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`
+
+
+            
+
+            /// 감쇠 함수
+enum DecayFunction {
+                    exponential,
+gaussian,
+                    ;
+                    
+                }
+
+@freezed
+                sealed class ScoreModifier with _$ScoreModifier  {
+                    const ScoreModifier._();
+
+                     /// 날짜 필드가 기준 시각에서 멀어질수록 점수를 낮춥니다
+/// (offset_days 이내는 1배, 거기서 scale_days 만큼 더 멀어지면 decay 배)
+const factory ScoreModifier.dateDecay({   required String field ,  required DecayFunction function ,/// 기준 시각 (Unix 시간 밀리초, None 이면 검색 시각)
+  PlatformInt64? origin ,  required double scaleDays ,  required double offsetDays ,/// 0 보다 크고 1 보다 작은 값 (예: 0.5)
+  required double decay , }) = ScoreModifier_DateDecay;
+ /// 숫자 필드 값(예: 조회수)에 따라 `1 + ln(1 + factor * 값)` 배
+const factory ScoreModifier.logBoost({   required String field ,  required double factor , }) = ScoreModifier_LogBoost;
+ /// 문서별 고정 순위 값에 따라 `1 + weight * 값` 배 (음수가 되면 0배)
+const factory ScoreModifier.staticRank({   required String field ,  required double weight , }) = ScoreModifier_StaticRank;
+
+                    
+
+                    
+                }
+            
\ No newline at end of file
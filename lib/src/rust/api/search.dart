@@ -4,206 +4,666 @@
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import '../frb_generated.dart';
+import 'aggregation.dart';
+import 'explain.dart';
+import 'facet.dart';
+import 'geo.dart';
+import 'group.dart';
+import 'more_like_this.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'projection.dart';
+import 'query.dart';
+import 'scoring.dart';
+import 'sort.dart';
+import 'variant.dart';
 
-// These functions are ignored because they are not marked as `pub`: `generate_uuid`
-// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `SearchIndex`
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
 
-/// 검색 인덱스를 초기화합니다
-String initializeSearchIndex({required DictionaryType dictionaryType}) =>
-    RustLib.instance.api.crateApiSearchInitializeSearchIndex(
-      dictionaryType: dictionaryType,
-    );
+            // These functions are ignored because they are not marked as `pub`: `apply_settings`, `build_document`, `build_schema`, `commit`, `count_query`, `create_snippet_generators`, `distance_origin`, `execute_search`, `explain_query`, `field_tokenizer_name`, `find_created_at`, `find_document`, `generate_uuid`, `hangul_tokenizer_name`, `jamo_tokenizer_name`, `metadata_to_json_string`, `new`, `parse_query_lenient`, `parse_user_query`, `ranked_doc`, `register_tokenizers`, `save_settings`, `settings`, `snippet`, `stored_text`, `stored_timestamp`, `term_suggestions`, `title_suggestions`, `to_stored_document`, `to_utf16_ranges`, `update_settings`, `utf16_offset`, `with_fuzzy_terms`, `with_search_options`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `SearchIndex`, `SnippetGenerators`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+
+
+            /// 검색 인덱스를 초기화합니다
+String  initializeSearchIndex({required DictionaryType dictionaryType }) => RustLib.instance.api.crateApiSearchInitializeSearchIndex(dictionaryType: dictionaryType);
 
 /// 텍스트를 형태소 분석하여 토큰표형(surface) 리스트로 반환합니다
-List<String> tokenizeText({
-  required DictionaryType dictionaryType,
-  required String text,
-  required TokenMode mode,
-}) => RustLib.instance.api.crateApiSearchTokenizeText(
-  dictionaryType: dictionaryType,
-  text: text,
-  mode: mode,
-);
+List<String>  tokenizeText({required DictionaryType dictionaryType , required String text , required TokenMode mode }) => RustLib.instance.api.crateApiSearchTokenizeText(dictionaryType: dictionaryType, text: text, mode: mode);
 
 /// 텍스트를 형태소 분석하여 상세 정보(표형, 품사, 세부속성) 리스트로 반환합니다
-List<TokenDetail> tokenizeTextDetailed({
-  required DictionaryType dictionaryType,
-  required String text,
-  required TokenMode mode,
-}) => RustLib.instance.api.crateApiSearchTokenizeTextDetailed(
-  dictionaryType: dictionaryType,
-  text: text,
-  mode: mode,
-);
+List<TokenDetail>  tokenizeTextDetailed({required DictionaryType dictionaryType , required String text , required TokenMode mode }) => RustLib.instance.api.crateApiSearchTokenizeTextDetailed(dictionaryType: dictionaryType, text: text, mode: mode);
 
 /// 디스크에 인덱스를 생성하거나 로드합니다
-String initializeSearchIndexWithPath({
-  required DictionaryType dictionaryType,
-  required String indexPath,
-}) => RustLib.instance.api.crateApiSearchInitializeSearchIndexWithPath(
-  dictionaryType: dictionaryType,
-  indexPath: indexPath,
-);
+///
+/// configure_* 로 바꾼 설정은 인덱스에 함께 저장되어 다시 열 때 복원됩니다.
+/// 다른 스키마 버전으로 만든 인덱스(이전 버전에서 만든 인덱스 포함)는 열지 않고 오류를 반환합니다.
+String  initializeSearchIndexWithPath({required DictionaryType dictionaryType , required String indexPath }) => RustLib.instance.api.crateApiSearchInitializeSearchIndexWithPath(dictionaryType: dictionaryType, indexPath: indexPath);
+
+/// 날짜로 인식할 메타데이터 경로를 설정합니다 (기본값: createdAt, updatedAt, date)
+///
+/// 값이 RFC 3339 또는 `YYYY-MM-DD` 문자열이면 `dates.<경로>` 에 DateTime 으로 색인되어
+/// 범위 검색, 정렬, 날짜 히스토그램에 사용할 수 있습니다.
+/// 설정 이후에 추가하거나 수정한 문서부터 적용됩니다.
+String  configureDateFields({required List<String> paths }) => RustLib.instance.api.crateApiSearchConfigureDateFields(paths: paths);
+
+/// 좌표를 읽을 메타데이터 경로를 설정합니다 (기본값: location)
+///
+/// 경로의 값이 `{"lat": 37.46, "lon": 126.44}` 형태이면 lat/lon fast field 에 색인되어
+/// 좌표 필터, 거리 정렬, 거리 계산에 사용됩니다 (예: "place.coordinates").
+/// 설정 이후에 추가하거나 수정한 문서부터 적용됩니다.
+String  configureLocationField({required String path }) => RustLib.instance.api.crateApiSearchConfigureLocationField(path: path);
+
+/// 초성 필드를 채울 원본 필드를 설정합니다 (기본값: 제목만)
+///
+/// 자음/모음(호환용 자모)으로만 된 검색어(예: "ㅇㅊㄱㅈㄱㅎ")는 초성 필드에서 검색합니다.
+/// 설정 이후에 추가하거나 수정한 문서부터 적용됩니다.
+String  configureChosungFields({required bool title , required bool body }) => RustLib.instance.api.crateApiSearchConfigureChosungFields(title: title, body: body);
+
+/// 한자 <-> 한글 검색을 켜거나 끕니다 (기본값: 끔)
+///
+/// 켜면 제목/본문의 한자를 내장된 독음 표로 한글로 바꾼 필드를 함께 색인하고,
+/// 검색어도 같은 방식으로 바꿔 검색하므로 "인천국제공항" 으로 "仁川國際空港" 을,
+/// "仁川" 으로 "인천" 을 찾을 수 있습니다. 설정 이후에 추가하거나 수정한 문서부터 적용됩니다.
+/// 독음 표는 자주 쓰는 한자 631자와 일부 지명만 담고 있어 표에 없는 한자는 바뀌지 않습니다.
+String  configureHanjaReadings({required bool enabled }) => RustLib.instance.api.crateApiSearchConfigureHanjaReadings(enabled: enabled);
+
+/// 일본어 읽기(요미) 검색을 켜거나 끕니다 (기본값: 끔, IPADIC/UniDic 사전 전용)
+///
+/// 켜면 제목/본문을 형태소 분석한 사전의 읽기를 가타카나로 이어 붙여 읽기 필드에 색인하고,
+/// 가나로만 된 검색어("かんさい", "カンサイ")를 읽기 필드에서도 찾으므로 "関西国際空港" 을 찾을 수 있습니다.
+/// romaji 를 켜면 헵번식 로마자 표기도 함께 색인하여 "kansai", "tokyo" 같은 영문 검색어로도 찾습니다
+/// (장음은 구분하지 않습니다). 설정 이후에 추가하거나 수정한 문서부터 적용됩니다.
+String  configureJapaneseReadings({required bool enabled , required bool romaji }) => RustLib.instance.api.crateApiSearchConfigureJapaneseReadings(enabled: enabled, romaji: romaji);
+
+/// 중국어 병음 검색을 켜거나 끕니다 (기본값: 끔, CC-CEDICT 사전 전용)
+///
+/// 켜면 제목/본문을 형태소 분석한 사전의 병음에서 성조를 빼고 이어 붙인 전체 병음과
+/// 음절 첫 글자를 병음 필드에 색인하고, 영문 검색어를 병음 필드에서도 찾으므로
+/// "beijing" 이나 "bjsd" 로 "北京首都国际机场" 을 찾을 수 있습니다 (ü 는 v 로 입력합니다).
+/// 설정 이후에 추가하거나 수정한 문서부터 적용됩니다.
+String  configurePinyin({required bool enabled }) => RustLib.instance.api.crateApiSearchConfigurePinyin(enabled: enabled);
+
+/// CJK 이체자 통일을 켤 필드와 이체자 표를 설정합니다 (기본값: 모든 필드 끔)
+///
+/// 켠 필드는 색인과 검색 모두 이체자를 대표 글자로 바꾼 뒤 분석하므로
+/// "国际机场", "國際機場", "国際空港" 처럼 글자 모양이 다른 텍스트가 서로 일치합니다.
+/// fields 는 "title", "body", "title_ngram", "body_ngram" 중에서 고르고,
+/// mappings 가 비어 있으면 내장된 표(번체자, 일본 신자체 -> 간체자)를 사용합니다.
+/// 검색어 분석이 바로 바뀌므로 문서가 없는 인덱스에서만 바꿀 수 있습니다
+/// (먼저 clear_all_documents() 를 호출하고 문서를 다시 추가하세요).
+String  configureVariantFolding({required List<String> fields , required List<VariantMapping> mappings }) => RustLib.instance.api.crateApiSearchConfigureVariantFolding(fields: fields, mappings: mappings);
 
 /// 샘플 문서를 인덱싱합니다
-String indexSampleDocuments() =>
-    RustLib.instance.api.crateApiSearchIndexSampleDocuments();
+String  indexSampleDocuments() => RustLib.instance.api.crateApiSearchIndexSampleDocuments();
 
 /// 문서를 검색합니다 (형태소 분석 + N-gram 부분 검색)
-List<SearchResult> searchDocuments({
-  required String queryStr,
-  required BigInt limit,
-}) => RustLib.instance.api.crateApiSearchSearchDocuments(
-  queryStr: queryStr,
-  limit: limit,
-);
+List<SearchResult>  searchDocuments({required String queryStr , required BigInt limit }) => RustLib.instance.api.crateApiSearchSearchDocuments(queryStr: queryStr, limit: limit);
+
+/// 옵션을 지정하여 문서를 검색합니다 (스니펫/하이라이트 포함)
+SearchResponse  searchDocumentsWithOptions({required String queryStr , required SearchOptions options }) => RustLib.instance.api.crateApiSearchSearchDocumentsWithOptions(queryStr: queryStr, options: options);
+
+/// 입력 중인 검색어로 제목을 검색합니다 (search-as-you-type)
+///
+/// 검색어와 제목을 자모로 풀어 비교하므로 마지막 음절이 완성되지 않아도
+/// 접두어로 일치합니다 (예: "한구" -> "한국어", "인천 국제고" -> "인천 국제공항").
+/// 검색어의 모든 단어가 제목의 어떤 단어의 접두어여야 합니다.
+SearchResponse  searchAsYouType({required String queryStr , required SearchOptions options }) => RustLib.instance.api.crateApiSearchSearchAsYouType(queryStr: queryStr, options: options);
+
+/// 구조화된 쿼리로 문서를 검색합니다 (쿼리 문자열 파싱/이스케이프 불필요)
+SearchResponse  searchWithQuery({required QueryNode query , required SearchOptions options }) => RustLib.instance.api.crateApiSearchSearchWithQuery(query: query, options: options);
+
+/// 기준 문서(또는 텍스트)와 비슷한 문서를 검색합니다 (기준 문서는 결과에서 제외)
+SearchResponse  moreLikeThis({required MoreLikeThisSource source , required MoreLikeThisOptions mltOptions , required SearchOptions options }) => RustLib.instance.api.crateApiSearchMoreLikeThis(source: source, mltOptions: mltOptions, options: options);
+
+/// 쿼리 문자열에 대해 문서의 점수가 어떻게 계산되었는지 설명합니다
+///
+/// 검색 옵션의 퍼지 매칭과 필터도 검색할 때와 같이 적용됩니다.
+ScoreExplanation  explainDocument({required String queryStr , required String id , required SearchOptions options }) => RustLib.instance.api.crateApiSearchExplainDocument(queryStr: queryStr, id: id, options: options);
+
+/// 구조화된 쿼리에 대해 문서의 점수가 어떻게 계산되었는지 설명합니다
+ScoreExplanation  explainStructuredQuery({required QueryNode query , required String id , required SearchOptions options }) => RustLib.instance.api.crateApiSearchExplainStructuredQuery(query: query, id: id, options: options);
+
+/// 입력 중인 접두어로 자동완성 후보를 반환합니다
+///
+/// 제목은 edge n-gram 필드의 색인어 하나를, 색인어는 term dictionary(FST)의
+/// 접두어 구간만 조회하므로 검색 파이프라인보다 훨씬 가볍습니다.
+/// 색인어 후보는 대소문자를 구분하지 않으며, 접두어로 시작하는 색인어가 아주 많으면
+/// 사전 순으로 앞쪽 색인어만 문서 수를 세므로 순위가 근사값일 수 있습니다.
+List<Suggestion>  autocomplete({required String prefix , required BigInt limit , required SuggestionSource source }) => RustLib.instance.api.crateApiSearchAutocomplete(prefix: prefix, limit: limit, source: source);
 
 /// 커스텀 문서를 추가합니다 (UUID 자동 생성)
-String addDocument({
-  required String title,
-  required String body,
-  required String metadataJson,
-}) => RustLib.instance.api.crateApiSearchAddDocument(
-  title: title,
-  body: body,
-  metadataJson: metadataJson,
-);
-
-/// 여러 문서를 한 번에 추가합니다 (UUID 자동 생성)
-String addDocuments({required List<DocumentInput> documents}) =>
-    RustLib.instance.api.crateApiSearchAddDocuments(documents: documents);
+String  addDocument({required String title , required String body , required String metadataJson }) => RustLib.instance.api.crateApiSearchAddDocument(title: title, body: body, metadataJson: metadataJson);
+
+/// 여러 문서를 한 번에 추가합니다
+///
+/// id 가 비어 있으면 UUID 를 만들고, 같은 ID 의 문서가 이미 있으면 `update_document` 처럼
+/// 생성 시각을 유지한 채 바꿉니다 (한 번에 같은 ID 를 여러 번 넘기면 마지막 문서가 남습니다).
+String  addDocuments({required List<DocumentInput> documents }) => RustLib.instance.api.crateApiSearchAddDocuments(documents: documents);
 
 /// 문서를 업데이트합니다 (ID로 찾아서 삭제 후 재추가)
-String updateDocument({
-  required String id,
-  required String title,
-  required String body,
-  required String metadataJson,
-}) => RustLib.instance.api.crateApiSearchUpdateDocument(
-  id: id,
-  title: title,
-  body: body,
-  metadataJson: metadataJson,
-);
+String  updateDocument({required String id , required String title , required String body , required String metadataJson }) => RustLib.instance.api.crateApiSearchUpdateDocument(id: id, title: title, body: body, metadataJson: metadataJson);
 
 /// ID로 문서를 삭제합니다
-String deleteDocument({required String id}) =>
-    RustLib.instance.api.crateApiSearchDeleteDocument(id: id);
+String  deleteDocument({required String id }) => RustLib.instance.api.crateApiSearchDeleteDocument(id: id);
 
 /// 여러 문서를 한 번에 삭제합니다
-String deleteDocuments({required List<String> ids}) =>
-    RustLib.instance.api.crateApiSearchDeleteDocuments(ids: ids);
+String  deleteDocuments({required List<String> ids }) => RustLib.instance.api.crateApiSearchDeleteDocuments(ids: ids);
 
 /// 모든 문서를 삭제합니다
-String clearAllDocuments() =>
-    RustLib.instance.api.crateApiSearchClearAllDocuments();
+String  clearAllDocuments() => RustLib.instance.api.crateApiSearchClearAllDocuments();
 
 /// 인덱스에 있는 문서 개수를 반환합니다
-BigInt getDocumentCount() =>
-    RustLib.instance.api.crateApiSearchGetDocumentCount();
-
-enum DictionaryType {
-  korean,
-  japaneseIpadic,
-  japaneseUnidic,
-  chinese;
-
-  Future<void> toEmbeddedPath() => RustLib.instance.api
-      .crateApiSearchDictionaryTypeToEmbeddedPath(that: this);
-
-  Future<void> toTokenizerName() => RustLib.instance.api
-      .crateApiSearchDictionaryTypeToTokenizerName(that: this);
-}
-
-class DocumentInput {
-  final String id;
-  final String title;
-  final String body;
-  final String metadata;
-
-  const DocumentInput({
-    required this.id,
-    required this.title,
-    required this.body,
-    required this.metadata,
-  });
-
-  @override
-  int get hashCode =>
-      id.hashCode ^ title.hashCode ^ body.hashCode ^ metadata.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is DocumentInput &&
-          runtimeType == other.runtimeType &&
-          id == other.id &&
-          title == other.title &&
-          body == other.body &&
-          metadata == other.metadata;
-}
-
-class SearchResult {
-  final String id;
-  final String title;
-  final String body;
-  final double score;
-  final String metadata;
-
-  const SearchResult({
-    required this.id,
-    required this.title,
-    required this.body,
-    required this.score,
-    required this.metadata,
-  });
-
-  @override
-  int get hashCode =>
-      id.hashCode ^
-      title.hashCode ^
-      body.hashCode ^
-      score.hashCode ^
-      metadata.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is SearchResult &&
-          runtimeType == other.runtimeType &&
-          id == other.id &&
-          title == other.title &&
-          body == other.body &&
-          score == other.score &&
-          metadata == other.metadata;
-}
+BigInt  getDocumentCount() => RustLib.instance.api.crateApiSearchGetDocumentCount();
+
+/// 쿼리 문자열과 일치하는 문서 수를 반환합니다 (문서를 읽지 않습니다)
+///
+/// 퍼지 매칭과 필터(카테고리, 좌표) 옵션은 검색할 때와 같이 적용되고
+/// limit, 정렬, 집계 등 결과에 관한 옵션은 무시됩니다.
+BigInt  countDocuments({required String queryStr , required SearchOptions options }) => RustLib.instance.api.crateApiSearchCountDocuments(queryStr: queryStr, options: options);
+
+/// 구조화된 쿼리와 일치하는 문서 수를 반환합니다
+BigInt  countWithQuery({required QueryNode query , required SearchOptions options }) => RustLib.instance.api.crateApiSearchCountWithQuery(query: query, options: options);
+
+/// 해당 ID의 문서가 있는지 확인합니다
+bool  documentExists({required String id }) => RustLib.instance.api.crateApiSearchDocumentExists(id: id);
+
+/// ID로 저장된 문서를 가져옵니다 (없으면 None)
+StoredDocument?  getDocument({required String id }) => RustLib.instance.api.crateApiSearchGetDocument(id: id);
+
+/// 여러 ID의 문서를 요청한 순서대로 가져옵니다 (없는 ID는 건너뜁니다)
+List<StoredDocument>  getDocuments({required List<String> ids }) => RustLib.instance.api.crateApiSearchGetDocuments(ids: ids);
+
+/// 삭제되지 않은 모든 문서를 ID 순으로 페이지 단위로 읽습니다
+///
+/// 처음에는 cursor 를 None 으로 호출하고, 이후에는 이전 페이지의 `next_cursor` 를 넘깁니다.
+/// 커서는 마지막 문서의 ID 이므로 페이지 사이에 문서가 추가/삭제되어도 순서가 흔들리지 않습니다.
+DocumentPage  scanDocuments({String? cursor , required BigInt limit }) => RustLib.instance.api.crateApiSearchScanDocuments(cursor: cursor, limit: limit);
+
+            enum DictionaryType {
+                    korean,
+japaneseIpadic,
+japaneseUnidic,
+chinese,
+                    ;
+                     Future<void>  toEmbeddedPath()=>RustLib.instance.api.crateApiSearchDictionaryTypeToEmbeddedPath(that: this, );
+
+
+ Future<void>  toTokenizerName()=>RustLib.instance.api.crateApiSearchDictionaryTypeToTokenizerName(that: this, );
+
+
+                }
+
+class DocumentInput  {
+                final String id;
+final String title;
+final String body;
+final String metadata;
+
+                const DocumentInput({required this.id ,required this.title ,required this.body ,required this.metadata ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^title.hashCode^body.hashCode^metadata.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is DocumentInput &&
+                runtimeType == other.runtimeType
+                && id == other.id&& title == other.title&& body == other.body&& metadata == other.metadata;
+        
+            }
+
+/// 전체 문서 목록의 한 페이지
+class DocumentPage  {
+                final List<StoredDocument> documents;
+/// 다음 페이지를 읽을 때 넘길 커서 (마지막 페이지면 None)
+final String? nextCursor;
+
+                const DocumentPage({required this.documents ,this.nextCursor ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => documents.hashCode^nextCursor.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is DocumentPage &&
+                runtimeType == other.runtimeType
+                && documents == other.documents&& nextCursor == other.nextCursor;
+        
+            }
+
+/// 퍼지(오타 허용) 검색 옵션
+class FuzzyOptions  {
+                /// 레벤슈타인 거리 (1 ~ 2)
+final int distance;
+/// true 이면 인접 문자 교환(transposition)을 거리 1로 계산합니다
+final bool transposition;
+/// 앞에서부터 정확히 일치해야 하는 글자 수
+final BigInt prefixLength;
+
+                const FuzzyOptions({required this.distance ,required this.transposition ,required this.prefixLength ,});
+
+                static Future<FuzzyOptions>  default_()=>RustLib.instance.api.crateApiSearchFuzzyOptionsDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => distance.hashCode^transposition.hashCode^prefixLength.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is FuzzyOptions &&
+                runtimeType == other.runtimeType
+                && distance == other.distance&& transposition == other.transposition&& prefixLength == other.prefixLength;
+        
+            }
+
+/// 하이라이트 구간 (UTF-16 오프셋, Dart `String` 인덱스와 동일)
+class HighlightRange  {
+                final int start;
+final int end;
+
+                const HighlightRange({required this.start ,required this.end ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => start.hashCode^end.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is HighlightRange &&
+                runtimeType == other.runtimeType
+                && start == other.start&& end == other.end;
+        
+            }
+
+/// 관대한(lenient) 쿼리 파싱 중 발견된 문제
+class QueryDiagnostic  {
+                /// 쿼리 문자열에서의 위치 (UTF-16 오프셋, 위치를 알 수 없으면 None)
+final int? position;
+final String message;
+
+                const QueryDiagnostic({this.position ,required this.message ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => position.hashCode^message.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is QueryDiagnostic &&
+                runtimeType == other.runtimeType
+                && position == other.position&& message == other.message;
+        
+            }
+
+/// 그룹별 검색 결과
+class SearchGroup  {
+                /// 그룹 필드 값 (값이 없는 문서는 문서마다 따로 key 가 None 인 그룹이 됩니다)
+final String? key;
+/// 그룹에 속한 매칭 문서 수
+final BigInt count;
+final List<SearchHit> hits;
+
+                const SearchGroup({this.key ,required this.count ,required this.hits ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => key.hashCode^count.hashCode^hits.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SearchGroup &&
+                runtimeType == other.runtimeType
+                && key == other.key&& count == other.count&& hits == other.hits;
+        
+            }
+
+/// 옵션 검색 결과 (SearchResult + 스니펫)
+class SearchHit  {
+                final String id;
+final String title;
+final String body;
+final double score;
+final String metadata;
+final SearchSnippet? snippet;
+/// `SearchOptions::sort` 의 키 순서대로 이 문서의 정렬 값
+final List<SortValue> sortValues;
+/// 처음 추가된 시각 (Unix 시간 밀리초, 수정해도 유지)
+final PlatformInt64 createdAt;
+/// 마지막으로 추가/수정된 시각 (Unix 시간 밀리초)
+final PlatformInt64 updatedAt;
+/// 기준 좌표까지의 거리 (km, 거리 정렬 키 또는 반경 필터의 중심 기준, 좌표가 없으면 None)
+final double? distanceKm;
+
+                const SearchHit({required this.id ,required this.title ,required this.body ,required this.score ,required this.metadata ,this.snippet ,required this.sortValues ,required this.createdAt ,required this.updatedAt ,this.distanceKm ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^title.hashCode^body.hashCode^score.hashCode^metadata.hashCode^snippet.hashCode^sortValues.hashCode^createdAt.hashCode^updatedAt.hashCode^distanceKm.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SearchHit &&
+                runtimeType == other.runtimeType
+                && id == other.id&& title == other.title&& body == other.body&& score == other.score&& metadata == other.metadata&& snippet == other.snippet&& sortValues == other.sortValues&& createdAt == other.createdAt&& updatedAt == other.updatedAt&& distanceKm == other.distanceKm;
+        
+            }
+
+/// 검색 옵션
+class SearchOptions  {
+                final BigInt limit;
+final SnippetOptions? snippet;
+/// 형태소 분석 필드(title, body)의 검색어에 퍼지 매칭을 추가합니다
+final FuzzyOptions? fuzzy;
+/// 검색어와 색인어를 자모로 풀어 비교하는 퍼지 매칭을 추가합니다
+/// (예: "공헝" -> "공항", 거리와 prefix_length 는 자모 수 기준)
+final FuzzyOptions? jamoFuzzy;
+/// true 이면 쿼리 문법 오류가 있어도 해석 가능한 부분으로 검색하고
+/// 오류는 `SearchResponse::diagnostics` 로 반환합니다
+final bool lenient;
+/// 정렬 키 (비어 있으면 BM25 점수 순)
+final List<SortKey> sort;
+/// 검색 결과 전체(limit 와 무관)에 대해 함께 계산할 집계
+final List<AggregationRequest> aggregations;
+/// 이 카테고리 경로와 그 하위 카테고리의 문서만 검색합니다 (예: "/travel/asia")
+final String? categoryFilter;
+/// 바로 아래 하위 카테고리별 문서 수를 셀 상위 카테고리 경로 (루트는 "/")
+final List<String> categoryCounts;
+/// 좌표(기본값 `metadata.location`, `configure_location_field` 로 변경)가 영역 또는 반경 안에 있는 문서만 검색합니다
+final GeoFilter? geoFilter;
+/// 이 필드 값마다 가장 앞선 문서 하나만 남깁니다 (예: `metadata.city`, 값이 없는 문서는 모두 남깁니다)
+final String? collapse;
+/// 필드 값별 그룹의 상위 문서와 문서 수를 `SearchResponse::groups` 로 함께 반환합니다
+final GroupByOptions? groupBy;
+/// BM25 점수에 곱할 보정 (최신성 감쇠, 조회수 부스트, 고정 순위)
+final List<ScoreModifier> scoreModifiers;
+/// 결과에 담을 저장 필드 (목록 화면처럼 일부만 필요할 때 FFI 로 넘기는 양을 줄입니다)
+final FieldSelection fields;
+/// 결과 본문을 이 글자 수까지만 반환합니다 (스니펫에는 영향 없음)
+final BigInt? bodyMaxChars;
+
+                const SearchOptions({required this.limit ,this.snippet ,this.fuzzy ,this.jamoFuzzy ,required this.lenient ,required this.sort ,required this.aggregations ,this.categoryFilter ,required this.categoryCounts ,this.geoFilter ,this.collapse ,this.groupBy ,required this.scoreModifiers ,required this.fields ,this.bodyMaxChars ,});
+
+                static Future<SearchOptions>  default_()=>RustLib.instance.api.crateApiSearchSearchOptionsDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => limit.hashCode^snippet.hashCode^fuzzy.hashCode^jamoFuzzy.hashCode^lenient.hashCode^sort.hashCode^aggregations.hashCode^categoryFilter.hashCode^categoryCounts.hashCode^geoFilter.hashCode^collapse.hashCode^groupBy.hashCode^scoreModifiers.hashCode^fields.hashCode^bodyMaxChars.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SearchOptions &&
+                runtimeType == other.runtimeType
+                && limit == other.limit&& snippet == other.snippet&& fuzzy == other.fuzzy&& jamoFuzzy == other.jamoFuzzy&& lenient == other.lenient&& sort == other.sort&& aggregations == other.aggregations&& categoryFilter == other.categoryFilter&& categoryCounts == other.categoryCounts&& geoFilter == other.geoFilter&& collapse == other.collapse&& groupBy == other.groupBy&& scoreModifiers == other.scoreModifiers&& fields == other.fields&& bodyMaxChars == other.bodyMaxChars;
+        
+            }
+
+/// 옵션 검색 응답
+class SearchResponse  {
+                final List<SearchHit> hits;
+final List<QueryDiagnostic> diagnostics;
+/// `SearchOptions::aggregations` 의 요청 순서대로 집계 결과
+final List<AggregationResult> aggregations;
+/// `SearchOptions::category_counts` 의 요청 순서대로 하위 카테고리 문서 수
+final List<CategoryCounts> categoryCounts;
+/// `SearchOptions::group_by` 의 그룹 결과
+final List<SearchGroup> groups;
+
+                const SearchResponse({required this.hits ,required this.diagnostics ,required this.aggregations ,required this.categoryCounts ,required this.groups ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => hits.hashCode^diagnostics.hashCode^aggregations.hashCode^categoryCounts.hashCode^groups.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SearchResponse &&
+                runtimeType == other.runtimeType
+                && hits == other.hits&& diagnostics == other.diagnostics&& aggregations == other.aggregations&& categoryCounts == other.categoryCounts&& groups == other.groups;
+        
+            }
+
+class SearchResult  {
+                final String id;
+final String title;
+final String body;
+final double score;
+final String metadata;
+/// 처음 추가된 시각 (Unix 시간 밀리초)
+final PlatformInt64 createdAt;
+/// 마지막으로 추가/수정된 시각 (Unix 시간 밀리초)
+final PlatformInt64 updatedAt;
+
+                const SearchResult({required this.id ,required this.title ,required this.body ,required this.score ,required this.metadata ,required this.createdAt ,required this.updatedAt ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^title.hashCode^body.hashCode^score.hashCode^metadata.hashCode^createdAt.hashCode^updatedAt.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SearchResult &&
+                runtimeType == other.runtimeType
+                && id == other.id&& title == other.title&& body == other.body&& score == other.score&& metadata == other.metadata&& createdAt == other.createdAt&& updatedAt == other.updatedAt;
+        
+            }
+
+/// 검색어가 매칭된 본문 조각
+class SearchSnippet  {
+                final String fragment;
+final List<HighlightRange> highlights;
+final String? html;
+
+                const SearchSnippet({required this.fragment ,required this.highlights ,this.html ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => fragment.hashCode^highlights.hashCode^html.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SearchSnippet &&
+                runtimeType == other.runtimeType
+                && fragment == other.fragment&& highlights == other.highlights&& html == other.html;
+        
+            }
+
+/// 검색 결과 스니펫(하이라이트) 옵션
+class SnippetOptions  {
+                /// 스니펫을 만들 필드 이름 ("body" 또는 "title")
+final String field;
+/// 조각(fragment)의 최대 글자 수
+final BigInt maxNumChars;
+/// true 이면 `<b>` 태그로 감싼 HTML 도 함께 반환합니다
+final bool html;
+
+                const SnippetOptions({required this.field ,required this.maxNumChars ,required this.html ,});
+
+                static Future<SnippetOptions>  default_()=>RustLib.instance.api.crateApiSearchSnippetOptionsDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => field.hashCode^maxNumChars.hashCode^html.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SnippetOptions &&
+                runtimeType == other.runtimeType
+                && field == other.field&& maxNumChars == other.maxNumChars&& html == other.html;
+        
+            }
+
+/// 저장된 문서 (시스템 필드 포함)
+class StoredDocument  {
+                final String id;
+final String title;
+final String body;
+final String metadata;
+/// 문서를 처음 추가한 시각 (Unix 시간 밀리초)
+final PlatformInt64 createdAt;
+/// 문서를 마지막으로 추가/수정한 시각 (Unix 시간 밀리초)
+final PlatformInt64 updatedAt;
+
+                const StoredDocument({required this.id ,required this.title ,required this.body ,required this.metadata ,required this.createdAt ,required this.updatedAt ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^title.hashCode^body.hashCode^metadata.hashCode^createdAt.hashCode^updatedAt.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is StoredDocument &&
+                runtimeType == other.runtimeType
+                && id == other.id&& title == other.title&& body == other.body&& metadata == other.metadata&& createdAt == other.createdAt&& updatedAt == other.updatedAt;
+        
+            }
+
+/// 자동완성 후보
+class Suggestion  {
+                final String text;
+final SuggestionSource source;
+/// 이 후보를 포함하는 문서 수
+final BigInt docCount;
+
+                const Suggestion({required this.text ,required this.source ,required this.docCount ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => text.hashCode^source.hashCode^docCount.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is Suggestion &&
+                runtimeType == other.runtimeType
+                && text == other.text&& source == other.source&& docCount == other.docCount;
+        
+            }
+
+/// 자동완성 후보의 출처
+enum SuggestionSource {
+                    /// 문서 제목 (제목 앞부분이 접두어와 일치)
+title,
+/// 형태소 분석된 색인어 (title, body)
+term,
+                    ;
+                    
+                }
 
 /// 형태소 분석 토큰 상세 정보
-class TokenDetail {
-  final String surface;
-  final String pos;
-  final List<String> details;
-
-  const TokenDetail({
-    required this.surface,
-    required this.pos,
-    required this.details,
-  });
-
-  @override
-  int get hashCode => surface.hashCode ^ pos.hashCode ^ details.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is TokenDetail &&
-          runtimeType == other.runtimeType &&
-          surface == other.surface &&
-          pos == other.pos &&
-          details == other.details;
-}
+class TokenDetail  {
+                final String surface;
+final String pos;
+final List<String> details;
+
+                const TokenDetail({required this.surface ,required this.pos ,required this.details ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => surface.hashCode^pos.hashCode^details.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is TokenDetail &&
+                runtimeType == other.runtimeType
+                && surface == other.surface&& pos == other.pos&& details == other.details;
+        
+            }
 
 /// 형태소 분석 토큰화 모드 (Normal / Decompose)
-enum TokenMode { normal, decompose }
+enum TokenMode {
+                    normal,
+decompose,
+                    ;
+                    
+                }
+            
\ No newline at end of file
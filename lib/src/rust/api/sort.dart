@@ -0,0 +1,83 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'geo.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'sort.freezed.dart';
+
+            // These functions are ignored because they are not marked as `pub`: `as_f64`, `ensure_fast_field`, `first_value`, `natural_cmp`, `new`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `FastFieldSorter`, `SegmentColumns`, `SegmentSorter`, `SortValues`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `partial_cmp`, `score`, `segment_tweaker`
+
+
+            
+
+            @freezed
+                sealed class SortBy with _$SortBy  {
+                    const SortBy._();
+
+                     /// BM25 관련도 점수 (점수 보정이 있으면 보정한 점수)
+const factory SortBy.score() = SortBy_Score;
+ /// fast field 이름 또는 메타데이터 JSON 경로 (예: `metadata.price`)
+const factory SortBy.field(  String field0,) = SortBy_Field;
+ /// 문서 좌표(기본값 `metadata.location`)와 기준 좌표 사이의 거리 (km)
+const factory SortBy.distance(  GeoPoint field0,) = SortBy_Distance;
+
+                    
+
+                    
+                }
+
+/// 정렬 키 (여러 개를 지정하면 앞의 키가 같을 때 다음 키로 비교합니다)
+class SortKey  {
+                final SortBy by;
+final SortOrder order;
+
+                const SortKey({required this.by ,required this.order ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => by.hashCode^order.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SortKey &&
+                runtimeType == other.runtimeType
+                && by == other.by&& order == other.order;
+        
+            }
+
+/// 정렬 방향
+enum SortOrder {
+                    asc,
+desc,
+                    ;
+                    
+                }
+
+@freezed
+                sealed class SortValue with _$SortValue  {
+                    const SortValue._();
+
+                     /// 정수 값 (날짜 필드는 Unix 시간 밀리초)
+const factory SortValue.integer(  PlatformInt64 field0,) = SortValue_Integer;
+ const factory SortValue.float(  double field0,) = SortValue_Float;
+ const factory SortValue.text(  String field0,) = SortValue_Text;
+ /// 문서에 해당 필드 값이 없음 (방향과 관계없이 항상 마지막)
+const factory SortValue.missing() = SortValue_Missing;
+
+                    
+
+                    
+                }
+            
\ No newline at end of file
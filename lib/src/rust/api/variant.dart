@@ -0,0 +1,42 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `configure`, `default_table`, `fields`, `fold`, `folding_filter`, `mapping_table`, `mappings`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `VariantFolding`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `eq`, `fmt`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`
+
+
+            
+
+            /// 이체자와 대표 글자 (각각 한 글자)
+class VariantMapping  {
+                final String variant;
+final String canonical;
+
+                const VariantMapping({required this.variant ,required this.canonical ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => variant.hashCode^canonical.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is VariantMapping &&
+                runtimeType == other.runtimeType
+                && variant == other.variant&& canonical == other.canonical;
+        
+            }
+            
\ No newline at end of file
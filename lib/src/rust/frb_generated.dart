@@ -3,1054 +3,3120 @@
 
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
+import 'api/aggregation.dart';
+import 'api/explain.dart';
+import 'api/facet.dart';
+import 'api/geo.dart';
+import 'api/group.dart';
+import 'api/more_like_this.dart';
+import 'api/projection.dart';
+import 'api/query.dart';
+import 'api/scoring.dart';
 import 'api/search.dart';
 import 'api/simple.dart';
+import 'api/sort.dart';
+import 'api/variant.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'frb_generated.dart';
-import 'frb_generated.io.dart'
-    if (dart.library.js_interop) 'frb_generated.web.dart';
+import 'frb_generated.io.dart' if (dart.library.js_interop) 'frb_generated.web.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-/// Main entrypoint of the Rust API
-class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
-  @internal
-  static final instance = RustLib._();
-
-  RustLib._();
-
-  /// Initialize flutter_rust_bridge
-  static Future<void> init({
-    RustLibApi? api,
-    BaseHandler? handler,
-    ExternalLibrary? externalLibrary,
-    bool forceSameCodegenVersion = true,
-  }) async {
-    await instance.initImpl(
-      api: api,
-      handler: handler,
-      externalLibrary: externalLibrary,
-      forceSameCodegenVersion: forceSameCodegenVersion,
-    );
-  }
-
-  /// Initialize flutter_rust_bridge in mock mode.
-  /// No libraries for FFI are loaded.
-  static void initMock({required RustLibApi api}) {
-    instance.initMockImpl(api: api);
-  }
-
-  /// Dispose flutter_rust_bridge
-  ///
-  /// The call to this function is optional, since flutter_rust_bridge (and everything else)
-  /// is automatically disposed when the app stops.
-  static void dispose() => instance.disposeImpl();
-
-  @override
-  ApiImplConstructor<RustLibApiImpl, RustLibWire> get apiImplConstructor =>
-      RustLibApiImpl.new;
-
-  @override
-  WireConstructor<RustLibWire> get wireConstructor =>
-      RustLibWire.fromExternalLibrary;
-
-  @override
-  Future<void> executeRustInitializers() async {
-    await api.crateApiSimpleInitApp();
-  }
-
-  @override
-  ExternalLibraryLoaderConfig get defaultExternalLibraryLoaderConfig =>
-      kDefaultExternalLibraryLoaderConfig;
-
-  @override
-  String get codegenVersion => '2.12.0';
-
-  @override
-  int get rustContentHash => -604279136;
-
-  static const kDefaultExternalLibraryLoaderConfig =
-      ExternalLibraryLoaderConfig(
-        stem: 'flutter_lindera_tantivy',
-        ioDirectory: 'rust/target/release/',
-        webPrefix: 'pkg/',
-        wasmBindgenName: 'wasm_bindgen',
-      );
-}
-
-abstract class RustLibApi extends BaseApi {
-  String crateApiSearchAddDocument({
-    required String title,
-    required String body,
-    required String metadataJson,
-  });
-
-  String crateApiSearchAddDocuments({required List<DocumentInput> documents});
-
-  String crateApiSearchClearAllDocuments();
-
-  String crateApiSearchDeleteDocument({required String id});
-
-  String crateApiSearchDeleteDocuments({required List<String> ids});
-
-  Future<void> crateApiSearchDictionaryTypeToEmbeddedPath({
-    required DictionaryType that,
-  });
-
-  Future<void> crateApiSearchDictionaryTypeToTokenizerName({
-    required DictionaryType that,
-  });
-
-  BigInt crateApiSearchGetDocumentCount();
-
-  String crateApiSimpleGreet({required String name});
-
-  String crateApiSearchIndexSampleDocuments();
-
-  Future<void> crateApiSimpleInitApp();
-
-  String crateApiSearchInitializeSearchIndex({
-    required DictionaryType dictionaryType,
-  });
-
-  String crateApiSearchInitializeSearchIndexWithPath({
-    required DictionaryType dictionaryType,
-    required String indexPath,
-  });
-
-  List<SearchResult> crateApiSearchSearchDocuments({
-    required String queryStr,
-    required BigInt limit,
-  });
-
-  List<String> crateApiSearchTokenizeText({
-    required DictionaryType dictionaryType,
-    required String text,
-    required TokenMode mode,
-  });
-
-  List<TokenDetail> crateApiSearchTokenizeTextDetailed({
-    required DictionaryType dictionaryType,
-    required String text,
-    required TokenMode mode,
-  });
-
-  String crateApiSearchUpdateDocument({
-    required String id,
-    required String title,
-    required String body,
-    required String metadataJson,
-  });
-}
-
-class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
-  RustLibApiImpl({
-    required super.handler,
-    required super.wire,
-    required super.generalizedFrbRustBinding,
-    required super.portManager,
-  });
-
-  @override
-  String crateApiSearchAddDocument({
-    required String title,
-    required String body,
-    required String metadataJson,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(title, serializer);
-          sse_encode_String(body, serializer);
-          sse_encode_String(metadataJson, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 1)!;
-        },
-        codec: SseCodec(
+
+                /// Main entrypoint of the Rust API
+                class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
+                  @internal
+                  static final instance = RustLib._();
+
+                  RustLib._();
+
+                  /// Initialize flutter_rust_bridge
+                  static Future<void> init({
+                    RustLibApi? api,
+                    BaseHandler? handler,
+                    ExternalLibrary? externalLibrary,
+                    bool forceSameCodegenVersion = true,
+                  }) async {
+                    await instance.initImpl(
+                      api: api,
+                      handler: handler,
+                      externalLibrary: externalLibrary,
+                      forceSameCodegenVersion: forceSameCodegenVersion,
+                    );
+                  }
+
+                  /// Initialize flutter_rust_bridge in mock mode.
+                  /// No libraries for FFI are loaded.
+                  static void initMock({
+                    required RustLibApi api,
+                  }) {
+                    instance.initMockImpl(
+                      api: api,
+                    );
+                  }
+
+                  /// Dispose flutter_rust_bridge
+                  ///
+                  /// The call to this function is optional, since flutter_rust_bridge (and everything else)
+                  /// is automatically disposed when the app stops.
+                  static void dispose() => instance.disposeImpl();
+
+                  @override
+                  ApiImplConstructor<RustLibApiImpl, RustLibWire> get apiImplConstructor => RustLibApiImpl.new;
+
+                  @override
+                  WireConstructor<RustLibWire> get wireConstructor => RustLibWire.fromExternalLibrary;
+
+                  @override
+                  Future<void> executeRustInitializers() async {
+                    await api.crateApiSimpleInitApp();
+
+                  }
+
+                  @override
+                  ExternalLibraryLoaderConfig get defaultExternalLibraryLoaderConfig => kDefaultExternalLibraryLoaderConfig;
+
+                  @override
+                  String get codegenVersion => '2.12.0';
+
+                  @override
+                  int get rustContentHash => -1344905961;
+
+                  static const kDefaultExternalLibraryLoaderConfig = ExternalLibraryLoaderConfig(
+                    stem: 'flutter_lindera_tantivy',
+                    ioDirectory: 'rust/target/release/',
+                    webPrefix: 'pkg/',
+                    wasmBindgenName: 'wasm_bindgen',
+                  );
+                }
+                
+
+                abstract class RustLibApi extends BaseApi {
+                  String crateApiSearchAddDocument({required String title , required String body , required String metadataJson });
+
+String crateApiSearchAddDocuments({required List<DocumentInput> documents });
+
+List<Suggestion> crateApiSearchAutocomplete({required String prefix , required BigInt limit , required SuggestionSource source });
+
+String crateApiSearchClearAllDocuments();
+
+String crateApiSearchConfigureChosungFields({required bool title , required bool body });
+
+String crateApiSearchConfigureDateFields({required List<String> paths });
+
+String crateApiSearchConfigureHanjaReadings({required bool enabled });
+
+String crateApiSearchConfigureJapaneseReadings({required bool enabled , required bool romaji });
+
+String crateApiSearchConfigureLocationField({required String path });
+
+String crateApiSearchConfigurePinyin({required bool enabled });
+
+String crateApiSearchConfigureVariantFolding({required List<String> fields , required List<VariantMapping> mappings });
+
+BigInt crateApiSearchCountDocuments({required String queryStr , required SearchOptions options });
+
+BigInt crateApiSearchCountWithQuery({required QueryNode query , required SearchOptions options });
+
+String crateApiSearchDeleteDocument({required String id });
+
+String crateApiSearchDeleteDocuments({required List<String> ids });
+
+Future<void> crateApiSearchDictionaryTypeToEmbeddedPath({required DictionaryType that });
+
+Future<void> crateApiSearchDictionaryTypeToTokenizerName({required DictionaryType that });
+
+bool crateApiSearchDocumentExists({required String id });
+
+ScoreExplanation crateApiSearchExplainDocument({required String queryStr , required String id , required SearchOptions options });
+
+ScoreExplanation crateApiSearchExplainStructuredQuery({required QueryNode query , required String id , required SearchOptions options });
+
+Future<FieldSelection> crateApiProjectionFieldSelectionDefault();
+
+Future<FuzzyOptions> crateApiSearchFuzzyOptionsDefault();
+
+StoredDocument? crateApiSearchGetDocument({required String id });
+
+BigInt crateApiSearchGetDocumentCount();
+
+List<StoredDocument> crateApiSearchGetDocuments({required List<String> ids });
+
+String crateApiSimpleGreet({required String name });
+
+String crateApiSearchIndexSampleDocuments();
+
+Future<void> crateApiSimpleInitApp();
+
+String crateApiSearchInitializeSearchIndex({required DictionaryType dictionaryType });
+
+String crateApiSearchInitializeSearchIndexWithPath({required DictionaryType dictionaryType , required String indexPath });
+
+SearchResponse crateApiSearchMoreLikeThis({required MoreLikeThisSource source , required MoreLikeThisOptions mltOptions , required SearchOptions options });
+
+Future<MoreLikeThisOptions> crateApiMoreLikeThisMoreLikeThisOptionsDefault();
+
+DocumentPage crateApiSearchScanDocuments({String? cursor , required BigInt limit });
+
+Future<String> crateApiExplainScoreExplanationToPrettyString({required ScoreExplanation that });
+
+SearchResponse crateApiSearchSearchAsYouType({required String queryStr , required SearchOptions options });
+
+List<SearchResult> crateApiSearchSearchDocuments({required String queryStr , required BigInt limit });
+
+SearchResponse crateApiSearchSearchDocumentsWithOptions({required String queryStr , required SearchOptions options });
+
+Future<SearchOptions> crateApiSearchSearchOptionsDefault();
+
+SearchResponse crateApiSearchSearchWithQuery({required QueryNode query , required SearchOptions options });
+
+Future<SnippetOptions> crateApiSearchSnippetOptionsDefault();
+
+List<String> crateApiSearchTokenizeText({required DictionaryType dictionaryType , required String text , required TokenMode mode });
+
+List<TokenDetail> crateApiSearchTokenizeTextDetailed({required DictionaryType dictionaryType , required String text , required TokenMode mode });
+
+String crateApiSearchUpdateDocument({required String id , required String title , required String body , required String metadataJson });
+
+
+                }
+                
+
+                class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
+                  RustLibApiImpl({
+                    required super.handler,
+                    required super.wire,
+                    required super.generalizedFrbRustBinding,
+                    required super.portManager,
+                  });
+
+                  @override String crateApiSearchAddDocument({required String title , required String body , required String metadataJson })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(title, serializer);
+sse_encode_String(body, serializer);
+sse_encode_String(metadataJson, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 1)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSearchAddDocumentConstMeta,
+            argValues: [title, body, metadataJson],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSearchAddDocumentConstMeta => const TaskConstMeta(
+            debugName: "add_document",
+            argNames: ["title", "body", "metadataJson"],
+        );
+        
+
+@override String crateApiSearchAddDocuments({required List<DocumentInput> documents })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_document_input(documents, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 2)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSearchAddDocumentsConstMeta,
+            argValues: [documents],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSearchAddDocumentsConstMeta => const TaskConstMeta(
+            debugName: "add_documents",
+            argNames: ["documents"],
+        );
+        
+
+@override List<Suggestion> crateApiSearchAutocomplete({required String prefix , required BigInt limit , required SuggestionSource source })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(prefix, serializer);
+sse_encode_usize(limit, serializer);
+sse_encode_suggestion_source(source, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 3)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_suggestion,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSearchAutocompleteConstMeta,
+            argValues: [prefix, limit, source],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSearchAutocompleteConstMeta => const TaskConstMeta(
+            debugName: "autocomplete",
+            argNames: ["prefix", "limit", "source"],
+        );
+        
+
+@override String crateApiSearchClearAllDocuments()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 4)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSearchClearAllDocumentsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSearchClearAllDocumentsConstMeta => const TaskConstMeta(
+            debugName: "clear_all_documents",
+            argNames: [],
+        );
+        
+
+@override String crateApiSearchConfigureChosungFields({required bool title , required bool body })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_bool(title, serializer);
+sse_encode_bool(body, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 5)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSearchConfigureChosungFieldsConstMeta,
+            argValues: [title, body],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSearchConfigureChosungFieldsConstMeta => const TaskConstMeta(
+            debugName: "configure_chosung_fields",
+            argNames: ["title", "body"],
+        );
+        
+
+@override String crateApiSearchConfigureDateFields({required List<String> paths })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_String(paths, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 6)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiSearchAddDocumentConstMeta,
-        argValues: [title, body, metadataJson],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSearchAddDocumentConstMeta => const TaskConstMeta(
-    debugName: "add_document",
-    argNames: ["title", "body", "metadataJson"],
-  );
-
-  @override
-  String crateApiSearchAddDocuments({required List<DocumentInput> documents}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_document_input(documents, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 2)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiSearchConfigureDateFieldsConstMeta,
+            argValues: [paths],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSearchConfigureDateFieldsConstMeta => const TaskConstMeta(
+            debugName: "configure_date_fields",
+            argNames: ["paths"],
+        );
+        
+
+@override String crateApiSearchConfigureHanjaReadings({required bool enabled })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_bool(enabled, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 7)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiSearchAddDocumentsConstMeta,
-        argValues: [documents],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSearchAddDocumentsConstMeta =>
-      const TaskConstMeta(debugName: "add_documents", argNames: ["documents"]);
-
-  @override
-  String crateApiSearchClearAllDocuments() {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 3)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiSearchConfigureHanjaReadingsConstMeta,
+            argValues: [enabled],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSearchConfigureHanjaReadingsConstMeta => const TaskConstMeta(
+            debugName: "configure_hanja_readings",
+            argNames: ["enabled"],
+        );
+        
+
+@override String crateApiSearchConfigureJapaneseReadings({required bool enabled , required bool romaji })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_bool(enabled, serializer);
+sse_encode_bool(romaji, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 8)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiSearchClearAllDocumentsConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSearchClearAllDocumentsConstMeta =>
-      const TaskConstMeta(debugName: "clear_all_documents", argNames: []);
-
-  @override
-  String crateApiSearchDeleteDocument({required String id}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(id, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 4)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiSearchConfigureJapaneseReadingsConstMeta,
+            argValues: [enabled, romaji],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSearchConfigureJapaneseReadingsConstMeta => const TaskConstMeta(
+            debugName: "configure_japanese_readings",
+            argNames: ["enabled", "romaji"],
+        );
+        
+
+@override String crateApiSearchConfigureLocationField({required String path })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 9)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiSearchDeleteDocumentConstMeta,
-        argValues: [id],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSearchDeleteDocumentConstMeta =>
-      const TaskConstMeta(debugName: "delete_document", argNames: ["id"]);
-
-  @override
-  String crateApiSearchDeleteDocuments({required List<String> ids}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_String(ids, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 5)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiSearchConfigureLocationFieldConstMeta,
+            argValues: [path],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSearchConfigureLocationFieldConstMeta => const TaskConstMeta(
+            debugName: "configure_location_field",
+            argNames: ["path"],
+        );
+        
+
+@override String crateApiSearchConfigurePinyin({required bool enabled })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_bool(enabled, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 10)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiSearchDeleteDocumentsConstMeta,
-        argValues: [ids],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSearchDeleteDocumentsConstMeta =>
-      const TaskConstMeta(debugName: "delete_documents", argNames: ["ids"]);
-
-  @override
-  Future<void> crateApiSearchDictionaryTypeToEmbeddedPath({
-    required DictionaryType that,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_dictionary_type(that, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 6,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiSearchConfigurePinyinConstMeta,
+            argValues: [enabled],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSearchConfigurePinyinConstMeta => const TaskConstMeta(
+            debugName: "configure_pinyin",
+            argNames: ["enabled"],
+        );
+        
+
+@override String crateApiSearchConfigureVariantFolding({required List<String> fields , required List<VariantMapping> mappings })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_String(fields, serializer);
+sse_encode_list_variant_mapping(mappings, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 11)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSearchConfigureVariantFoldingConstMeta,
+            argValues: [fields, mappings],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSearchConfigureVariantFoldingConstMeta => const TaskConstMeta(
+            debugName: "configure_variant_folding",
+            argNames: ["fields", "mappings"],
+        );
+        
+
+@override BigInt crateApiSearchCountDocuments({required String queryStr , required SearchOptions options })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(queryStr, serializer);
+sse_encode_box_autoadd_search_options(options, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 12)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSearchCountDocumentsConstMeta,
+            argValues: [queryStr, options],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSearchCountDocumentsConstMeta => const TaskConstMeta(
+            debugName: "count_documents",
+            argNames: ["queryStr", "options"],
+        );
+        
+
+@override BigInt crateApiSearchCountWithQuery({required QueryNode query , required SearchOptions options })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_query_node(query, serializer);
+sse_encode_box_autoadd_search_options(options, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 13)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSearchCountWithQueryConstMeta,
+            argValues: [query, options],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSearchCountWithQueryConstMeta => const TaskConstMeta(
+            debugName: "count_with_query",
+            argNames: ["query", "options"],
+        );
+        
+
+@override String crateApiSearchDeleteDocument({required String id })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(id, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 14)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSearchDeleteDocumentConstMeta,
+            argValues: [id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSearchDeleteDocumentConstMeta => const TaskConstMeta(
+            debugName: "delete_document",
+            argNames: ["id"],
+        );
+        
+
+@override String crateApiSearchDeleteDocuments({required List<String> ids })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_String(ids, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 15)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSearchDeleteDocumentsConstMeta,
+            argValues: [ids],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSearchDeleteDocumentsConstMeta => const TaskConstMeta(
+            debugName: "delete_documents",
+            argNames: ["ids"],
+        );
+        
+
+@override Future<void> crateApiSearchDictionaryTypeToEmbeddedPath({required DictionaryType that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_dictionary_type(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 16, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiSearchDictionaryTypeToEmbeddedPathConstMeta,
-        argValues: [that],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSearchDictionaryTypeToEmbeddedPathConstMeta =>
-      const TaskConstMeta(
-        debugName: "dictionary_type_to_embedded_path",
-        argNames: ["that"],
-      );
-
-  @override
-  Future<void> crateApiSearchDictionaryTypeToTokenizerName({
-    required DictionaryType that,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_dictionary_type(that, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 7,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiSearchDictionaryTypeToEmbeddedPathConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSearchDictionaryTypeToEmbeddedPathConstMeta => const TaskConstMeta(
+            debugName: "dictionary_type_to_embedded_path",
+            argNames: ["that"],
+        );
+        
+
+@override Future<void> crateApiSearchDictionaryTypeToTokenizerName({required DictionaryType that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_dictionary_type(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 17, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiSearchDictionaryTypeToTokenizerNameConstMeta,
-        argValues: [that],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSearchDictionaryTypeToTokenizerNameConstMeta =>
-      const TaskConstMeta(
-        debugName: "dictionary_type_to_tokenizer_name",
-        argNames: ["that"],
-      );
-
-  @override
-  BigInt crateApiSearchGetDocumentCount() {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 8)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiSearchDictionaryTypeToTokenizerNameConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSearchDictionaryTypeToTokenizerNameConstMeta => const TaskConstMeta(
+            debugName: "dictionary_type_to_tokenizer_name",
+            argNames: ["that"],
+        );
+        
+
+@override bool crateApiSearchDocumentExists({required String id })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(id, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 18)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSearchDocumentExistsConstMeta,
+            argValues: [id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSearchDocumentExistsConstMeta => const TaskConstMeta(
+            debugName: "document_exists",
+            argNames: ["id"],
+        );
+        
+
+@override ScoreExplanation crateApiSearchExplainDocument({required String queryStr , required String id , required SearchOptions options })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(queryStr, serializer);
+sse_encode_String(id, serializer);
+sse_encode_box_autoadd_search_options(options, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 19)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_score_explanation,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSearchExplainDocumentConstMeta,
+            argValues: [queryStr, id, options],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSearchExplainDocumentConstMeta => const TaskConstMeta(
+            debugName: "explain_document",
+            argNames: ["queryStr", "id", "options"],
+        );
+        
+
+@override ScoreExplanation crateApiSearchExplainStructuredQuery({required QueryNode query , required String id , required SearchOptions options })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_query_node(query, serializer);
+sse_encode_String(id, serializer);
+sse_encode_box_autoadd_search_options(options, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 20)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_score_explanation,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSearchExplainStructuredQueryConstMeta,
+            argValues: [query, id, options],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSearchExplainStructuredQueryConstMeta => const TaskConstMeta(
+            debugName: "explain_structured_query",
+            argNames: ["query", "id", "options"],
+        );
+        
+
+@override Future<FieldSelection> crateApiProjectionFieldSelectionDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 21, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_field_selection,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiProjectionFieldSelectionDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProjectionFieldSelectionDefaultConstMeta => const TaskConstMeta(
+            debugName: "field_selection_default",
+            argNames: [],
+        );
+        
+
+@override Future<FuzzyOptions> crateApiSearchFuzzyOptionsDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 22, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_fuzzy_options,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSearchFuzzyOptionsDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSearchFuzzyOptionsDefaultConstMeta => const TaskConstMeta(
+            debugName: "fuzzy_options_default",
+            argNames: [],
+        );
+        
+
+@override StoredDocument? crateApiSearchGetDocument({required String id })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(id, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 23)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_stored_document,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSearchGetDocumentConstMeta,
+            argValues: [id],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSearchGetDocumentConstMeta => const TaskConstMeta(
+            debugName: "get_document",
+            argNames: ["id"],
+        );
+        
+
+@override BigInt crateApiSearchGetDocumentCount()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 24)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_u_64,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiSearchGetDocumentCountConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSearchGetDocumentCountConstMeta =>
-      const TaskConstMeta(debugName: "get_document_count", argNames: []);
-
-  @override
-  String crateApiSimpleGreet({required String name}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(name, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 9)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiSearchGetDocumentCountConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSearchGetDocumentCountConstMeta => const TaskConstMeta(
+            debugName: "get_document_count",
+            argNames: [],
+        );
+        
+
+@override List<StoredDocument> crateApiSearchGetDocuments({required List<String> ids })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_String(ids, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 25)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_stored_document,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSearchGetDocumentsConstMeta,
+            argValues: [ids],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSearchGetDocumentsConstMeta => const TaskConstMeta(
+            debugName: "get_documents",
+            argNames: ["ids"],
+        );
+        
+
+@override String crateApiSimpleGreet({required String name })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(name, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 26)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiSimpleGreetConstMeta,
-        argValues: [name],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSimpleGreetConstMeta =>
-      const TaskConstMeta(debugName: "greet", argNames: ["name"]);
-
-  @override
-  String crateApiSearchIndexSampleDocuments() {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 10)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiSimpleGreetConstMeta,
+            argValues: [name],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSimpleGreetConstMeta => const TaskConstMeta(
+            debugName: "greet",
+            argNames: ["name"],
+        );
+        
+
+@override String crateApiSearchIndexSampleDocuments()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 27)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiSearchIndexSampleDocumentsConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSearchIndexSampleDocumentsConstMeta =>
-      const TaskConstMeta(debugName: "index_sample_documents", argNames: []);
-
-  @override
-  Future<void> crateApiSimpleInitApp() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 11,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiSearchIndexSampleDocumentsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSearchIndexSampleDocumentsConstMeta => const TaskConstMeta(
+            debugName: "index_sample_documents",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiSimpleInitApp()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 28, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiSimpleInitAppConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSimpleInitAppConstMeta =>
-      const TaskConstMeta(debugName: "init_app", argNames: []);
-
-  @override
-  String crateApiSearchInitializeSearchIndex({
-    required DictionaryType dictionaryType,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_dictionary_type(dictionaryType, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 12)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiSimpleInitAppConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSimpleInitAppConstMeta => const TaskConstMeta(
+            debugName: "init_app",
+            argNames: [],
+        );
+        
+
+@override String crateApiSearchInitializeSearchIndex({required DictionaryType dictionaryType })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_dictionary_type(dictionaryType, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 29)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiSearchInitializeSearchIndexConstMeta,
-        argValues: [dictionaryType],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSearchInitializeSearchIndexConstMeta =>
-      const TaskConstMeta(
-        debugName: "initialize_search_index",
-        argNames: ["dictionaryType"],
-      );
-
-  @override
-  String crateApiSearchInitializeSearchIndexWithPath({
-    required DictionaryType dictionaryType,
-    required String indexPath,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_dictionary_type(dictionaryType, serializer);
-          sse_encode_String(indexPath, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 13)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiSearchInitializeSearchIndexConstMeta,
+            argValues: [dictionaryType],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSearchInitializeSearchIndexConstMeta => const TaskConstMeta(
+            debugName: "initialize_search_index",
+            argNames: ["dictionaryType"],
+        );
+        
+
+@override String crateApiSearchInitializeSearchIndexWithPath({required DictionaryType dictionaryType , required String indexPath })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_dictionary_type(dictionaryType, serializer);
+sse_encode_String(indexPath, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 30)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSearchInitializeSearchIndexWithPathConstMeta,
+            argValues: [dictionaryType, indexPath],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSearchInitializeSearchIndexWithPathConstMeta => const TaskConstMeta(
+            debugName: "initialize_search_index_with_path",
+            argNames: ["dictionaryType", "indexPath"],
+        );
+        
+
+@override SearchResponse crateApiSearchMoreLikeThis({required MoreLikeThisSource source , required MoreLikeThisOptions mltOptions , required SearchOptions options })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_more_like_this_source(source, serializer);
+sse_encode_box_autoadd_more_like_this_options(mltOptions, serializer);
+sse_encode_box_autoadd_search_options(options, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 31)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_search_response,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSearchMoreLikeThisConstMeta,
+            argValues: [source, mltOptions, options],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSearchMoreLikeThisConstMeta => const TaskConstMeta(
+            debugName: "more_like_this",
+            argNames: ["source", "mltOptions", "options"],
+        );
+        
+
+@override Future<MoreLikeThisOptions> crateApiMoreLikeThisMoreLikeThisOptionsDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 32, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_more_like_this_options,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiMoreLikeThisMoreLikeThisOptionsDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMoreLikeThisMoreLikeThisOptionsDefaultConstMeta => const TaskConstMeta(
+            debugName: "more_like_this_options_default",
+            argNames: [],
+        );
+        
+
+@override DocumentPage crateApiSearchScanDocuments({String? cursor , required BigInt limit })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_opt_String(cursor, serializer);
+sse_encode_usize(limit, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 33)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_document_page,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSearchScanDocumentsConstMeta,
+            argValues: [cursor, limit],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSearchScanDocumentsConstMeta => const TaskConstMeta(
+            debugName: "scan_documents",
+            argNames: ["cursor", "limit"],
+        );
+        
+
+@override Future<String> crateApiExplainScoreExplanationToPrettyString({required ScoreExplanation that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_score_explanation(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 34, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiExplainScoreExplanationToPrettyStringConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiExplainScoreExplanationToPrettyStringConstMeta => const TaskConstMeta(
+            debugName: "score_explanation_to_pretty_string",
+            argNames: ["that"],
+        );
+        
+
+@override SearchResponse crateApiSearchSearchAsYouType({required String queryStr , required SearchOptions options })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(queryStr, serializer);
+sse_encode_box_autoadd_search_options(options, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 35)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_search_response,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiSearchInitializeSearchIndexWithPathConstMeta,
-        argValues: [dictionaryType, indexPath],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSearchInitializeSearchIndexWithPathConstMeta =>
-      const TaskConstMeta(
-        debugName: "initialize_search_index_with_path",
-        argNames: ["dictionaryType", "indexPath"],
-      );
-
-  @override
-  List<SearchResult> crateApiSearchSearchDocuments({
-    required String queryStr,
-    required BigInt limit,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(queryStr, serializer);
-          sse_encode_usize(limit, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 14)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiSearchSearchAsYouTypeConstMeta,
+            argValues: [queryStr, options],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSearchSearchAsYouTypeConstMeta => const TaskConstMeta(
+            debugName: "search_as_you_type",
+            argNames: ["queryStr", "options"],
+        );
+        
+
+@override List<SearchResult> crateApiSearchSearchDocuments({required String queryStr , required BigInt limit })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(queryStr, serializer);
+sse_encode_usize(limit, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 36)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_list_search_result,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiSearchSearchDocumentsConstMeta,
-        argValues: [queryStr, limit],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSearchSearchDocumentsConstMeta =>
-      const TaskConstMeta(
-        debugName: "search_documents",
-        argNames: ["queryStr", "limit"],
-      );
-
-  @override
-  List<String> crateApiSearchTokenizeText({
-    required DictionaryType dictionaryType,
-    required String text,
-    required TokenMode mode,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_dictionary_type(dictionaryType, serializer);
-          sse_encode_String(text, serializer);
-          sse_encode_token_mode(mode, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 15)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiSearchSearchDocumentsConstMeta,
+            argValues: [queryStr, limit],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSearchSearchDocumentsConstMeta => const TaskConstMeta(
+            debugName: "search_documents",
+            argNames: ["queryStr", "limit"],
+        );
+        
+
+@override SearchResponse crateApiSearchSearchDocumentsWithOptions({required String queryStr , required SearchOptions options })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(queryStr, serializer);
+sse_encode_box_autoadd_search_options(options, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 37)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_search_response,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSearchSearchDocumentsWithOptionsConstMeta,
+            argValues: [queryStr, options],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSearchSearchDocumentsWithOptionsConstMeta => const TaskConstMeta(
+            debugName: "search_documents_with_options",
+            argNames: ["queryStr", "options"],
+        );
+        
+
+@override Future<SearchOptions> crateApiSearchSearchOptionsDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 38, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_search_options,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSearchSearchOptionsDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSearchSearchOptionsDefaultConstMeta => const TaskConstMeta(
+            debugName: "search_options_default",
+            argNames: [],
+        );
+        
+
+@override SearchResponse crateApiSearchSearchWithQuery({required QueryNode query , required SearchOptions options })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_query_node(query, serializer);
+sse_encode_box_autoadd_search_options(options, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 39)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_search_response,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiSearchSearchWithQueryConstMeta,
+            argValues: [query, options],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSearchSearchWithQueryConstMeta => const TaskConstMeta(
+            debugName: "search_with_query",
+            argNames: ["query", "options"],
+        );
+        
+
+@override Future<SnippetOptions> crateApiSearchSnippetOptionsDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 40, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_snippet_options,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiSearchSnippetOptionsDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSearchSnippetOptionsDefaultConstMeta => const TaskConstMeta(
+            debugName: "snippet_options_default",
+            argNames: [],
+        );
+        
+
+@override List<String> crateApiSearchTokenizeText({required DictionaryType dictionaryType , required String text , required TokenMode mode })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_dictionary_type(dictionaryType, serializer);
+sse_encode_String(text, serializer);
+sse_encode_token_mode(mode, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 41)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_list_String,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiSearchTokenizeTextConstMeta,
-        argValues: [dictionaryType, text, mode],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSearchTokenizeTextConstMeta => const TaskConstMeta(
-    debugName: "tokenize_text",
-    argNames: ["dictionaryType", "text", "mode"],
-  );
-
-  @override
-  List<TokenDetail> crateApiSearchTokenizeTextDetailed({
-    required DictionaryType dictionaryType,
-    required String text,
-    required TokenMode mode,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_dictionary_type(dictionaryType, serializer);
-          sse_encode_String(text, serializer);
-          sse_encode_token_mode(mode, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 16)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiSearchTokenizeTextConstMeta,
+            argValues: [dictionaryType, text, mode],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSearchTokenizeTextConstMeta => const TaskConstMeta(
+            debugName: "tokenize_text",
+            argNames: ["dictionaryType", "text", "mode"],
+        );
+        
+
+@override List<TokenDetail> crateApiSearchTokenizeTextDetailed({required DictionaryType dictionaryType , required String text , required TokenMode mode })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_dictionary_type(dictionaryType, serializer);
+sse_encode_String(text, serializer);
+sse_encode_token_mode(mode, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 42)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_list_token_detail,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiSearchTokenizeTextDetailedConstMeta,
-        argValues: [dictionaryType, text, mode],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSearchTokenizeTextDetailedConstMeta =>
-      const TaskConstMeta(
-        debugName: "tokenize_text_detailed",
-        argNames: ["dictionaryType", "text", "mode"],
-      );
-
-  @override
-  String crateApiSearchUpdateDocument({
-    required String id,
-    required String title,
-    required String body,
-    required String metadataJson,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(id, serializer);
-          sse_encode_String(title, serializer);
-          sse_encode_String(body, serializer);
-          sse_encode_String(metadataJson, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 17)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiSearchTokenizeTextDetailedConstMeta,
+            argValues: [dictionaryType, text, mode],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSearchTokenizeTextDetailedConstMeta => const TaskConstMeta(
+            debugName: "tokenize_text_detailed",
+            argNames: ["dictionaryType", "text", "mode"],
+        );
+        
+
+@override String crateApiSearchUpdateDocument({required String id , required String title , required String body , required String metadataJson })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(id, serializer);
+sse_encode_String(title, serializer);
+sse_encode_String(body, serializer);
+sse_encode_String(metadataJson, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 43)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiSearchUpdateDocumentConstMeta,
-        argValues: [id, title, body, metadataJson],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSearchUpdateDocumentConstMeta =>
-      const TaskConstMeta(
-        debugName: "update_document",
-        argNames: ["id", "title", "body", "metadataJson"],
-      );
-
-  @protected
-  String dco_decode_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
-  }
-
-  @protected
-  DictionaryType dco_decode_dictionary_type(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return DictionaryType.values[raw as int];
-  }
-
-  @protected
-  DocumentInput dco_decode_document_input(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-    return DocumentInput(
-      id: dco_decode_String(arr[0]),
-      title: dco_decode_String(arr[1]),
-      body: dco_decode_String(arr[2]),
-      metadata: dco_decode_String(arr[3]),
-    );
-  }
-
-  @protected
-  double dco_decode_f_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as double;
-  }
-
-  @protected
-  int dco_decode_i_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  List<String> dco_decode_list_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_String).toList();
-  }
-
-  @protected
-  List<DocumentInput> dco_decode_list_document_input(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_document_input).toList();
-  }
-
-  @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
-  }
-
-  @protected
-  List<SearchResult> dco_decode_list_search_result(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_search_result).toList();
-  }
-
-  @protected
-  List<TokenDetail> dco_decode_list_token_detail(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_token_detail).toList();
-  }
-
-  @protected
-  SearchResult dco_decode_search_result(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 5)
-      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
-    return SearchResult(
-      id: dco_decode_String(arr[0]),
-      title: dco_decode_String(arr[1]),
-      body: dco_decode_String(arr[2]),
-      score: dco_decode_f_32(arr[3]),
-      metadata: dco_decode_String(arr[4]),
-    );
-  }
-
-  @protected
-  TokenDetail dco_decode_token_detail(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return TokenDetail(
-      surface: dco_decode_String(arr[0]),
-      pos: dco_decode_String(arr[1]),
-      details: dco_decode_list_String(arr[2]),
-    );
-  }
-
-  @protected
-  TokenMode dco_decode_token_mode(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return TokenMode.values[raw as int];
-  }
-
-  @protected
-  BigInt dco_decode_u_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
-  }
-
-  @protected
-  int dco_decode_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  void dco_decode_unit(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return;
-  }
-
-  @protected
-  BigInt dco_decode_usize(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
-  }
-
-  @protected
-  String sse_decode_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
-  }
-
-  @protected
-  DictionaryType sse_decode_dictionary_type(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_i_32(deserializer);
-    return DictionaryType.values[inner];
-  }
-
-  @protected
-  DocumentInput sse_decode_document_input(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_id = sse_decode_String(deserializer);
-    var var_title = sse_decode_String(deserializer);
-    var var_body = sse_decode_String(deserializer);
-    var var_metadata = sse_decode_String(deserializer);
-    return DocumentInput(
-      id: var_id,
-      title: var_title,
-      body: var_body,
-      metadata: var_metadata,
-    );
-  }
-
-  @protected
-  double sse_decode_f_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getFloat32();
-  }
-
-  @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
-  }
-
-  @protected
-  List<String> sse_decode_list_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <String>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_String(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<DocumentInput> sse_decode_list_document_input(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <DocumentInput>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_document_input(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
-  }
-
-  @protected
-  List<SearchResult> sse_decode_list_search_result(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <SearchResult>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_search_result(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<TokenDetail> sse_decode_list_token_detail(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <TokenDetail>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_token_detail(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  SearchResult sse_decode_search_result(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_id = sse_decode_String(deserializer);
-    var var_title = sse_decode_String(deserializer);
-    var var_body = sse_decode_String(deserializer);
-    var var_score = sse_decode_f_32(deserializer);
-    var var_metadata = sse_decode_String(deserializer);
-    return SearchResult(
-      id: var_id,
-      title: var_title,
-      body: var_body,
-      score: var_score,
-      metadata: var_metadata,
-    );
-  }
-
-  @protected
-  TokenDetail sse_decode_token_detail(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_surface = sse_decode_String(deserializer);
-    var var_pos = sse_decode_String(deserializer);
-    var var_details = sse_decode_list_String(deserializer);
-    return TokenDetail(
-      surface: var_surface,
-      pos: var_pos,
-      details: var_details,
-    );
-  }
-
-  @protected
-  TokenMode sse_decode_token_mode(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_i_32(deserializer);
-    return TokenMode.values[inner];
-  }
-
-  @protected
-  BigInt sse_decode_u_64(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getBigUint64();
-  }
-
-  @protected
-  int sse_decode_u_8(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8();
-  }
-
-  @protected
-  void sse_decode_unit(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-  }
-
-  @protected
-  BigInt sse_decode_usize(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getBigUint64();
-  }
-
-  @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
-  }
-
-  @protected
-  void sse_encode_String(String self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
-  }
-
-  @protected
-  void sse_encode_dictionary_type(
-    DictionaryType self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.index, serializer);
-  }
-
-  @protected
-  void sse_encode_document_input(DocumentInput self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.id, serializer);
-    sse_encode_String(self.title, serializer);
-    sse_encode_String(self.body, serializer);
-    sse_encode_String(self.metadata, serializer);
-  }
-
-  @protected
-  void sse_encode_f_32(double self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putFloat32(self);
-  }
-
-  @protected
-  void sse_encode_i_32(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putInt32(self);
-  }
-
-  @protected
-  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_String(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_document_input(
-    List<DocumentInput> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_document_input(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(self);
-  }
-
-  @protected
-  void sse_encode_list_search_result(
-    List<SearchResult> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_search_result(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_token_detail(
-    List<TokenDetail> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_token_detail(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_search_result(SearchResult self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.id, serializer);
-    sse_encode_String(self.title, serializer);
-    sse_encode_String(self.body, serializer);
-    sse_encode_f_32(self.score, serializer);
-    sse_encode_String(self.metadata, serializer);
-  }
-
-  @protected
-  void sse_encode_token_detail(TokenDetail self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.surface, serializer);
-    sse_encode_String(self.pos, serializer);
-    sse_encode_list_String(self.details, serializer);
-  }
-
-  @protected
-  void sse_encode_token_mode(TokenMode self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.index, serializer);
-  }
-
-  @protected
-  void sse_encode_u_64(BigInt self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putBigUint64(self);
-  }
-
-  @protected
-  void sse_encode_u_8(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self);
-  }
-
-  @protected
-  void sse_encode_unit(void self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-  }
-
-  @protected
-  void sse_encode_usize(BigInt self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putBigUint64(self);
-  }
-
-  @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
-  }
-}
+        )
+        ,
+            constMeta: kCrateApiSearchUpdateDocumentConstMeta,
+            argValues: [id, title, body, metadataJson],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSearchUpdateDocumentConstMeta => const TaskConstMeta(
+            debugName: "update_document",
+            argNames: ["id", "title", "body", "metadataJson"],
+        );
+        
+
+
+
+                  @protected String dco_decode_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as String; }
+
+@protected AggregationBucket dco_decode_aggregation_bucket(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return AggregationBucket(key: dco_decode_String(arr[0]),
+docCount: dco_decode_u_64(arr[1]),
+from: dco_decode_opt_box_autoadd_f_64(arr[2]),
+to: dco_decode_opt_box_autoadd_f_64(arr[3]),); }
+
+@protected AggregationKind dco_decode_aggregation_kind(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return AggregationKind_Terms(field: dco_decode_String(raw[1]),size: dco_decode_u_32(raw[2]),);
+case 1: return AggregationKind_Range(field: dco_decode_String(raw[1]),ranges: dco_decode_list_aggregation_range(raw[2]),);
+case 2: return AggregationKind_Histogram(field: dco_decode_String(raw[1]),interval: dco_decode_f_64(raw[2]),);
+case 3: return AggregationKind_DateHistogram(field: dco_decode_String(raw[1]),fixedInterval: dco_decode_String(raw[2]),);
+case 4: return AggregationKind_Min(field: dco_decode_String(raw[1]),);
+case 5: return AggregationKind_Max(field: dco_decode_String(raw[1]),);
+case 6: return AggregationKind_Avg(field: dco_decode_String(raw[1]),);
+case 7: return AggregationKind_Stats(field: dco_decode_String(raw[1]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected AggregationRange dco_decode_aggregation_range(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return AggregationRange(from: dco_decode_opt_box_autoadd_f_64(arr[0]),
+to: dco_decode_opt_box_autoadd_f_64(arr[1]),); }
+
+@protected AggregationRequest dco_decode_aggregation_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return AggregationRequest(name: dco_decode_String(arr[0]),
+kind: dco_decode_aggregation_kind(arr[1]),); }
+
+@protected AggregationResult dco_decode_aggregation_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return AggregationResult(name: dco_decode_String(arr[0]),
+value: dco_decode_aggregation_value(arr[1]),); }
+
+@protected AggregationStats dco_decode_aggregation_stats(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return AggregationStats(count: dco_decode_u_64(arr[0]),
+sum: dco_decode_f_64(arr[1]),
+min: dco_decode_opt_box_autoadd_f_64(arr[2]),
+max: dco_decode_opt_box_autoadd_f_64(arr[3]),
+avg: dco_decode_opt_box_autoadd_f_64(arr[4]),); }
+
+@protected AggregationValue dco_decode_aggregation_value(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return AggregationValue_Buckets(dco_decode_list_aggregation_bucket(raw[1]),);
+case 1: return AggregationValue_Metric(dco_decode_opt_box_autoadd_f_64(raw[1]),);
+case 2: return AggregationValue_Stats(dco_decode_box_autoadd_aggregation_stats(raw[1]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected bool dco_decode_bool(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as bool; }
+
+@protected AggregationStats dco_decode_box_autoadd_aggregation_stats(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_aggregation_stats(raw); }
+
+@protected double dco_decode_box_autoadd_f_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as double; }
+
+@protected FuzzyOptions dco_decode_box_autoadd_fuzzy_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_fuzzy_options(raw); }
+
+@protected GeoFilter dco_decode_box_autoadd_geo_filter(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_geo_filter(raw); }
+
+@protected GeoPoint dco_decode_box_autoadd_geo_point(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_geo_point(raw); }
+
+@protected GroupByOptions dco_decode_box_autoadd_group_by_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_group_by_options(raw); }
+
+@protected PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_i_64(raw); }
+
+@protected MoreLikeThisOptions dco_decode_box_autoadd_more_like_this_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_more_like_this_options(raw); }
+
+@protected MoreLikeThisSource dco_decode_box_autoadd_more_like_this_source(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_more_like_this_source(raw); }
+
+@protected QueryNode dco_decode_box_autoadd_query_node(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_query_node(raw); }
+
+@protected RangeBound dco_decode_box_autoadd_range_bound(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_range_bound(raw); }
+
+@protected ScoreExplanation dco_decode_box_autoadd_score_explanation(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_score_explanation(raw); }
+
+@protected SearchOptions dco_decode_box_autoadd_search_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_search_options(raw); }
+
+@protected SearchSnippet dco_decode_box_autoadd_search_snippet(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_search_snippet(raw); }
+
+@protected SnippetOptions dco_decode_box_autoadd_snippet_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_snippet_options(raw); }
+
+@protected StoredDocument dco_decode_box_autoadd_stored_document(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_stored_document(raw); }
+
+@protected int dco_decode_box_autoadd_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected BigInt dco_decode_box_autoadd_usize(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_usize(raw); }
+
+@protected QueryNode dco_decode_box_query_node(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_query_node(raw); }
+
+@protected CategoryCount dco_decode_category_count(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return CategoryCount(path: dco_decode_String(arr[0]),
+count: dco_decode_u_64(arr[1]),); }
+
+@protected CategoryCounts dco_decode_category_counts(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return CategoryCounts(parent: dco_decode_String(arr[0]),
+children: dco_decode_list_category_count(arr[1]),); }
+
+@protected DecayFunction dco_decode_decay_function(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return DecayFunction.values[raw as int]; }
+
+@protected DictionaryType dco_decode_dictionary_type(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return DictionaryType.values[raw as int]; }
+
+@protected DocumentInput dco_decode_document_input(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return DocumentInput(id: dco_decode_String(arr[0]),
+title: dco_decode_String(arr[1]),
+body: dco_decode_String(arr[2]),
+metadata: dco_decode_String(arr[3]),); }
+
+@protected DocumentPage dco_decode_document_page(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return DocumentPage(documents: dco_decode_list_stored_document(arr[0]),
+nextCursor: dco_decode_opt_String(arr[1]),); }
+
+@protected double dco_decode_f_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as double; }
+
+@protected double dco_decode_f_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as double; }
+
+@protected FieldSelection dco_decode_field_selection(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return FieldSelection_All();
+case 1: return FieldSelection_IdOnly();
+case 2: return FieldSelection_Fields(dco_decode_list_String(raw[1]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected FuzzyOptions dco_decode_fuzzy_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return FuzzyOptions(distance: dco_decode_u_8(arr[0]),
+transposition: dco_decode_bool(arr[1]),
+prefixLength: dco_decode_usize(arr[2]),); }
+
+@protected GeoFilter dco_decode_geo_filter(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return GeoFilter_BoundingBox(topLeft: dco_decode_box_autoadd_geo_point(raw[1]),bottomRight: dco_decode_box_autoadd_geo_point(raw[2]),);
+case 1: return GeoFilter_Radius(center: dco_decode_box_autoadd_geo_point(raw[1]),distanceKm: dco_decode_f_64(raw[2]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected GeoPoint dco_decode_geo_point(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return GeoPoint(lat: dco_decode_f_64(arr[0]),
+lon: dco_decode_f_64(arr[1]),); }
+
+@protected GroupByOptions dco_decode_group_by_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return GroupByOptions(field: dco_decode_String(arr[0]),
+groupsLimit: dco_decode_usize(arr[1]),
+hitsPerGroup: dco_decode_usize(arr[2]),); }
+
+@protected HighlightRange dco_decode_highlight_range(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return HighlightRange(start: dco_decode_u_32(arr[0]),
+end: dco_decode_u_32(arr[1]),); }
+
+@protected int dco_decode_i_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected PlatformInt64 dco_decode_i_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeI64(raw); }
+
+@protected List<String> dco_decode_list_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_String).toList(); }
+
+@protected List<AggregationBucket> dco_decode_list_aggregation_bucket(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_aggregation_bucket).toList(); }
+
+@protected List<AggregationRange> dco_decode_list_aggregation_range(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_aggregation_range).toList(); }
+
+@protected List<AggregationRequest> dco_decode_list_aggregation_request(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_aggregation_request).toList(); }
+
+@protected List<AggregationResult> dco_decode_list_aggregation_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_aggregation_result).toList(); }
+
+@protected List<CategoryCount> dco_decode_list_category_count(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_category_count).toList(); }
+
+@protected List<CategoryCounts> dco_decode_list_category_counts(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_category_counts).toList(); }
+
+@protected List<DocumentInput> dco_decode_list_document_input(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_document_input).toList(); }
+
+@protected List<HighlightRange> dco_decode_list_highlight_range(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_highlight_range).toList(); }
+
+@protected Uint8List dco_decode_list_prim_u_8_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as Uint8List; }
+
+@protected List<QueryDiagnostic> dco_decode_list_query_diagnostic(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_query_diagnostic).toList(); }
+
+@protected List<QueryNode> dco_decode_list_query_node(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_query_node).toList(); }
+
+@protected List<ScoreExplanation> dco_decode_list_score_explanation(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_score_explanation).toList(); }
+
+@protected List<ScoreModifier> dco_decode_list_score_modifier(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_score_modifier).toList(); }
+
+@protected List<SearchGroup> dco_decode_list_search_group(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_search_group).toList(); }
+
+@protected List<SearchHit> dco_decode_list_search_hit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_search_hit).toList(); }
+
+@protected List<SearchResult> dco_decode_list_search_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_search_result).toList(); }
+
+@protected List<SortKey> dco_decode_list_sort_key(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_sort_key).toList(); }
+
+@protected List<SortValue> dco_decode_list_sort_value(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_sort_value).toList(); }
+
+@protected List<StoredDocument> dco_decode_list_stored_document(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_stored_document).toList(); }
+
+@protected List<Suggestion> dco_decode_list_suggestion(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_suggestion).toList(); }
+
+@protected List<TokenDetail> dco_decode_list_token_detail(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_token_detail).toList(); }
+
+@protected List<VariantMapping> dco_decode_list_variant_mapping(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_variant_mapping).toList(); }
+
+@protected MoreLikeThisOptions dco_decode_more_like_this_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return MoreLikeThisOptions(minDocFreq: dco_decode_u_64(arr[0]),
+minTermFreq: dco_decode_usize(arr[1]),
+maxQueryTerms: dco_decode_usize(arr[2]),); }
+
+@protected MoreLikeThisSource dco_decode_more_like_this_source(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return MoreLikeThisSource_DocumentId(dco_decode_String(raw[1]),);
+case 1: return MoreLikeThisSource_Text(dco_decode_String(raw[1]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected String? dco_decode_opt_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_String(raw); }
+
+@protected double? dco_decode_opt_box_autoadd_f_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_f_64(raw); }
+
+@protected FuzzyOptions? dco_decode_opt_box_autoadd_fuzzy_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_fuzzy_options(raw); }
+
+@protected GeoFilter? dco_decode_opt_box_autoadd_geo_filter(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_geo_filter(raw); }
+
+@protected GroupByOptions? dco_decode_opt_box_autoadd_group_by_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_group_by_options(raw); }
+
+@protected PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_i_64(raw); }
+
+@protected RangeBound? dco_decode_opt_box_autoadd_range_bound(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_range_bound(raw); }
+
+@protected SearchSnippet? dco_decode_opt_box_autoadd_search_snippet(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_search_snippet(raw); }
+
+@protected SnippetOptions? dco_decode_opt_box_autoadd_snippet_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_snippet_options(raw); }
+
+@protected StoredDocument? dco_decode_opt_box_autoadd_stored_document(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_stored_document(raw); }
+
+@protected int? dco_decode_opt_box_autoadd_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_u_32(raw); }
+
+@protected BigInt? dco_decode_opt_box_autoadd_usize(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_usize(raw); }
+
+@protected QueryDiagnostic dco_decode_query_diagnostic(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return QueryDiagnostic(position: dco_decode_opt_box_autoadd_u_32(arr[0]),
+message: dco_decode_String(arr[1]),); }
+
+@protected QueryNode dco_decode_query_node(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return QueryNode_MatchAll();
+case 1: return QueryNode_Term(field: dco_decode_String(raw[1]),text: dco_decode_String(raw[2]),);
+case 2: return QueryNode_Phrase(field: dco_decode_String(raw[1]),text: dco_decode_String(raw[2]),slop: dco_decode_u_32(raw[3]),);
+case 3: return QueryNode_Boolean(must: dco_decode_list_query_node(raw[1]),should: dco_decode_list_query_node(raw[2]),mustNot: dco_decode_list_query_node(raw[3]),);
+case 4: return QueryNode_Boost(query: dco_decode_box_query_node(raw[1]),boost: dco_decode_f_32(raw[2]),);
+case 5: return QueryNode_Fuzzy(field: dco_decode_String(raw[1]),text: dco_decode_String(raw[2]),distance: dco_decode_u_8(raw[3]),transposition: dco_decode_bool(raw[4]),);
+case 6: return QueryNode_Prefix(field: dco_decode_String(raw[1]),prefix: dco_decode_String(raw[2]),);
+case 7: return QueryNode_Range(field: dco_decode_String(raw[1]),lower: dco_decode_opt_box_autoadd_range_bound(raw[2]),upper: dco_decode_opt_box_autoadd_range_bound(raw[3]),);
+case 8: return QueryNode_Facet(field: dco_decode_String(raw[1]),path: dco_decode_String(raw[2]),);
+case 9: return QueryNode_Geo(filter: dco_decode_box_autoadd_geo_filter(raw[1]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected RangeBound dco_decode_range_bound(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return RangeBound(value: dco_decode_range_value(arr[0]),
+inclusive: dco_decode_bool(arr[1]),); }
+
+@protected RangeValue dco_decode_range_value(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return RangeValue_Text(dco_decode_String(raw[1]),);
+case 1: return RangeValue_Integer(dco_decode_i_64(raw[1]),);
+case 2: return RangeValue_Float(dco_decode_f_64(raw[1]),);
+case 3: return RangeValue_Date(dco_decode_String(raw[1]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected ScoreExplanation dco_decode_score_explanation(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return ScoreExplanation(value: dco_decode_f_32(arr[0]),
+description: dco_decode_String(arr[1]),
+details: dco_decode_list_score_explanation(arr[2]),
+context: dco_decode_list_String(arr[3]),); }
+
+@protected ScoreModifier dco_decode_score_modifier(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return ScoreModifier_DateDecay(field: dco_decode_String(raw[1]),function: dco_decode_decay_function(raw[2]),origin: dco_decode_opt_box_autoadd_i_64(raw[3]),scaleDays: dco_decode_f_64(raw[4]),offsetDays: dco_decode_f_64(raw[5]),decay: dco_decode_f_64(raw[6]),);
+case 1: return ScoreModifier_LogBoost(field: dco_decode_String(raw[1]),factor: dco_decode_f_64(raw[2]),);
+case 2: return ScoreModifier_StaticRank(field: dco_decode_String(raw[1]),weight: dco_decode_f_64(raw[2]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected SearchGroup dco_decode_search_group(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return SearchGroup(key: dco_decode_opt_String(arr[0]),
+count: dco_decode_u_64(arr[1]),
+hits: dco_decode_list_search_hit(arr[2]),); }
+
+@protected SearchHit dco_decode_search_hit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 10) throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+                return SearchHit(id: dco_decode_String(arr[0]),
+title: dco_decode_String(arr[1]),
+body: dco_decode_String(arr[2]),
+score: dco_decode_f_32(arr[3]),
+metadata: dco_decode_String(arr[4]),
+snippet: dco_decode_opt_box_autoadd_search_snippet(arr[5]),
+sortValues: dco_decode_list_sort_value(arr[6]),
+createdAt: dco_decode_i_64(arr[7]),
+updatedAt: dco_decode_i_64(arr[8]),
+distanceKm: dco_decode_opt_box_autoadd_f_64(arr[9]),); }
+
+@protected SearchOptions dco_decode_search_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 15) throw Exception('unexpected arr length: expect 15 but see ${arr.length}');
+                return SearchOptions(limit: dco_decode_usize(arr[0]),
+snippet: dco_decode_opt_box_autoadd_snippet_options(arr[1]),
+fuzzy: dco_decode_opt_box_autoadd_fuzzy_options(arr[2]),
+jamoFuzzy: dco_decode_opt_box_autoadd_fuzzy_options(arr[3]),
+lenient: dco_decode_bool(arr[4]),
+sort: dco_decode_list_sort_key(arr[5]),
+aggregations: dco_decode_list_aggregation_request(arr[6]),
+categoryFilter: dco_decode_opt_String(arr[7]),
+categoryCounts: dco_decode_list_String(arr[8]),
+geoFilter: dco_decode_opt_box_autoadd_geo_filter(arr[9]),
+collapse: dco_decode_opt_String(arr[10]),
+groupBy: dco_decode_opt_box_autoadd_group_by_options(arr[11]),
+scoreModifiers: dco_decode_list_score_modifier(arr[12]),
+fields: dco_decode_field_selection(arr[13]),
+bodyMaxChars: dco_decode_opt_box_autoadd_usize(arr[14]),); }
+
+@protected SearchResponse dco_decode_search_response(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return SearchResponse(hits: dco_decode_list_search_hit(arr[0]),
+diagnostics: dco_decode_list_query_diagnostic(arr[1]),
+aggregations: dco_decode_list_aggregation_result(arr[2]),
+categoryCounts: dco_decode_list_category_counts(arr[3]),
+groups: dco_decode_list_search_group(arr[4]),); }
+
+@protected SearchResult dco_decode_search_result(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return SearchResult(id: dco_decode_String(arr[0]),
+title: dco_decode_String(arr[1]),
+body: dco_decode_String(arr[2]),
+score: dco_decode_f_32(arr[3]),
+metadata: dco_decode_String(arr[4]),
+createdAt: dco_decode_i_64(arr[5]),
+updatedAt: dco_decode_i_64(arr[6]),); }
+
+@protected SearchSnippet dco_decode_search_snippet(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return SearchSnippet(fragment: dco_decode_String(arr[0]),
+highlights: dco_decode_list_highlight_range(arr[1]),
+html: dco_decode_opt_String(arr[2]),); }
+
+@protected SnippetOptions dco_decode_snippet_options(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return SnippetOptions(field: dco_decode_String(arr[0]),
+maxNumChars: dco_decode_usize(arr[1]),
+html: dco_decode_bool(arr[2]),); }
+
+@protected SortBy dco_decode_sort_by(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return SortBy_Score();
+case 1: return SortBy_Field(dco_decode_String(raw[1]),);
+case 2: return SortBy_Distance(dco_decode_box_autoadd_geo_point(raw[1]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected SortKey dco_decode_sort_key(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return SortKey(by: dco_decode_sort_by(arr[0]),
+order: dco_decode_sort_order(arr[1]),); }
+
+@protected SortOrder dco_decode_sort_order(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return SortOrder.values[raw as int]; }
+
+@protected SortValue dco_decode_sort_value(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return SortValue_Integer(dco_decode_i_64(raw[1]),);
+case 1: return SortValue_Float(dco_decode_f_64(raw[1]),);
+case 2: return SortValue_Text(dco_decode_String(raw[1]),);
+case 3: return SortValue_Missing();
+                default: throw Exception("unreachable");
+            } }
+
+@protected StoredDocument dco_decode_stored_document(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return StoredDocument(id: dco_decode_String(arr[0]),
+title: dco_decode_String(arr[1]),
+body: dco_decode_String(arr[2]),
+metadata: dco_decode_String(arr[3]),
+createdAt: dco_decode_i_64(arr[4]),
+updatedAt: dco_decode_i_64(arr[5]),); }
+
+@protected Suggestion dco_decode_suggestion(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return Suggestion(text: dco_decode_String(arr[0]),
+source: dco_decode_suggestion_source(arr[1]),
+docCount: dco_decode_u_64(arr[2]),); }
+
+@protected SuggestionSource dco_decode_suggestion_source(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return SuggestionSource.values[raw as int]; }
+
+@protected TokenDetail dco_decode_token_detail(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return TokenDetail(surface: dco_decode_String(arr[0]),
+pos: dco_decode_String(arr[1]),
+details: dco_decode_list_String(arr[2]),); }
+
+@protected TokenMode dco_decode_token_mode(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return TokenMode.values[raw as int]; }
+
+@protected int dco_decode_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected BigInt dco_decode_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeU64(raw); }
+
+@protected int dco_decode_u_8(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected void dco_decode_unit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return; }
+
+@protected BigInt dco_decode_usize(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeU64(raw); }
+
+@protected VariantMapping dco_decode_variant_mapping(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return VariantMapping(variant: dco_decode_String(arr[0]),
+canonical: dco_decode_String(arr[1]),); }
+
+@protected String sse_decode_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_list_prim_u_8_strict(deserializer);
+        return utf8.decoder.convert(inner); }
+
+@protected AggregationBucket sse_decode_aggregation_bucket(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_key = sse_decode_String(deserializer);
+var var_docCount = sse_decode_u_64(deserializer);
+var var_from = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_to = sse_decode_opt_box_autoadd_f_64(deserializer);
+return AggregationBucket(key: var_key, docCount: var_docCount, from: var_from, to: var_to); }
+
+@protected AggregationKind sse_decode_aggregation_kind(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_field = sse_decode_String(deserializer);
+var var_size = sse_decode_u_32(deserializer);
+return AggregationKind_Terms(field: var_field, size: var_size);case 1: var var_field = sse_decode_String(deserializer);
+var var_ranges = sse_decode_list_aggregation_range(deserializer);
+return AggregationKind_Range(field: var_field, ranges: var_ranges);case 2: var var_field = sse_decode_String(deserializer);
+var var_interval = sse_decode_f_64(deserializer);
+return AggregationKind_Histogram(field: var_field, interval: var_interval);case 3: var var_field = sse_decode_String(deserializer);
+var var_fixedInterval = sse_decode_String(deserializer);
+return AggregationKind_DateHistogram(field: var_field, fixedInterval: var_fixedInterval);case 4: var var_field = sse_decode_String(deserializer);
+return AggregationKind_Min(field: var_field);case 5: var var_field = sse_decode_String(deserializer);
+return AggregationKind_Max(field: var_field);case 6: var var_field = sse_decode_String(deserializer);
+return AggregationKind_Avg(field: var_field);case 7: var var_field = sse_decode_String(deserializer);
+return AggregationKind_Stats(field: var_field); default: throw UnimplementedError(''); }
+             }
+
+@protected AggregationRange sse_decode_aggregation_range(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_from = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_to = sse_decode_opt_box_autoadd_f_64(deserializer);
+return AggregationRange(from: var_from, to: var_to); }
+
+@protected AggregationRequest sse_decode_aggregation_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_name = sse_decode_String(deserializer);
+var var_kind = sse_decode_aggregation_kind(deserializer);
+return AggregationRequest(name: var_name, kind: var_kind); }
+
+@protected AggregationResult sse_decode_aggregation_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_name = sse_decode_String(deserializer);
+var var_value = sse_decode_aggregation_value(deserializer);
+return AggregationResult(name: var_name, value: var_value); }
+
+@protected AggregationStats sse_decode_aggregation_stats(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_count = sse_decode_u_64(deserializer);
+var var_sum = sse_decode_f_64(deserializer);
+var var_min = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_max = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_avg = sse_decode_opt_box_autoadd_f_64(deserializer);
+return AggregationStats(count: var_count, sum: var_sum, min: var_min, max: var_max, avg: var_avg); }
+
+@protected AggregationValue sse_decode_aggregation_value(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_field0 = sse_decode_list_aggregation_bucket(deserializer);
+return AggregationValue_Buckets(var_field0);case 1: var var_field0 = sse_decode_opt_box_autoadd_f_64(deserializer);
+return AggregationValue_Metric(var_field0);case 2: var var_field0 = sse_decode_box_autoadd_aggregation_stats(deserializer);
+return AggregationValue_Stats(var_field0); default: throw UnimplementedError(''); }
+             }
+
+@protected bool sse_decode_bool(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint8() != 0; }
+
+@protected AggregationStats sse_decode_box_autoadd_aggregation_stats(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_aggregation_stats(deserializer)); }
+
+@protected double sse_decode_box_autoadd_f_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_f_64(deserializer)); }
+
+@protected FuzzyOptions sse_decode_box_autoadd_fuzzy_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_fuzzy_options(deserializer)); }
+
+@protected GeoFilter sse_decode_box_autoadd_geo_filter(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_geo_filter(deserializer)); }
+
+@protected GeoPoint sse_decode_box_autoadd_geo_point(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_geo_point(deserializer)); }
+
+@protected GroupByOptions sse_decode_box_autoadd_group_by_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_group_by_options(deserializer)); }
+
+@protected PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_i_64(deserializer)); }
+
+@protected MoreLikeThisOptions sse_decode_box_autoadd_more_like_this_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_more_like_this_options(deserializer)); }
+
+@protected MoreLikeThisSource sse_decode_box_autoadd_more_like_this_source(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_more_like_this_source(deserializer)); }
+
+@protected QueryNode sse_decode_box_autoadd_query_node(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_query_node(deserializer)); }
+
+@protected RangeBound sse_decode_box_autoadd_range_bound(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_range_bound(deserializer)); }
+
+@protected ScoreExplanation sse_decode_box_autoadd_score_explanation(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_score_explanation(deserializer)); }
+
+@protected SearchOptions sse_decode_box_autoadd_search_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_search_options(deserializer)); }
+
+@protected SearchSnippet sse_decode_box_autoadd_search_snippet(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_search_snippet(deserializer)); }
+
+@protected SnippetOptions sse_decode_box_autoadd_snippet_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_snippet_options(deserializer)); }
+
+@protected StoredDocument sse_decode_box_autoadd_stored_document(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_stored_document(deserializer)); }
+
+@protected int sse_decode_box_autoadd_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_u_32(deserializer)); }
+
+@protected BigInt sse_decode_box_autoadd_usize(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_usize(deserializer)); }
+
+@protected QueryNode sse_decode_box_query_node(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_query_node(deserializer)); }
+
+@protected CategoryCount sse_decode_category_count(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_path = sse_decode_String(deserializer);
+var var_count = sse_decode_u_64(deserializer);
+return CategoryCount(path: var_path, count: var_count); }
+
+@protected CategoryCounts sse_decode_category_counts(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_parent = sse_decode_String(deserializer);
+var var_children = sse_decode_list_category_count(deserializer);
+return CategoryCounts(parent: var_parent, children: var_children); }
+
+@protected DecayFunction sse_decode_decay_function(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return DecayFunction.values[inner]; }
+
+@protected DictionaryType sse_decode_dictionary_type(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return DictionaryType.values[inner]; }
+
+@protected DocumentInput sse_decode_document_input(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_String(deserializer);
+var var_title = sse_decode_String(deserializer);
+var var_body = sse_decode_String(deserializer);
+var var_metadata = sse_decode_String(deserializer);
+return DocumentInput(id: var_id, title: var_title, body: var_body, metadata: var_metadata); }
+
+@protected DocumentPage sse_decode_document_page(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_documents = sse_decode_list_stored_document(deserializer);
+var var_nextCursor = sse_decode_opt_String(deserializer);
+return DocumentPage(documents: var_documents, nextCursor: var_nextCursor); }
+
+@protected double sse_decode_f_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getFloat32(); }
+
+@protected double sse_decode_f_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getFloat64(); }
+
+@protected FieldSelection sse_decode_field_selection(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: return FieldSelection_All();case 1: return FieldSelection_IdOnly();case 2: var var_field0 = sse_decode_list_String(deserializer);
+return FieldSelection_Fields(var_field0); default: throw UnimplementedError(''); }
+             }
+
+@protected FuzzyOptions sse_decode_fuzzy_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_distance = sse_decode_u_8(deserializer);
+var var_transposition = sse_decode_bool(deserializer);
+var var_prefixLength = sse_decode_usize(deserializer);
+return FuzzyOptions(distance: var_distance, transposition: var_transposition, prefixLength: var_prefixLength); }
+
+@protected GeoFilter sse_decode_geo_filter(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_topLeft = sse_decode_box_autoadd_geo_point(deserializer);
+var var_bottomRight = sse_decode_box_autoadd_geo_point(deserializer);
+return GeoFilter_BoundingBox(topLeft: var_topLeft, bottomRight: var_bottomRight);case 1: var var_center = sse_decode_box_autoadd_geo_point(deserializer);
+var var_distanceKm = sse_decode_f_64(deserializer);
+return GeoFilter_Radius(center: var_center, distanceKm: var_distanceKm); default: throw UnimplementedError(''); }
+             }
+
+@protected GeoPoint sse_decode_geo_point(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_lat = sse_decode_f_64(deserializer);
+var var_lon = sse_decode_f_64(deserializer);
+return GeoPoint(lat: var_lat, lon: var_lon); }
+
+@protected GroupByOptions sse_decode_group_by_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_field = sse_decode_String(deserializer);
+var var_groupsLimit = sse_decode_usize(deserializer);
+var var_hitsPerGroup = sse_decode_usize(deserializer);
+return GroupByOptions(field: var_field, groupsLimit: var_groupsLimit, hitsPerGroup: var_hitsPerGroup); }
+
+@protected HighlightRange sse_decode_highlight_range(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_start = sse_decode_u_32(deserializer);
+var var_end = sse_decode_u_32(deserializer);
+return HighlightRange(start: var_start, end: var_end); }
+
+@protected int sse_decode_i_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getInt32(); }
+
+@protected PlatformInt64 sse_decode_i_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getPlatformInt64(); }
+
+@protected List<String> sse_decode_list_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <String>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_String(deserializer)); }
+        return ans_;
+         }
+
+@protected List<AggregationBucket> sse_decode_list_aggregation_bucket(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <AggregationBucket>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_aggregation_bucket(deserializer)); }
+        return ans_;
+         }
+
+@protected List<AggregationRange> sse_decode_list_aggregation_range(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <AggregationRange>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_aggregation_range(deserializer)); }
+        return ans_;
+         }
+
+@protected List<AggregationRequest> sse_decode_list_aggregation_request(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <AggregationRequest>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_aggregation_request(deserializer)); }
+        return ans_;
+         }
+
+@protected List<AggregationResult> sse_decode_list_aggregation_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <AggregationResult>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_aggregation_result(deserializer)); }
+        return ans_;
+         }
+
+@protected List<CategoryCount> sse_decode_list_category_count(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <CategoryCount>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_category_count(deserializer)); }
+        return ans_;
+         }
+
+@protected List<CategoryCounts> sse_decode_list_category_counts(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <CategoryCounts>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_category_counts(deserializer)); }
+        return ans_;
+         }
+
+@protected List<DocumentInput> sse_decode_list_document_input(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <DocumentInput>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_document_input(deserializer)); }
+        return ans_;
+         }
+
+@protected List<HighlightRange> sse_decode_list_highlight_range(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <HighlightRange>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_highlight_range(deserializer)); }
+        return ans_;
+         }
+
+@protected Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint8List(len_); }
+
+@protected List<QueryDiagnostic> sse_decode_list_query_diagnostic(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <QueryDiagnostic>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_query_diagnostic(deserializer)); }
+        return ans_;
+         }
+
+@protected List<QueryNode> sse_decode_list_query_node(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <QueryNode>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_query_node(deserializer)); }
+        return ans_;
+         }
+
+@protected List<ScoreExplanation> sse_decode_list_score_explanation(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ScoreExplanation>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_score_explanation(deserializer)); }
+        return ans_;
+         }
+
+@protected List<ScoreModifier> sse_decode_list_score_modifier(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ScoreModifier>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_score_modifier(deserializer)); }
+        return ans_;
+         }
+
+@protected List<SearchGroup> sse_decode_list_search_group(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <SearchGroup>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_search_group(deserializer)); }
+        return ans_;
+         }
+
+@protected List<SearchHit> sse_decode_list_search_hit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <SearchHit>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_search_hit(deserializer)); }
+        return ans_;
+         }
+
+@protected List<SearchResult> sse_decode_list_search_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <SearchResult>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_search_result(deserializer)); }
+        return ans_;
+         }
+
+@protected List<SortKey> sse_decode_list_sort_key(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <SortKey>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_sort_key(deserializer)); }
+        return ans_;
+         }
+
+@protected List<SortValue> sse_decode_list_sort_value(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <SortValue>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_sort_value(deserializer)); }
+        return ans_;
+         }
+
+@protected List<StoredDocument> sse_decode_list_stored_document(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <StoredDocument>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_stored_document(deserializer)); }
+        return ans_;
+         }
+
+@protected List<Suggestion> sse_decode_list_suggestion(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <Suggestion>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_suggestion(deserializer)); }
+        return ans_;
+         }
+
+@protected List<TokenDetail> sse_decode_list_token_detail(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <TokenDetail>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_token_detail(deserializer)); }
+        return ans_;
+         }
+
+@protected List<VariantMapping> sse_decode_list_variant_mapping(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <VariantMapping>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_variant_mapping(deserializer)); }
+        return ans_;
+         }
+
+@protected MoreLikeThisOptions sse_decode_more_like_this_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_minDocFreq = sse_decode_u_64(deserializer);
+var var_minTermFreq = sse_decode_usize(deserializer);
+var var_maxQueryTerms = sse_decode_usize(deserializer);
+return MoreLikeThisOptions(minDocFreq: var_minDocFreq, minTermFreq: var_minTermFreq, maxQueryTerms: var_maxQueryTerms); }
+
+@protected MoreLikeThisSource sse_decode_more_like_this_source(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_field0 = sse_decode_String(deserializer);
+return MoreLikeThisSource_DocumentId(var_field0);case 1: var var_field0 = sse_decode_String(deserializer);
+return MoreLikeThisSource_Text(var_field0); default: throw UnimplementedError(''); }
+             }
+
+@protected String? sse_decode_opt_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_String(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_f_64(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected FuzzyOptions? sse_decode_opt_box_autoadd_fuzzy_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_fuzzy_options(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected GeoFilter? sse_decode_opt_box_autoadd_geo_filter(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_geo_filter(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected GroupByOptions? sse_decode_opt_box_autoadd_group_by_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_group_by_options(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_i_64(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected RangeBound? sse_decode_opt_box_autoadd_range_bound(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_range_bound(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected SearchSnippet? sse_decode_opt_box_autoadd_search_snippet(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_search_snippet(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected SnippetOptions? sse_decode_opt_box_autoadd_snippet_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_snippet_options(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected StoredDocument? sse_decode_opt_box_autoadd_stored_document(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_stored_document(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_u_32(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected BigInt? sse_decode_opt_box_autoadd_usize(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_usize(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected QueryDiagnostic sse_decode_query_diagnostic(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_position = sse_decode_opt_box_autoadd_u_32(deserializer);
+var var_message = sse_decode_String(deserializer);
+return QueryDiagnostic(position: var_position, message: var_message); }
+
+@protected QueryNode sse_decode_query_node(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: return QueryNode_MatchAll();case 1: var var_field = sse_decode_String(deserializer);
+var var_text = sse_decode_String(deserializer);
+return QueryNode_Term(field: var_field, text: var_text);case 2: var var_field = sse_decode_String(deserializer);
+var var_text = sse_decode_String(deserializer);
+var var_slop = sse_decode_u_32(deserializer);
+return QueryNode_Phrase(field: var_field, text: var_text, slop: var_slop);case 3: var var_must = sse_decode_list_query_node(deserializer);
+var var_should = sse_decode_list_query_node(deserializer);
+var var_mustNot = sse_decode_list_query_node(deserializer);
+return QueryNode_Boolean(must: var_must, should: var_should, mustNot: var_mustNot);case 4: var var_query = sse_decode_box_query_node(deserializer);
+var var_boost = sse_decode_f_32(deserializer);
+return QueryNode_Boost(query: var_query, boost: var_boost);case 5: var var_field = sse_decode_String(deserializer);
+var var_text = sse_decode_String(deserializer);
+var var_distance = sse_decode_u_8(deserializer);
+var var_transposition = sse_decode_bool(deserializer);
+return QueryNode_Fuzzy(field: var_field, text: var_text, distance: var_distance, transposition: var_transposition);case 6: var var_field = sse_decode_String(deserializer);
+var var_prefix = sse_decode_String(deserializer);
+return QueryNode_Prefix(field: var_field, prefix: var_prefix);case 7: var var_field = sse_decode_String(deserializer);
+var var_lower = sse_decode_opt_box_autoadd_range_bound(deserializer);
+var var_upper = sse_decode_opt_box_autoadd_range_bound(deserializer);
+return QueryNode_Range(field: var_field, lower: var_lower, upper: var_upper);case 8: var var_field = sse_decode_String(deserializer);
+var var_path = sse_decode_String(deserializer);
+return QueryNode_Facet(field: var_field, path: var_path);case 9: var var_filter = sse_decode_box_autoadd_geo_filter(deserializer);
+return QueryNode_Geo(filter: var_filter); default: throw UnimplementedError(''); }
+             }
+
+@protected RangeBound sse_decode_range_bound(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_value = sse_decode_range_value(deserializer);
+var var_inclusive = sse_decode_bool(deserializer);
+return RangeBound(value: var_value, inclusive: var_inclusive); }
+
+@protected RangeValue sse_decode_range_value(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_field0 = sse_decode_String(deserializer);
+return RangeValue_Text(var_field0);case 1: var var_field0 = sse_decode_i_64(deserializer);
+return RangeValue_Integer(var_field0);case 2: var var_field0 = sse_decode_f_64(deserializer);
+return RangeValue_Float(var_field0);case 3: var var_field0 = sse_decode_String(deserializer);
+return RangeValue_Date(var_field0); default: throw UnimplementedError(''); }
+             }
+
+@protected ScoreExplanation sse_decode_score_explanation(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_value = sse_decode_f_32(deserializer);
+var var_description = sse_decode_String(deserializer);
+var var_details = sse_decode_list_score_explanation(deserializer);
+var var_context = sse_decode_list_String(deserializer);
+return ScoreExplanation(value: var_value, description: var_description, details: var_details, context: var_context); }
+
+@protected ScoreModifier sse_decode_score_modifier(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_field = sse_decode_String(deserializer);
+var var_function = sse_decode_decay_function(deserializer);
+var var_origin = sse_decode_opt_box_autoadd_i_64(deserializer);
+var var_scaleDays = sse_decode_f_64(deserializer);
+var var_offsetDays = sse_decode_f_64(deserializer);
+var var_decay = sse_decode_f_64(deserializer);
+return ScoreModifier_DateDecay(field: var_field, function: var_function, origin: var_origin, scaleDays: var_scaleDays, offsetDays: var_offsetDays, decay: var_decay);case 1: var var_field = sse_decode_String(deserializer);
+var var_factor = sse_decode_f_64(deserializer);
+return ScoreModifier_LogBoost(field: var_field, factor: var_factor);case 2: var var_field = sse_decode_String(deserializer);
+var var_weight = sse_decode_f_64(deserializer);
+return ScoreModifier_StaticRank(field: var_field, weight: var_weight); default: throw UnimplementedError(''); }
+             }
+
+@protected SearchGroup sse_decode_search_group(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_key = sse_decode_opt_String(deserializer);
+var var_count = sse_decode_u_64(deserializer);
+var var_hits = sse_decode_list_search_hit(deserializer);
+return SearchGroup(key: var_key, count: var_count, hits: var_hits); }
+
+@protected SearchHit sse_decode_search_hit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_String(deserializer);
+var var_title = sse_decode_String(deserializer);
+var var_body = sse_decode_String(deserializer);
+var var_score = sse_decode_f_32(deserializer);
+var var_metadata = sse_decode_String(deserializer);
+var var_snippet = sse_decode_opt_box_autoadd_search_snippet(deserializer);
+var var_sortValues = sse_decode_list_sort_value(deserializer);
+var var_createdAt = sse_decode_i_64(deserializer);
+var var_updatedAt = sse_decode_i_64(deserializer);
+var var_distanceKm = sse_decode_opt_box_autoadd_f_64(deserializer);
+return SearchHit(id: var_id, title: var_title, body: var_body, score: var_score, metadata: var_metadata, snippet: var_snippet, sortValues: var_sortValues, createdAt: var_createdAt, updatedAt: var_updatedAt, distanceKm: var_distanceKm); }
+
+@protected SearchOptions sse_decode_search_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_limit = sse_decode_usize(deserializer);
+var var_snippet = sse_decode_opt_box_autoadd_snippet_options(deserializer);
+var var_fuzzy = sse_decode_opt_box_autoadd_fuzzy_options(deserializer);
+var var_jamoFuzzy = sse_decode_opt_box_autoadd_fuzzy_options(deserializer);
+var var_lenient = sse_decode_bool(deserializer);
+var var_sort = sse_decode_list_sort_key(deserializer);
+var var_aggregations = sse_decode_list_aggregation_request(deserializer);
+var var_categoryFilter = sse_decode_opt_String(deserializer);
+var var_categoryCounts = sse_decode_list_String(deserializer);
+var var_geoFilter = sse_decode_opt_box_autoadd_geo_filter(deserializer);
+var var_collapse = sse_decode_opt_String(deserializer);
+var var_groupBy = sse_decode_opt_box_autoadd_group_by_options(deserializer);
+var var_scoreModifiers = sse_decode_list_score_modifier(deserializer);
+var var_fields = sse_decode_field_selection(deserializer);
+var var_bodyMaxChars = sse_decode_opt_box_autoadd_usize(deserializer);
+return SearchOptions(limit: var_limit, snippet: var_snippet, fuzzy: var_fuzzy, jamoFuzzy: var_jamoFuzzy, lenient: var_lenient, sort: var_sort, aggregations: var_aggregations, categoryFilter: var_categoryFilter, categoryCounts: var_categoryCounts, geoFilter: var_geoFilter, collapse: var_collapse, groupBy: var_groupBy, scoreModifiers: var_scoreModifiers, fields: var_fields, bodyMaxChars: var_bodyMaxChars); }
+
+@protected SearchResponse sse_decode_search_response(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_hits = sse_decode_list_search_hit(deserializer);
+var var_diagnostics = sse_decode_list_query_diagnostic(deserializer);
+var var_aggregations = sse_decode_list_aggregation_result(deserializer);
+var var_categoryCounts = sse_decode_list_category_counts(deserializer);
+var var_groups = sse_decode_list_search_group(deserializer);
+return SearchResponse(hits: var_hits, diagnostics: var_diagnostics, aggregations: var_aggregations, categoryCounts: var_categoryCounts, groups: var_groups); }
+
+@protected SearchResult sse_decode_search_result(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_String(deserializer);
+var var_title = sse_decode_String(deserializer);
+var var_body = sse_decode_String(deserializer);
+var var_score = sse_decode_f_32(deserializer);
+var var_metadata = sse_decode_String(deserializer);
+var var_createdAt = sse_decode_i_64(deserializer);
+var var_updatedAt = sse_decode_i_64(deserializer);
+return SearchResult(id: var_id, title: var_title, body: var_body, score: var_score, metadata: var_metadata, createdAt: var_createdAt, updatedAt: var_updatedAt); }
+
+@protected SearchSnippet sse_decode_search_snippet(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_fragment = sse_decode_String(deserializer);
+var var_highlights = sse_decode_list_highlight_range(deserializer);
+var var_html = sse_decode_opt_String(deserializer);
+return SearchSnippet(fragment: var_fragment, highlights: var_highlights, html: var_html); }
+
+@protected SnippetOptions sse_decode_snippet_options(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_field = sse_decode_String(deserializer);
+var var_maxNumChars = sse_decode_usize(deserializer);
+var var_html = sse_decode_bool(deserializer);
+return SnippetOptions(field: var_field, maxNumChars: var_maxNumChars, html: var_html); }
+
+@protected SortBy sse_decode_sort_by(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: return SortBy_Score();case 1: var var_field0 = sse_decode_String(deserializer);
+return SortBy_Field(var_field0);case 2: var var_field0 = sse_decode_box_autoadd_geo_point(deserializer);
+return SortBy_Distance(var_field0); default: throw UnimplementedError(''); }
+             }
+
+@protected SortKey sse_decode_sort_key(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_by = sse_decode_sort_by(deserializer);
+var var_order = sse_decode_sort_order(deserializer);
+return SortKey(by: var_by, order: var_order); }
+
+@protected SortOrder sse_decode_sort_order(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return SortOrder.values[inner]; }
+
+@protected SortValue sse_decode_sort_value(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_field0 = sse_decode_i_64(deserializer);
+return SortValue_Integer(var_field0);case 1: var var_field0 = sse_decode_f_64(deserializer);
+return SortValue_Float(var_field0);case 2: var var_field0 = sse_decode_String(deserializer);
+return SortValue_Text(var_field0);case 3: return SortValue_Missing(); default: throw UnimplementedError(''); }
+             }
+
+@protected StoredDocument sse_decode_stored_document(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_String(deserializer);
+var var_title = sse_decode_String(deserializer);
+var var_body = sse_decode_String(deserializer);
+var var_metadata = sse_decode_String(deserializer);
+var var_createdAt = sse_decode_i_64(deserializer);
+var var_updatedAt = sse_decode_i_64(deserializer);
+return StoredDocument(id: var_id, title: var_title, body: var_body, metadata: var_metadata, createdAt: var_createdAt, updatedAt: var_updatedAt); }
+
+@protected Suggestion sse_decode_suggestion(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_text = sse_decode_String(deserializer);
+var var_source = sse_decode_suggestion_source(deserializer);
+var var_docCount = sse_decode_u_64(deserializer);
+return Suggestion(text: var_text, source: var_source, docCount: var_docCount); }
+
+@protected SuggestionSource sse_decode_suggestion_source(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return SuggestionSource.values[inner]; }
+
+@protected TokenDetail sse_decode_token_detail(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_surface = sse_decode_String(deserializer);
+var var_pos = sse_decode_String(deserializer);
+var var_details = sse_decode_list_String(deserializer);
+return TokenDetail(surface: var_surface, pos: var_pos, details: var_details); }
+
+@protected TokenMode sse_decode_token_mode(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return TokenMode.values[inner]; }
+
+@protected int sse_decode_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint32(); }
+
+@protected BigInt sse_decode_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getBigUint64(); }
+
+@protected int sse_decode_u_8(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint8(); }
+
+@protected void sse_decode_unit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+ }
+
+@protected BigInt sse_decode_usize(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getBigUint64(); }
+
+@protected VariantMapping sse_decode_variant_mapping(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_variant = sse_decode_String(deserializer);
+var var_canonical = sse_decode_String(deserializer);
+return VariantMapping(variant: var_variant, canonical: var_canonical); }
+
+@protected void sse_encode_String(String self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer); }
+
+@protected void sse_encode_aggregation_bucket(AggregationBucket self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.key, serializer);
+sse_encode_u_64(self.docCount, serializer);
+sse_encode_opt_box_autoadd_f_64(self.from, serializer);
+sse_encode_opt_box_autoadd_f_64(self.to, serializer);
+ }
+
+@protected void sse_encode_aggregation_kind(AggregationKind self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case AggregationKind_Terms(field: final field,size: final size): sse_encode_i_32(0, serializer); sse_encode_String(field, serializer);
+sse_encode_u_32(size, serializer);
+case AggregationKind_Range(field: final field,ranges: final ranges): sse_encode_i_32(1, serializer); sse_encode_String(field, serializer);
+sse_encode_list_aggregation_range(ranges, serializer);
+case AggregationKind_Histogram(field: final field,interval: final interval): sse_encode_i_32(2, serializer); sse_encode_String(field, serializer);
+sse_encode_f_64(interval, serializer);
+case AggregationKind_DateHistogram(field: final field,fixedInterval: final fixedInterval): sse_encode_i_32(3, serializer); sse_encode_String(field, serializer);
+sse_encode_String(fixedInterval, serializer);
+case AggregationKind_Min(field: final field): sse_encode_i_32(4, serializer); sse_encode_String(field, serializer);
+case AggregationKind_Max(field: final field): sse_encode_i_32(5, serializer); sse_encode_String(field, serializer);
+case AggregationKind_Avg(field: final field): sse_encode_i_32(6, serializer); sse_encode_String(field, serializer);
+case AggregationKind_Stats(field: final field): sse_encode_i_32(7, serializer); sse_encode_String(field, serializer);
+  } }
+
+@protected void sse_encode_aggregation_range(AggregationRange self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_box_autoadd_f_64(self.from, serializer);
+sse_encode_opt_box_autoadd_f_64(self.to, serializer);
+ }
+
+@protected void sse_encode_aggregation_request(AggregationRequest self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.name, serializer);
+sse_encode_aggregation_kind(self.kind, serializer);
+ }
+
+@protected void sse_encode_aggregation_result(AggregationResult self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.name, serializer);
+sse_encode_aggregation_value(self.value, serializer);
+ }
+
+@protected void sse_encode_aggregation_stats(AggregationStats self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.count, serializer);
+sse_encode_f_64(self.sum, serializer);
+sse_encode_opt_box_autoadd_f_64(self.min, serializer);
+sse_encode_opt_box_autoadd_f_64(self.max, serializer);
+sse_encode_opt_box_autoadd_f_64(self.avg, serializer);
+ }
+
+@protected void sse_encode_aggregation_value(AggregationValue self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case AggregationValue_Buckets(field0: final field0): sse_encode_i_32(0, serializer); sse_encode_list_aggregation_bucket(field0, serializer);
+case AggregationValue_Metric(field0: final field0): sse_encode_i_32(1, serializer); sse_encode_opt_box_autoadd_f_64(field0, serializer);
+case AggregationValue_Stats(field0: final field0): sse_encode_i_32(2, serializer); sse_encode_box_autoadd_aggregation_stats(field0, serializer);
+  } }
+
+@protected void sse_encode_bool(bool self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint8(self ? 1 : 0); }
+
+@protected void sse_encode_box_autoadd_aggregation_stats(AggregationStats self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_aggregation_stats(self, serializer); }
+
+@protected void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_f_64(self, serializer); }
+
+@protected void sse_encode_box_autoadd_fuzzy_options(FuzzyOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_fuzzy_options(self, serializer); }
+
+@protected void sse_encode_box_autoadd_geo_filter(GeoFilter self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_geo_filter(self, serializer); }
+
+@protected void sse_encode_box_autoadd_geo_point(GeoPoint self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_geo_point(self, serializer); }
+
+@protected void sse_encode_box_autoadd_group_by_options(GroupByOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_group_by_options(self, serializer); }
+
+@protected void sse_encode_box_autoadd_i_64(PlatformInt64 self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(self, serializer); }
+
+@protected void sse_encode_box_autoadd_more_like_this_options(MoreLikeThisOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_more_like_this_options(self, serializer); }
+
+@protected void sse_encode_box_autoadd_more_like_this_source(MoreLikeThisSource self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_more_like_this_source(self, serializer); }
+
+@protected void sse_encode_box_autoadd_query_node(QueryNode self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_query_node(self, serializer); }
+
+@protected void sse_encode_box_autoadd_range_bound(RangeBound self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_range_bound(self, serializer); }
+
+@protected void sse_encode_box_autoadd_score_explanation(ScoreExplanation self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_score_explanation(self, serializer); }
+
+@protected void sse_encode_box_autoadd_search_options(SearchOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_search_options(self, serializer); }
+
+@protected void sse_encode_box_autoadd_search_snippet(SearchSnippet self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_search_snippet(self, serializer); }
+
+@protected void sse_encode_box_autoadd_snippet_options(SnippetOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_snippet_options(self, serializer); }
+
+@protected void sse_encode_box_autoadd_stored_document(StoredDocument self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_stored_document(self, serializer); }
+
+@protected void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self, serializer); }
+
+@protected void sse_encode_box_autoadd_usize(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize(self, serializer); }
+
+@protected void sse_encode_box_query_node(QueryNode self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_query_node(self, serializer); }
+
+@protected void sse_encode_category_count(CategoryCount self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.path, serializer);
+sse_encode_u_64(self.count, serializer);
+ }
+
+@protected void sse_encode_category_counts(CategoryCounts self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.parent, serializer);
+sse_encode_list_category_count(self.children, serializer);
+ }
+
+@protected void sse_encode_decay_function(DecayFunction self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_dictionary_type(DictionaryType self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_document_input(DocumentInput self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.id, serializer);
+sse_encode_String(self.title, serializer);
+sse_encode_String(self.body, serializer);
+sse_encode_String(self.metadata, serializer);
+ }
+
+@protected void sse_encode_document_page(DocumentPage self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_stored_document(self.documents, serializer);
+sse_encode_opt_String(self.nextCursor, serializer);
+ }
+
+@protected void sse_encode_f_32(double self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putFloat32(self); }
+
+@protected void sse_encode_f_64(double self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putFloat64(self); }
+
+@protected void sse_encode_field_selection(FieldSelection self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case FieldSelection_All(): sse_encode_i_32(0, serializer); case FieldSelection_IdOnly(): sse_encode_i_32(1, serializer); case FieldSelection_Fields(field0: final field0): sse_encode_i_32(2, serializer); sse_encode_list_String(field0, serializer);
+  } }
+
+@protected void sse_encode_fuzzy_options(FuzzyOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_8(self.distance, serializer);
+sse_encode_bool(self.transposition, serializer);
+sse_encode_usize(self.prefixLength, serializer);
+ }
+
+@protected void sse_encode_geo_filter(GeoFilter self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case GeoFilter_BoundingBox(topLeft: final topLeft,bottomRight: final bottomRight): sse_encode_i_32(0, serializer); sse_encode_box_autoadd_geo_point(topLeft, serializer);
+sse_encode_box_autoadd_geo_point(bottomRight, serializer);
+case GeoFilter_Radius(center: final center,distanceKm: final distanceKm): sse_encode_i_32(1, serializer); sse_encode_box_autoadd_geo_point(center, serializer);
+sse_encode_f_64(distanceKm, serializer);
+  } }
+
+@protected void sse_encode_geo_point(GeoPoint self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_f_64(self.lat, serializer);
+sse_encode_f_64(self.lon, serializer);
+ }
+
+@protected void sse_encode_group_by_options(GroupByOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.field, serializer);
+sse_encode_usize(self.groupsLimit, serializer);
+sse_encode_usize(self.hitsPerGroup, serializer);
+ }
+
+@protected void sse_encode_highlight_range(HighlightRange self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.start, serializer);
+sse_encode_u_32(self.end, serializer);
+ }
+
+@protected void sse_encode_i_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putInt32(self); }
+
+@protected void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putPlatformInt64(self); }
+
+@protected void sse_encode_list_String(List<String> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_String(item, serializer); } }
+
+@protected void sse_encode_list_aggregation_bucket(List<AggregationBucket> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_aggregation_bucket(item, serializer); } }
+
+@protected void sse_encode_list_aggregation_range(List<AggregationRange> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_aggregation_range(item, serializer); } }
+
+@protected void sse_encode_list_aggregation_request(List<AggregationRequest> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_aggregation_request(item, serializer); } }
+
+@protected void sse_encode_list_aggregation_result(List<AggregationResult> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_aggregation_result(item, serializer); } }
+
+@protected void sse_encode_list_category_count(List<CategoryCount> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_category_count(item, serializer); } }
+
+@protected void sse_encode_list_category_counts(List<CategoryCounts> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_category_counts(item, serializer); } }
+
+@protected void sse_encode_list_document_input(List<DocumentInput> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_document_input(item, serializer); } }
+
+@protected void sse_encode_list_highlight_range(List<HighlightRange> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_highlight_range(item, serializer); } }
+
+@protected void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint8List(self); }
+
+@protected void sse_encode_list_query_diagnostic(List<QueryDiagnostic> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_query_diagnostic(item, serializer); } }
+
+@protected void sse_encode_list_query_node(List<QueryNode> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_query_node(item, serializer); } }
+
+@protected void sse_encode_list_score_explanation(List<ScoreExplanation> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_score_explanation(item, serializer); } }
+
+@protected void sse_encode_list_score_modifier(List<ScoreModifier> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_score_modifier(item, serializer); } }
+
+@protected void sse_encode_list_search_group(List<SearchGroup> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_search_group(item, serializer); } }
+
+@protected void sse_encode_list_search_hit(List<SearchHit> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_search_hit(item, serializer); } }
+
+@protected void sse_encode_list_search_result(List<SearchResult> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_search_result(item, serializer); } }
+
+@protected void sse_encode_list_sort_key(List<SortKey> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_sort_key(item, serializer); } }
+
+@protected void sse_encode_list_sort_value(List<SortValue> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_sort_value(item, serializer); } }
+
+@protected void sse_encode_list_stored_document(List<StoredDocument> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_stored_document(item, serializer); } }
+
+@protected void sse_encode_list_suggestion(List<Suggestion> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_suggestion(item, serializer); } }
+
+@protected void sse_encode_list_token_detail(List<TokenDetail> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_token_detail(item, serializer); } }
+
+@protected void sse_encode_list_variant_mapping(List<VariantMapping> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_variant_mapping(item, serializer); } }
+
+@protected void sse_encode_more_like_this_options(MoreLikeThisOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.minDocFreq, serializer);
+sse_encode_usize(self.minTermFreq, serializer);
+sse_encode_usize(self.maxQueryTerms, serializer);
+ }
+
+@protected void sse_encode_more_like_this_source(MoreLikeThisSource self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case MoreLikeThisSource_DocumentId(field0: final field0): sse_encode_i_32(0, serializer); sse_encode_String(field0, serializer);
+case MoreLikeThisSource_Text(field0: final field0): sse_encode_i_32(1, serializer); sse_encode_String(field0, serializer);
+  } }
+
+@protected void sse_encode_opt_String(String? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_String(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_f_64(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_fuzzy_options(FuzzyOptions? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_fuzzy_options(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_geo_filter(GeoFilter? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_geo_filter(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_group_by_options(GroupByOptions? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_group_by_options(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_i_64(PlatformInt64? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_i_64(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_range_bound(RangeBound? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_range_bound(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_search_snippet(SearchSnippet? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_search_snippet(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_snippet_options(SnippetOptions? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_snippet_options(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_stored_document(StoredDocument? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_stored_document(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_u_32(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_usize(BigInt? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_usize(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_query_diagnostic(QueryDiagnostic self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_box_autoadd_u_32(self.position, serializer);
+sse_encode_String(self.message, serializer);
+ }
+
+@protected void sse_encode_query_node(QueryNode self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case QueryNode_MatchAll(): sse_encode_i_32(0, serializer); case QueryNode_Term(field: final field,text: final text): sse_encode_i_32(1, serializer); sse_encode_String(field, serializer);
+sse_encode_String(text, serializer);
+case QueryNode_Phrase(field: final field,text: final text,slop: final slop): sse_encode_i_32(2, serializer); sse_encode_String(field, serializer);
+sse_encode_String(text, serializer);
+sse_encode_u_32(slop, serializer);
+case QueryNode_Boolean(must: final must,should: final should,mustNot: final mustNot): sse_encode_i_32(3, serializer); sse_encode_list_query_node(must, serializer);
+sse_encode_list_query_node(should, serializer);
+sse_encode_list_query_node(mustNot, serializer);
+case QueryNode_Boost(query: final query,boost: final boost): sse_encode_i_32(4, serializer); sse_encode_box_query_node(query, serializer);
+sse_encode_f_32(boost, serializer);
+case QueryNode_Fuzzy(field: final field,text: final text,distance: final distance,transposition: final transposition): sse_encode_i_32(5, serializer); sse_encode_String(field, serializer);
+sse_encode_String(text, serializer);
+sse_encode_u_8(distance, serializer);
+sse_encode_bool(transposition, serializer);
+case QueryNode_Prefix(field: final field,prefix: final prefix): sse_encode_i_32(6, serializer); sse_encode_String(field, serializer);
+sse_encode_String(prefix, serializer);
+case QueryNode_Range(field: final field,lower: final lower,upper: final upper): sse_encode_i_32(7, serializer); sse_encode_String(field, serializer);
+sse_encode_opt_box_autoadd_range_bound(lower, serializer);
+sse_encode_opt_box_autoadd_range_bound(upper, serializer);
+case QueryNode_Facet(field: final field,path: final path): sse_encode_i_32(8, serializer); sse_encode_String(field, serializer);
+sse_encode_String(path, serializer);
+case QueryNode_Geo(filter: final filter): sse_encode_i_32(9, serializer); sse_encode_box_autoadd_geo_filter(filter, serializer);
+  } }
+
+@protected void sse_encode_range_bound(RangeBound self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_range_value(self.value, serializer);
+sse_encode_bool(self.inclusive, serializer);
+ }
+
+@protected void sse_encode_range_value(RangeValue self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case RangeValue_Text(field0: final field0): sse_encode_i_32(0, serializer); sse_encode_String(field0, serializer);
+case RangeValue_Integer(field0: final field0): sse_encode_i_32(1, serializer); sse_encode_i_64(field0, serializer);
+case RangeValue_Float(field0: final field0): sse_encode_i_32(2, serializer); sse_encode_f_64(field0, serializer);
+case RangeValue_Date(field0: final field0): sse_encode_i_32(3, serializer); sse_encode_String(field0, serializer);
+  } }
+
+@protected void sse_encode_score_explanation(ScoreExplanation self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_f_32(self.value, serializer);
+sse_encode_String(self.description, serializer);
+sse_encode_list_score_explanation(self.details, serializer);
+sse_encode_list_String(self.context, serializer);
+ }
+
+@protected void sse_encode_score_modifier(ScoreModifier self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case ScoreModifier_DateDecay(field: final field,function: final function,origin: final origin,scaleDays: final scaleDays,offsetDays: final offsetDays,decay: final decay): sse_encode_i_32(0, serializer); sse_encode_String(field, serializer);
+sse_encode_decay_function(function, serializer);
+sse_encode_opt_box_autoadd_i_64(origin, serializer);
+sse_encode_f_64(scaleDays, serializer);
+sse_encode_f_64(offsetDays, serializer);
+sse_encode_f_64(decay, serializer);
+case ScoreModifier_LogBoost(field: final field,factor: final factor): sse_encode_i_32(1, serializer); sse_encode_String(field, serializer);
+sse_encode_f_64(factor, serializer);
+case ScoreModifier_StaticRank(field: final field,weight: final weight): sse_encode_i_32(2, serializer); sse_encode_String(field, serializer);
+sse_encode_f_64(weight, serializer);
+  } }
+
+@protected void sse_encode_search_group(SearchGroup self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_String(self.key, serializer);
+sse_encode_u_64(self.count, serializer);
+sse_encode_list_search_hit(self.hits, serializer);
+ }
+
+@protected void sse_encode_search_hit(SearchHit self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.id, serializer);
+sse_encode_String(self.title, serializer);
+sse_encode_String(self.body, serializer);
+sse_encode_f_32(self.score, serializer);
+sse_encode_String(self.metadata, serializer);
+sse_encode_opt_box_autoadd_search_snippet(self.snippet, serializer);
+sse_encode_list_sort_value(self.sortValues, serializer);
+sse_encode_i_64(self.createdAt, serializer);
+sse_encode_i_64(self.updatedAt, serializer);
+sse_encode_opt_box_autoadd_f_64(self.distanceKm, serializer);
+ }
+
+@protected void sse_encode_search_options(SearchOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize(self.limit, serializer);
+sse_encode_opt_box_autoadd_snippet_options(self.snippet, serializer);
+sse_encode_opt_box_autoadd_fuzzy_options(self.fuzzy, serializer);
+sse_encode_opt_box_autoadd_fuzzy_options(self.jamoFuzzy, serializer);
+sse_encode_bool(self.lenient, serializer);
+sse_encode_list_sort_key(self.sort, serializer);
+sse_encode_list_aggregation_request(self.aggregations, serializer);
+sse_encode_opt_String(self.categoryFilter, serializer);
+sse_encode_list_String(self.categoryCounts, serializer);
+sse_encode_opt_box_autoadd_geo_filter(self.geoFilter, serializer);
+sse_encode_opt_String(self.collapse, serializer);
+sse_encode_opt_box_autoadd_group_by_options(self.groupBy, serializer);
+sse_encode_list_score_modifier(self.scoreModifiers, serializer);
+sse_encode_field_selection(self.fields, serializer);
+sse_encode_opt_box_autoadd_usize(self.bodyMaxChars, serializer);
+ }
+
+@protected void sse_encode_search_response(SearchResponse self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_search_hit(self.hits, serializer);
+sse_encode_list_query_diagnostic(self.diagnostics, serializer);
+sse_encode_list_aggregation_result(self.aggregations, serializer);
+sse_encode_list_category_counts(self.categoryCounts, serializer);
+sse_encode_list_search_group(self.groups, serializer);
+ }
+
+@protected void sse_encode_search_result(SearchResult self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.id, serializer);
+sse_encode_String(self.title, serializer);
+sse_encode_String(self.body, serializer);
+sse_encode_f_32(self.score, serializer);
+sse_encode_String(self.metadata, serializer);
+sse_encode_i_64(self.createdAt, serializer);
+sse_encode_i_64(self.updatedAt, serializer);
+ }
+
+@protected void sse_encode_search_snippet(SearchSnippet self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.fragment, serializer);
+sse_encode_list_highlight_range(self.highlights, serializer);
+sse_encode_opt_String(self.html, serializer);
+ }
+
+@protected void sse_encode_snippet_options(SnippetOptions self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.field, serializer);
+sse_encode_usize(self.maxNumChars, serializer);
+sse_encode_bool(self.html, serializer);
+ }
+
+@protected void sse_encode_sort_by(SortBy self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case SortBy_Score(): sse_encode_i_32(0, serializer); case SortBy_Field(field0: final field0): sse_encode_i_32(1, serializer); sse_encode_String(field0, serializer);
+case SortBy_Distance(field0: final field0): sse_encode_i_32(2, serializer); sse_encode_box_autoadd_geo_point(field0, serializer);
+  } }
+
+@protected void sse_encode_sort_key(SortKey self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_sort_by(self.by, serializer);
+sse_encode_sort_order(self.order, serializer);
+ }
+
+@protected void sse_encode_sort_order(SortOrder self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_sort_value(SortValue self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case SortValue_Integer(field0: final field0): sse_encode_i_32(0, serializer); sse_encode_i_64(field0, serializer);
+case SortValue_Float(field0: final field0): sse_encode_i_32(1, serializer); sse_encode_f_64(field0, serializer);
+case SortValue_Text(field0: final field0): sse_encode_i_32(2, serializer); sse_encode_String(field0, serializer);
+case SortValue_Missing(): sse_encode_i_32(3, serializer);   } }
+
+@protected void sse_encode_stored_document(StoredDocument self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.id, serializer);
+sse_encode_String(self.title, serializer);
+sse_encode_String(self.body, serializer);
+sse_encode_String(self.metadata, serializer);
+sse_encode_i_64(self.createdAt, serializer);
+sse_encode_i_64(self.updatedAt, serializer);
+ }
+
+@protected void sse_encode_suggestion(Suggestion self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.text, serializer);
+sse_encode_suggestion_source(self.source, serializer);
+sse_encode_u_64(self.docCount, serializer);
+ }
+
+@protected void sse_encode_suggestion_source(SuggestionSource self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_token_detail(TokenDetail self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.surface, serializer);
+sse_encode_String(self.pos, serializer);
+sse_encode_list_String(self.details, serializer);
+ }
+
+@protected void sse_encode_token_mode(TokenMode self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_u_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint32(self); }
+
+@protected void sse_encode_u_64(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putBigUint64(self); }
+
+@protected void sse_encode_u_8(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint8(self); }
+
+@protected void sse_encode_unit(void self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+ }
+
+@protected void sse_encode_usize(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putBigUint64(self); }
+
+@protected void sse_encode_variant_mapping(VariantMapping self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.variant, serializer);
+sse_encode_String(self.canonical, serializer);
+ }
+                }
+                
@@ -3,212 +3,708 @@
 
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
+
 // Static analysis wrongly picks the IO variant, thus ignore this
 // ignore_for_file: argument_type_not_assignable
 
+import 'api/aggregation.dart';
+import 'api/explain.dart';
+import 'api/facet.dart';
+import 'api/geo.dart';
+import 'api/group.dart';
+import 'api/more_like_this.dart';
+import 'api/projection.dart';
+import 'api/query.dart';
+import 'api/scoring.dart';
 import 'api/search.dart';
 import 'api/simple.dart';
+import 'api/sort.dart';
+import 'api/variant.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated_web.dart';
 
-abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
-  RustLibApiImplPlatform({
-    required super.handler,
-    required super.wire,
-    required super.generalizedFrbRustBinding,
-    required super.portManager,
-  });
 
-  @protected
-  String dco_decode_String(dynamic raw);
 
-  @protected
-  DictionaryType dco_decode_dictionary_type(dynamic raw);
 
-  @protected
-  DocumentInput dco_decode_document_input(dynamic raw);
+                abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
+                  RustLibApiImplPlatform({
+                    required super.handler,
+                    required super.wire,
+                    required super.generalizedFrbRustBinding,
+                    required super.portManager,
+                  });
+
+                  
+
+                  @protected String dco_decode_String(dynamic raw);
+
+@protected AggregationBucket dco_decode_aggregation_bucket(dynamic raw);
+
+@protected AggregationKind dco_decode_aggregation_kind(dynamic raw);
+
+@protected AggregationRange dco_decode_aggregation_range(dynamic raw);
+
+@protected AggregationRequest dco_decode_aggregation_request(dynamic raw);
+
+@protected AggregationResult dco_decode_aggregation_result(dynamic raw);
+
+@protected AggregationStats dco_decode_aggregation_stats(dynamic raw);
+
+@protected AggregationValue dco_decode_aggregation_value(dynamic raw);
+
+@protected bool dco_decode_bool(dynamic raw);
+
+@protected AggregationStats dco_decode_box_autoadd_aggregation_stats(dynamic raw);
+
+@protected double dco_decode_box_autoadd_f_64(dynamic raw);
+
+@protected FuzzyOptions dco_decode_box_autoadd_fuzzy_options(dynamic raw);
+
+@protected GeoFilter dco_decode_box_autoadd_geo_filter(dynamic raw);
+
+@protected GeoPoint dco_decode_box_autoadd_geo_point(dynamic raw);
+
+@protected GroupByOptions dco_decode_box_autoadd_group_by_options(dynamic raw);
+
+@protected PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw);
+
+@protected MoreLikeThisOptions dco_decode_box_autoadd_more_like_this_options(dynamic raw);
+
+@protected MoreLikeThisSource dco_decode_box_autoadd_more_like_this_source(dynamic raw);
+
+@protected QueryNode dco_decode_box_autoadd_query_node(dynamic raw);
+
+@protected RangeBound dco_decode_box_autoadd_range_bound(dynamic raw);
+
+@protected ScoreExplanation dco_decode_box_autoadd_score_explanation(dynamic raw);
+
+@protected SearchOptions dco_decode_box_autoadd_search_options(dynamic raw);
+
+@protected SearchSnippet dco_decode_box_autoadd_search_snippet(dynamic raw);
+
+@protected SnippetOptions dco_decode_box_autoadd_snippet_options(dynamic raw);
+
+@protected StoredDocument dco_decode_box_autoadd_stored_document(dynamic raw);
+
+@protected int dco_decode_box_autoadd_u_32(dynamic raw);
+
+@protected BigInt dco_decode_box_autoadd_usize(dynamic raw);
+
+@protected QueryNode dco_decode_box_query_node(dynamic raw);
+
+@protected CategoryCount dco_decode_category_count(dynamic raw);
+
+@protected CategoryCounts dco_decode_category_counts(dynamic raw);
+
+@protected DecayFunction dco_decode_decay_function(dynamic raw);
+
+@protected DictionaryType dco_decode_dictionary_type(dynamic raw);
+
+@protected DocumentInput dco_decode_document_input(dynamic raw);
+
+@protected DocumentPage dco_decode_document_page(dynamic raw);
+
+@protected double dco_decode_f_32(dynamic raw);
+
+@protected double dco_decode_f_64(dynamic raw);
+
+@protected FieldSelection dco_decode_field_selection(dynamic raw);
+
+@protected FuzzyOptions dco_decode_fuzzy_options(dynamic raw);
+
+@protected GeoFilter dco_decode_geo_filter(dynamic raw);
+
+@protected GeoPoint dco_decode_geo_point(dynamic raw);
+
+@protected GroupByOptions dco_decode_group_by_options(dynamic raw);
+
+@protected HighlightRange dco_decode_highlight_range(dynamic raw);
+
+@protected int dco_decode_i_32(dynamic raw);
+
+@protected PlatformInt64 dco_decode_i_64(dynamic raw);
+
+@protected List<String> dco_decode_list_String(dynamic raw);
+
+@protected List<AggregationBucket> dco_decode_list_aggregation_bucket(dynamic raw);
+
+@protected List<AggregationRange> dco_decode_list_aggregation_range(dynamic raw);
+
+@protected List<AggregationRequest> dco_decode_list_aggregation_request(dynamic raw);
+
+@protected List<AggregationResult> dco_decode_list_aggregation_result(dynamic raw);
+
+@protected List<CategoryCount> dco_decode_list_category_count(dynamic raw);
+
+@protected List<CategoryCounts> dco_decode_list_category_counts(dynamic raw);
+
+@protected List<DocumentInput> dco_decode_list_document_input(dynamic raw);
+
+@protected List<HighlightRange> dco_decode_list_highlight_range(dynamic raw);
+
+@protected Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+
+@protected List<QueryDiagnostic> dco_decode_list_query_diagnostic(dynamic raw);
+
+@protected List<QueryNode> dco_decode_list_query_node(dynamic raw);
+
+@protected List<ScoreExplanation> dco_decode_list_score_explanation(dynamic raw);
+
+@protected List<ScoreModifier> dco_decode_list_score_modifier(dynamic raw);
+
+@protected List<SearchGroup> dco_decode_list_search_group(dynamic raw);
+
+@protected List<SearchHit> dco_decode_list_search_hit(dynamic raw);
+
+@protected List<SearchResult> dco_decode_list_search_result(dynamic raw);
+
+@protected List<SortKey> dco_decode_list_sort_key(dynamic raw);
+
+@protected List<SortValue> dco_decode_list_sort_value(dynamic raw);
+
+@protected List<StoredDocument> dco_decode_list_stored_document(dynamic raw);
+
+@protected List<Suggestion> dco_decode_list_suggestion(dynamic raw);
+
+@protected List<TokenDetail> dco_decode_list_token_detail(dynamic raw);
+
+@protected List<VariantMapping> dco_decode_list_variant_mapping(dynamic raw);
+
+@protected MoreLikeThisOptions dco_decode_more_like_this_options(dynamic raw);
+
+@protected MoreLikeThisSource dco_decode_more_like_this_source(dynamic raw);
+
+@protected String? dco_decode_opt_String(dynamic raw);
+
+@protected double? dco_decode_opt_box_autoadd_f_64(dynamic raw);
+
+@protected FuzzyOptions? dco_decode_opt_box_autoadd_fuzzy_options(dynamic raw);
+
+@protected GeoFilter? dco_decode_opt_box_autoadd_geo_filter(dynamic raw);
+
+@protected GroupByOptions? dco_decode_opt_box_autoadd_group_by_options(dynamic raw);
+
+@protected PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw);
+
+@protected RangeBound? dco_decode_opt_box_autoadd_range_bound(dynamic raw);
+
+@protected SearchSnippet? dco_decode_opt_box_autoadd_search_snippet(dynamic raw);
+
+@protected SnippetOptions? dco_decode_opt_box_autoadd_snippet_options(dynamic raw);
+
+@protected StoredDocument? dco_decode_opt_box_autoadd_stored_document(dynamic raw);
+
+@protected int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
+
+@protected BigInt? dco_decode_opt_box_autoadd_usize(dynamic raw);
+
+@protected QueryDiagnostic dco_decode_query_diagnostic(dynamic raw);
+
+@protected QueryNode dco_decode_query_node(dynamic raw);
+
+@protected RangeBound dco_decode_range_bound(dynamic raw);
+
+@protected RangeValue dco_decode_range_value(dynamic raw);
+
+@protected ScoreExplanation dco_decode_score_explanation(dynamic raw);
+
+@protected ScoreModifier dco_decode_score_modifier(dynamic raw);
+
+@protected SearchGroup dco_decode_search_group(dynamic raw);
+
+@protected SearchHit dco_decode_search_hit(dynamic raw);
+
+@protected SearchOptions dco_decode_search_options(dynamic raw);
+
+@protected SearchResponse dco_decode_search_response(dynamic raw);
+
+@protected SearchResult dco_decode_search_result(dynamic raw);
+
+@protected SearchSnippet dco_decode_search_snippet(dynamic raw);
+
+@protected SnippetOptions dco_decode_snippet_options(dynamic raw);
+
+@protected SortBy dco_decode_sort_by(dynamic raw);
+
+@protected SortKey dco_decode_sort_key(dynamic raw);
+
+@protected SortOrder dco_decode_sort_order(dynamic raw);
+
+@protected SortValue dco_decode_sort_value(dynamic raw);
+
+@protected StoredDocument dco_decode_stored_document(dynamic raw);
+
+@protected Suggestion dco_decode_suggestion(dynamic raw);
+
+@protected SuggestionSource dco_decode_suggestion_source(dynamic raw);
+
+@protected TokenDetail dco_decode_token_detail(dynamic raw);
+
+@protected TokenMode dco_decode_token_mode(dynamic raw);
+
+@protected int dco_decode_u_32(dynamic raw);
+
+@protected BigInt dco_decode_u_64(dynamic raw);
+
+@protected int dco_decode_u_8(dynamic raw);
+
+@protected void dco_decode_unit(dynamic raw);
+
+@protected BigInt dco_decode_usize(dynamic raw);
+
+@protected VariantMapping dco_decode_variant_mapping(dynamic raw);
+
+@protected String sse_decode_String(SseDeserializer deserializer);
+
+@protected AggregationBucket sse_decode_aggregation_bucket(SseDeserializer deserializer);
+
+@protected AggregationKind sse_decode_aggregation_kind(SseDeserializer deserializer);
+
+@protected AggregationRange sse_decode_aggregation_range(SseDeserializer deserializer);
+
+@protected AggregationRequest sse_decode_aggregation_request(SseDeserializer deserializer);
+
+@protected AggregationResult sse_decode_aggregation_result(SseDeserializer deserializer);
+
+@protected AggregationStats sse_decode_aggregation_stats(SseDeserializer deserializer);
+
+@protected AggregationValue sse_decode_aggregation_value(SseDeserializer deserializer);
+
+@protected bool sse_decode_bool(SseDeserializer deserializer);
+
+@protected AggregationStats sse_decode_box_autoadd_aggregation_stats(SseDeserializer deserializer);
+
+@protected double sse_decode_box_autoadd_f_64(SseDeserializer deserializer);
+
+@protected FuzzyOptions sse_decode_box_autoadd_fuzzy_options(SseDeserializer deserializer);
+
+@protected GeoFilter sse_decode_box_autoadd_geo_filter(SseDeserializer deserializer);
+
+@protected GeoPoint sse_decode_box_autoadd_geo_point(SseDeserializer deserializer);
+
+@protected GroupByOptions sse_decode_box_autoadd_group_by_options(SseDeserializer deserializer);
+
+@protected PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer);
+
+@protected MoreLikeThisOptions sse_decode_box_autoadd_more_like_this_options(SseDeserializer deserializer);
+
+@protected MoreLikeThisSource sse_decode_box_autoadd_more_like_this_source(SseDeserializer deserializer);
+
+@protected QueryNode sse_decode_box_autoadd_query_node(SseDeserializer deserializer);
+
+@protected RangeBound sse_decode_box_autoadd_range_bound(SseDeserializer deserializer);
+
+@protected ScoreExplanation sse_decode_box_autoadd_score_explanation(SseDeserializer deserializer);
+
+@protected SearchOptions sse_decode_box_autoadd_search_options(SseDeserializer deserializer);
+
+@protected SearchSnippet sse_decode_box_autoadd_search_snippet(SseDeserializer deserializer);
+
+@protected SnippetOptions sse_decode_box_autoadd_snippet_options(SseDeserializer deserializer);
+
+@protected StoredDocument sse_decode_box_autoadd_stored_document(SseDeserializer deserializer);
+
+@protected int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
+
+@protected BigInt sse_decode_box_autoadd_usize(SseDeserializer deserializer);
+
+@protected QueryNode sse_decode_box_query_node(SseDeserializer deserializer);
+
+@protected CategoryCount sse_decode_category_count(SseDeserializer deserializer);
+
+@protected CategoryCounts sse_decode_category_counts(SseDeserializer deserializer);
+
+@protected DecayFunction sse_decode_decay_function(SseDeserializer deserializer);
+
+@protected DictionaryType sse_decode_dictionary_type(SseDeserializer deserializer);
+
+@protected DocumentInput sse_decode_document_input(SseDeserializer deserializer);
+
+@protected DocumentPage sse_decode_document_page(SseDeserializer deserializer);
+
+@protected double sse_decode_f_32(SseDeserializer deserializer);
+
+@protected double sse_decode_f_64(SseDeserializer deserializer);
+
+@protected FieldSelection sse_decode_field_selection(SseDeserializer deserializer);
+
+@protected FuzzyOptions sse_decode_fuzzy_options(SseDeserializer deserializer);
+
+@protected GeoFilter sse_decode_geo_filter(SseDeserializer deserializer);
+
+@protected GeoPoint sse_decode_geo_point(SseDeserializer deserializer);
+
+@protected GroupByOptions sse_decode_group_by_options(SseDeserializer deserializer);
+
+@protected HighlightRange sse_decode_highlight_range(SseDeserializer deserializer);
+
+@protected int sse_decode_i_32(SseDeserializer deserializer);
+
+@protected PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
+
+@protected List<String> sse_decode_list_String(SseDeserializer deserializer);
+
+@protected List<AggregationBucket> sse_decode_list_aggregation_bucket(SseDeserializer deserializer);
+
+@protected List<AggregationRange> sse_decode_list_aggregation_range(SseDeserializer deserializer);
+
+@protected List<AggregationRequest> sse_decode_list_aggregation_request(SseDeserializer deserializer);
+
+@protected List<AggregationResult> sse_decode_list_aggregation_result(SseDeserializer deserializer);
+
+@protected List<CategoryCount> sse_decode_list_category_count(SseDeserializer deserializer);
+
+@protected List<CategoryCounts> sse_decode_list_category_counts(SseDeserializer deserializer);
+
+@protected List<DocumentInput> sse_decode_list_document_input(SseDeserializer deserializer);
+
+@protected List<HighlightRange> sse_decode_list_highlight_range(SseDeserializer deserializer);
+
+@protected Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+
+@protected List<QueryDiagnostic> sse_decode_list_query_diagnostic(SseDeserializer deserializer);
+
+@protected List<QueryNode> sse_decode_list_query_node(SseDeserializer deserializer);
+
+@protected List<ScoreExplanation> sse_decode_list_score_explanation(SseDeserializer deserializer);
+
+@protected List<ScoreModifier> sse_decode_list_score_modifier(SseDeserializer deserializer);
+
+@protected List<SearchGroup> sse_decode_list_search_group(SseDeserializer deserializer);
+
+@protected List<SearchHit> sse_decode_list_search_hit(SseDeserializer deserializer);
+
+@protected List<SearchResult> sse_decode_list_search_result(SseDeserializer deserializer);
+
+@protected List<SortKey> sse_decode_list_sort_key(SseDeserializer deserializer);
+
+@protected List<SortValue> sse_decode_list_sort_value(SseDeserializer deserializer);
+
+@protected List<StoredDocument> sse_decode_list_stored_document(SseDeserializer deserializer);
+
+@protected List<Suggestion> sse_decode_list_suggestion(SseDeserializer deserializer);
+
+@protected List<TokenDetail> sse_decode_list_token_detail(SseDeserializer deserializer);
+
+@protected List<VariantMapping> sse_decode_list_variant_mapping(SseDeserializer deserializer);
+
+@protected MoreLikeThisOptions sse_decode_more_like_this_options(SseDeserializer deserializer);
+
+@protected MoreLikeThisSource sse_decode_more_like_this_source(SseDeserializer deserializer);
+
+@protected String? sse_decode_opt_String(SseDeserializer deserializer);
+
+@protected double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer);
+
+@protected FuzzyOptions? sse_decode_opt_box_autoadd_fuzzy_options(SseDeserializer deserializer);
+
+@protected GeoFilter? sse_decode_opt_box_autoadd_geo_filter(SseDeserializer deserializer);
+
+@protected GroupByOptions? sse_decode_opt_box_autoadd_group_by_options(SseDeserializer deserializer);
+
+@protected PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer);
+
+@protected RangeBound? sse_decode_opt_box_autoadd_range_bound(SseDeserializer deserializer);
+
+@protected SearchSnippet? sse_decode_opt_box_autoadd_search_snippet(SseDeserializer deserializer);
+
+@protected SnippetOptions? sse_decode_opt_box_autoadd_snippet_options(SseDeserializer deserializer);
+
+@protected StoredDocument? sse_decode_opt_box_autoadd_stored_document(SseDeserializer deserializer);
+
+@protected int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+
+@protected BigInt? sse_decode_opt_box_autoadd_usize(SseDeserializer deserializer);
+
+@protected QueryDiagnostic sse_decode_query_diagnostic(SseDeserializer deserializer);
+
+@protected QueryNode sse_decode_query_node(SseDeserializer deserializer);
+
+@protected RangeBound sse_decode_range_bound(SseDeserializer deserializer);
+
+@protected RangeValue sse_decode_range_value(SseDeserializer deserializer);
+
+@protected ScoreExplanation sse_decode_score_explanation(SseDeserializer deserializer);
+
+@protected ScoreModifier sse_decode_score_modifier(SseDeserializer deserializer);
+
+@protected SearchGroup sse_decode_search_group(SseDeserializer deserializer);
+
+@protected SearchHit sse_decode_search_hit(SseDeserializer deserializer);
+
+@protected SearchOptions sse_decode_search_options(SseDeserializer deserializer);
+
+@protected SearchResponse sse_decode_search_response(SseDeserializer deserializer);
+
+@protected SearchResult sse_decode_search_result(SseDeserializer deserializer);
+
+@protected SearchSnippet sse_decode_search_snippet(SseDeserializer deserializer);
+
+@protected SnippetOptions sse_decode_snippet_options(SseDeserializer deserializer);
+
+@protected SortBy sse_decode_sort_by(SseDeserializer deserializer);
+
+@protected SortKey sse_decode_sort_key(SseDeserializer deserializer);
+
+@protected SortOrder sse_decode_sort_order(SseDeserializer deserializer);
+
+@protected SortValue sse_decode_sort_value(SseDeserializer deserializer);
+
+@protected StoredDocument sse_decode_stored_document(SseDeserializer deserializer);
+
+@protected Suggestion sse_decode_suggestion(SseDeserializer deserializer);
+
+@protected SuggestionSource sse_decode_suggestion_source(SseDeserializer deserializer);
+
+@protected TokenDetail sse_decode_token_detail(SseDeserializer deserializer);
+
+@protected TokenMode sse_decode_token_mode(SseDeserializer deserializer);
+
+@protected int sse_decode_u_32(SseDeserializer deserializer);
+
+@protected BigInt sse_decode_u_64(SseDeserializer deserializer);
+
+@protected int sse_decode_u_8(SseDeserializer deserializer);
+
+@protected void sse_decode_unit(SseDeserializer deserializer);
+
+@protected BigInt sse_decode_usize(SseDeserializer deserializer);
+
+@protected VariantMapping sse_decode_variant_mapping(SseDeserializer deserializer);
+
+@protected void sse_encode_String(String self, SseSerializer serializer);
+
+@protected void sse_encode_aggregation_bucket(AggregationBucket self, SseSerializer serializer);
+
+@protected void sse_encode_aggregation_kind(AggregationKind self, SseSerializer serializer);
+
+@protected void sse_encode_aggregation_range(AggregationRange self, SseSerializer serializer);
+
+@protected void sse_encode_aggregation_request(AggregationRequest self, SseSerializer serializer);
+
+@protected void sse_encode_aggregation_result(AggregationResult self, SseSerializer serializer);
+
+@protected void sse_encode_aggregation_stats(AggregationStats self, SseSerializer serializer);
+
+@protected void sse_encode_aggregation_value(AggregationValue self, SseSerializer serializer);
+
+@protected void sse_encode_bool(bool self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_aggregation_stats(AggregationStats self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_fuzzy_options(FuzzyOptions self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_geo_filter(GeoFilter self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_geo_point(GeoPoint self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_group_by_options(GroupByOptions self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_i_64(PlatformInt64 self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_more_like_this_options(MoreLikeThisOptions self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_more_like_this_source(MoreLikeThisSource self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_query_node(QueryNode self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_range_bound(RangeBound self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_score_explanation(ScoreExplanation self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_search_options(SearchOptions self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_search_snippet(SearchSnippet self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_snippet_options(SnippetOptions self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_stored_document(StoredDocument self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_usize(BigInt self, SseSerializer serializer);
+
+@protected void sse_encode_box_query_node(QueryNode self, SseSerializer serializer);
+
+@protected void sse_encode_category_count(CategoryCount self, SseSerializer serializer);
+
+@protected void sse_encode_category_counts(CategoryCounts self, SseSerializer serializer);
+
+@protected void sse_encode_decay_function(DecayFunction self, SseSerializer serializer);
+
+@protected void sse_encode_dictionary_type(DictionaryType self, SseSerializer serializer);
+
+@protected void sse_encode_document_input(DocumentInput self, SseSerializer serializer);
+
+@protected void sse_encode_document_page(DocumentPage self, SseSerializer serializer);
+
+@protected void sse_encode_f_32(double self, SseSerializer serializer);
+
+@protected void sse_encode_f_64(double self, SseSerializer serializer);
+
+@protected void sse_encode_field_selection(FieldSelection self, SseSerializer serializer);
+
+@protected void sse_encode_fuzzy_options(FuzzyOptions self, SseSerializer serializer);
+
+@protected void sse_encode_geo_filter(GeoFilter self, SseSerializer serializer);
+
+@protected void sse_encode_geo_point(GeoPoint self, SseSerializer serializer);
+
+@protected void sse_encode_group_by_options(GroupByOptions self, SseSerializer serializer);
+
+@protected void sse_encode_highlight_range(HighlightRange self, SseSerializer serializer);
+
+@protected void sse_encode_i_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
+
+@protected void sse_encode_list_String(List<String> self, SseSerializer serializer);
+
+@protected void sse_encode_list_aggregation_bucket(List<AggregationBucket> self, SseSerializer serializer);
+
+@protected void sse_encode_list_aggregation_range(List<AggregationRange> self, SseSerializer serializer);
+
+@protected void sse_encode_list_aggregation_request(List<AggregationRequest> self, SseSerializer serializer);
+
+@protected void sse_encode_list_aggregation_result(List<AggregationResult> self, SseSerializer serializer);
+
+@protected void sse_encode_list_category_count(List<CategoryCount> self, SseSerializer serializer);
+
+@protected void sse_encode_list_category_counts(List<CategoryCounts> self, SseSerializer serializer);
+
+@protected void sse_encode_list_document_input(List<DocumentInput> self, SseSerializer serializer);
+
+@protected void sse_encode_list_highlight_range(List<HighlightRange> self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer);
+
+@protected void sse_encode_list_query_diagnostic(List<QueryDiagnostic> self, SseSerializer serializer);
+
+@protected void sse_encode_list_query_node(List<QueryNode> self, SseSerializer serializer);
+
+@protected void sse_encode_list_score_explanation(List<ScoreExplanation> self, SseSerializer serializer);
+
+@protected void sse_encode_list_score_modifier(List<ScoreModifier> self, SseSerializer serializer);
+
+@protected void sse_encode_list_search_group(List<SearchGroup> self, SseSerializer serializer);
+
+@protected void sse_encode_list_search_hit(List<SearchHit> self, SseSerializer serializer);
 
-  @protected
-  double dco_decode_f_32(dynamic raw);
+@protected void sse_encode_list_search_result(List<SearchResult> self, SseSerializer serializer);
 
-  @protected
-  int dco_decode_i_32(dynamic raw);
+@protected void sse_encode_list_sort_key(List<SortKey> self, SseSerializer serializer);
 
-  @protected
-  List<String> dco_decode_list_String(dynamic raw);
+@protected void sse_encode_list_sort_value(List<SortValue> self, SseSerializer serializer);
 
-  @protected
-  List<DocumentInput> dco_decode_list_document_input(dynamic raw);
+@protected void sse_encode_list_stored_document(List<StoredDocument> self, SseSerializer serializer);
 
-  @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+@protected void sse_encode_list_suggestion(List<Suggestion> self, SseSerializer serializer);
 
-  @protected
-  List<SearchResult> dco_decode_list_search_result(dynamic raw);
+@protected void sse_encode_list_token_detail(List<TokenDetail> self, SseSerializer serializer);
 
-  @protected
-  List<TokenDetail> dco_decode_list_token_detail(dynamic raw);
+@protected void sse_encode_list_variant_mapping(List<VariantMapping> self, SseSerializer serializer);
 
-  @protected
-  SearchResult dco_decode_search_result(dynamic raw);
+@protected void sse_encode_more_like_this_options(MoreLikeThisOptions self, SseSerializer serializer);
 
-  @protected
-  TokenDetail dco_decode_token_detail(dynamic raw);
+@protected void sse_encode_more_like_this_source(MoreLikeThisSource self, SseSerializer serializer);
 
-  @protected
-  TokenMode dco_decode_token_mode(dynamic raw);
+@protected void sse_encode_opt_String(String? self, SseSerializer serializer);
 
-  @protected
-  BigInt dco_decode_u_64(dynamic raw);
+@protected void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer);
 
-  @protected
-  int dco_decode_u_8(dynamic raw);
+@protected void sse_encode_opt_box_autoadd_fuzzy_options(FuzzyOptions? self, SseSerializer serializer);
 
-  @protected
-  void dco_decode_unit(dynamic raw);
+@protected void sse_encode_opt_box_autoadd_geo_filter(GeoFilter? self, SseSerializer serializer);
 
-  @protected
-  BigInt dco_decode_usize(dynamic raw);
+@protected void sse_encode_opt_box_autoadd_group_by_options(GroupByOptions? self, SseSerializer serializer);
 
-  @protected
-  String sse_decode_String(SseDeserializer deserializer);
+@protected void sse_encode_opt_box_autoadd_i_64(PlatformInt64? self, SseSerializer serializer);
 
-  @protected
-  DictionaryType sse_decode_dictionary_type(SseDeserializer deserializer);
+@protected void sse_encode_opt_box_autoadd_range_bound(RangeBound? self, SseSerializer serializer);
 
-  @protected
-  DocumentInput sse_decode_document_input(SseDeserializer deserializer);
+@protected void sse_encode_opt_box_autoadd_search_snippet(SearchSnippet? self, SseSerializer serializer);
 
-  @protected
-  double sse_decode_f_32(SseDeserializer deserializer);
+@protected void sse_encode_opt_box_autoadd_snippet_options(SnippetOptions? self, SseSerializer serializer);
 
-  @protected
-  int sse_decode_i_32(SseDeserializer deserializer);
+@protected void sse_encode_opt_box_autoadd_stored_document(StoredDocument? self, SseSerializer serializer);
 
-  @protected
-  List<String> sse_decode_list_String(SseDeserializer deserializer);
+@protected void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
 
-  @protected
-  List<DocumentInput> sse_decode_list_document_input(
-    SseDeserializer deserializer,
-  );
+@protected void sse_encode_opt_box_autoadd_usize(BigInt? self, SseSerializer serializer);
 
-  @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+@protected void sse_encode_query_diagnostic(QueryDiagnostic self, SseSerializer serializer);
 
-  @protected
-  List<SearchResult> sse_decode_list_search_result(
-    SseDeserializer deserializer,
-  );
+@protected void sse_encode_query_node(QueryNode self, SseSerializer serializer);
 
-  @protected
-  List<TokenDetail> sse_decode_list_token_detail(SseDeserializer deserializer);
+@protected void sse_encode_range_bound(RangeBound self, SseSerializer serializer);
 
-  @protected
-  SearchResult sse_decode_search_result(SseDeserializer deserializer);
+@protected void sse_encode_range_value(RangeValue self, SseSerializer serializer);
 
-  @protected
-  TokenDetail sse_decode_token_detail(SseDeserializer deserializer);
+@protected void sse_encode_score_explanation(ScoreExplanation self, SseSerializer serializer);
 
-  @protected
-  TokenMode sse_decode_token_mode(SseDeserializer deserializer);
+@protected void sse_encode_score_modifier(ScoreModifier self, SseSerializer serializer);
 
-  @protected
-  BigInt sse_decode_u_64(SseDeserializer deserializer);
+@protected void sse_encode_search_group(SearchGroup self, SseSerializer serializer);
 
-  @protected
-  int sse_decode_u_8(SseDeserializer deserializer);
+@protected void sse_encode_search_hit(SearchHit self, SseSerializer serializer);
 
-  @protected
-  void sse_decode_unit(SseDeserializer deserializer);
+@protected void sse_encode_search_options(SearchOptions self, SseSerializer serializer);
 
-  @protected
-  BigInt sse_decode_usize(SseDeserializer deserializer);
+@protected void sse_encode_search_response(SearchResponse self, SseSerializer serializer);
 
-  @protected
-  bool sse_decode_bool(SseDeserializer deserializer);
+@protected void sse_encode_search_result(SearchResult self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_String(String self, SseSerializer serializer);
+@protected void sse_encode_search_snippet(SearchSnippet self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_dictionary_type(
-    DictionaryType self,
-    SseSerializer serializer,
-  );
+@protected void sse_encode_snippet_options(SnippetOptions self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_document_input(DocumentInput self, SseSerializer serializer);
+@protected void sse_encode_sort_by(SortBy self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_f_32(double self, SseSerializer serializer);
+@protected void sse_encode_sort_key(SortKey self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_i_32(int self, SseSerializer serializer);
+@protected void sse_encode_sort_order(SortOrder self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_list_String(List<String> self, SseSerializer serializer);
+@protected void sse_encode_sort_value(SortValue self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_list_document_input(
-    List<DocumentInput> self,
-    SseSerializer serializer,
-  );
+@protected void sse_encode_stored_document(StoredDocument self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
-    SseSerializer serializer,
-  );
+@protected void sse_encode_suggestion(Suggestion self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_list_search_result(
-    List<SearchResult> self,
-    SseSerializer serializer,
-  );
+@protected void sse_encode_suggestion_source(SuggestionSource self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_list_token_detail(
-    List<TokenDetail> self,
-    SseSerializer serializer,
-  );
+@protected void sse_encode_token_detail(TokenDetail self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_search_result(SearchResult self, SseSerializer serializer);
+@protected void sse_encode_token_mode(TokenMode self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_token_detail(TokenDetail self, SseSerializer serializer);
+@protected void sse_encode_u_32(int self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_token_mode(TokenMode self, SseSerializer serializer);
+@protected void sse_encode_u_64(BigInt self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_u_64(BigInt self, SseSerializer serializer);
+@protected void sse_encode_u_8(int self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_u_8(int self, SseSerializer serializer);
+@protected void sse_encode_unit(void self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_unit(void self, SseSerializer serializer);
+@protected void sse_encode_usize(BigInt self, SseSerializer serializer);
 
-  @protected
-  void sse_encode_usize(BigInt self, SseSerializer serializer);
+@protected void sse_encode_variant_mapping(VariantMapping self, SseSerializer serializer);
+                }
+                
 
-  @protected
-  void sse_encode_bool(bool self, SseSerializer serializer);
-}
 
 // Section: wire_class
 
 class RustLibWire implements BaseWire {
-  RustLibWire.fromExternalLibrary(ExternalLibrary lib);
-}
+            RustLibWire.fromExternalLibrary(ExternalLibrary lib);
 
-@JS('wasm_bindgen')
-external RustLibWasmModule get wasmModule;
+            
+        }
+        @JS('wasm_bindgen') external RustLibWasmModule get wasmModule;
 
-@JS()
-@anonymous
-extension type RustLibWasmModule._(JSObject _) implements JSObject {}
+        @JS() @anonymous extension type RustLibWasmModule._(JSObject _) implements JSObject {
+            
+        }
+        
\ No newline at end of file
@@ -0,0 +1,243 @@
+use serde_json::{json, Map, Value as JsonValue};
+use tantivy::aggregation::agg_req::Aggregations;
+use tantivy::aggregation::agg_result::{
+    AggregationResult as TantivyAggregationResult, AggregationResults, BucketEntries,
+    BucketResult, MetricResult,
+};
+use tantivy::aggregation::{AggregationCollector, AggregationLimitsGuard, Key};
+
+/// 집계 요청 (결과는 같은 `name` 으로 요청 순서대로 반환됩니다)
+#[derive(Clone, Debug)]
+pub struct AggregationRequest {
+    pub name: String,
+    pub kind: AggregationKind,
+}
+
+/// 집계 종류
+///
+/// `field` 에는 fast field 이름이나 메타데이터 JSON 경로(`metadata.country`)를 지정합니다.
+#[derive(Clone, Debug)]
+pub enum AggregationKind {
+    /// 값별 문서 수 (문서 수 내림차순 상위 `size` 개)
+    Terms { field: String, size: u32 },
+    /// 지정한 구간별 문서 수 (from 이상, to 미만)
+    Range {
+        field: String,
+        ranges: Vec<AggregationRange>,
+    },
+    /// 고정 간격 숫자 히스토그램
+    Histogram { field: String, interval: f64 },
+    /// 고정 간격 날짜 히스토그램 (예: "1d", "12h", "30m")
+    DateHistogram {
+        field: String,
+        fixed_interval: String,
+    },
+    Min { field: String },
+    Max { field: String },
+    Avg { field: String },
+    /// 개수, 합계, 최솟값, 최댓값, 평균
+    Stats { field: String },
+}
+
+/// 범위 집계 구간 (None 이면 해당 방향으로 제한 없음)
+#[derive(Clone, Debug)]
+pub struct AggregationRange {
+    pub from: Option<f64>,
+    pub to: Option<f64>,
+}
+
+/// 집계 결과
+#[derive(Clone, Debug)]
+pub struct AggregationResult {
+    pub name: String,
+    pub value: AggregationValue,
+}
+
+/// 집계 결과 값
+#[derive(Clone, Debug)]
+pub enum AggregationValue {
+    /// terms, range, histogram, date_histogram
+    Buckets(Vec<AggregationBucket>),
+    /// min, max, avg (값이 있는 문서가 없으면 None)
+    Metric(Option<f64>),
+    Stats(AggregationStats),
+}
+
+/// 버킷 집계 결과 항목
+#[derive(Clone, Debug)]
+pub struct AggregationBucket {
+    /// 버킷 키 (terms 는 값, histogram 은 구간 시작 값, date_histogram 은 RFC 3339 날짜)
+    pub key: String,
+    pub doc_count: u64,
+    /// range 집계의 구간
+    pub from: Option<f64>,
+    pub to: Option<f64>,
+}
+
+/// stats 집계 결과
+#[derive(Clone, Debug)]
+pub struct AggregationStats {
+    pub count: u64,
+    pub sum: f64,
+    pub min: Option<f64>,
+    pub max: Option<f64>,
+    pub avg: Option<f64>,
+}
+
+// 집계 요청 목록으로 Tantivy 집계 수집기를 만듭니다 (요청이 없으면 None)
+pub(crate) fn aggregation_collector(
+    requests: &[AggregationRequest],
+) -> Result<Option<AggregationCollector>, String> {
+    if requests.is_empty() {
+        return Ok(None);
+    }
+
+    let mut aggregations = Map::new();
+    for request in requests {
+        if aggregations.contains_key(&request.name) {
+            return Err(format!("집계 이름이 중복되었습니다: {}", request.name));
+        }
+        aggregations.insert(request.name.clone(), request_to_json(&request.kind)?);
+    }
+    let aggregations: Aggregations =
+        serde_json::from_value(JsonValue::Object(aggregations)).map_err(|e| e.to_string())?;
+
+    Ok(Some(AggregationCollector::from_aggs(
+        aggregations,
+        AggregationLimitsGuard::default(),
+    )))
+}
+
+// Tantivy(Elasticsearch 호환) 집계 요청 JSON
+fn request_to_json(kind: &AggregationKind) -> Result<JsonValue, String> {
+    Ok(match kind {
+        AggregationKind::Terms { field, size } => {
+            json!({ "terms": { "field": field, "size": size } })
+        }
+        AggregationKind::Range { field, ranges } => {
+            if ranges.is_empty() {
+                return Err("범위 집계에는 구간이 하나 이상 필요합니다.".to_string());
+            }
+            let ranges: Vec<JsonValue> = ranges
+                .iter()
+                .map(|range| {
+                    let mut entry = Map::new();
+                    if let Some(from) = range.from {
+                        entry.insert("from".to_string(), json!(from));
+                    }
+                    if let Some(to) = range.to {
+                        entry.insert("to".to_string(), json!(to));
+                    }
+                    JsonValue::Object(entry)
+                })
+                .collect();
+            json!({ "range": { "field": field, "ranges": ranges } })
+        }
+        AggregationKind::Histogram { field, interval } => {
+            if interval.is_nan() || *interval <= 0.0 {
+                return Err(format!("히스토그램 간격은 0보다 커야 합니다: {}", interval));
+            }
+            json!({ "histogram": { "field": field, "interval": interval } })
+        }
+        AggregationKind::DateHistogram {
+            field,
+            fixed_interval,
+        } => {
+            json!({ "date_histogram": { "field": field, "fixed_interval": fixed_interval } })
+        }
+        AggregationKind::Min { field } => json!({ "min": { "field": field } }),
+        AggregationKind::Max { field } => json!({ "max": { "field": field } }),
+        AggregationKind::Avg { field } => json!({ "avg": { "field": field } }),
+        AggregationKind::Stats { field } => json!({ "stats": { "field": field } }),
+    })
+}
+
+// Tantivy 집계 결과를 요청 순서대로 변환합니다
+pub(crate) fn to_aggregation_results(
+    requests: &[AggregationRequest],
+    mut results: AggregationResults,
+) -> Vec<AggregationResult> {
+    requests
+        .iter()
+        .filter_map(|request| {
+            let result = results.0.remove(&request.name)?;
+            Some(AggregationResult {
+                name: request.name.clone(),
+                value: to_aggregation_value(result),
+            })
+        })
+        .collect()
+}
+
+fn to_aggregation_value(result: TantivyAggregationResult) -> AggregationValue {
+    match result {
+        TantivyAggregationResult::BucketResult(bucket_result) => {
+            AggregationValue::Buckets(to_buckets(bucket_result))
+        }
+        TantivyAggregationResult::MetricResult(metric_result) => match metric_result {
+            MetricResult::Stats(stats) => AggregationValue::Stats(AggregationStats {
+                count: stats.count,
+                sum: stats.sum,
+                min: stats.min,
+                max: stats.max,
+                avg: stats.avg,
+            }),
+            MetricResult::Average(metric)
+            | MetricResult::Count(metric)
+            | MetricResult::Max(metric)
+            | MetricResult::Min(metric)
+            | MetricResult::Sum(metric)
+            | MetricResult::Cardinality(metric) => AggregationValue::Metric(metric.value),
+            // 요청할 수 없는 집계 종류
+            _ => AggregationValue::Metric(None),
+        },
+    }
+}
+
+fn to_buckets(bucket_result: BucketResult) -> Vec<AggregationBucket> {
+    match bucket_result {
+        BucketResult::Terms { buckets, .. } => buckets
+            .into_iter()
+            .map(|bucket| AggregationBucket {
+                key: bucket.key_as_string.unwrap_or_else(|| key_to_string(bucket.key)),
+                doc_count: bucket.doc_count,
+                from: None,
+                to: None,
+            })
+            .collect(),
+        BucketResult::Histogram { buckets } => entries(buckets)
+            .into_iter()
+            .map(|bucket| AggregationBucket {
+                key: bucket.key_as_string.unwrap_or_else(|| key_to_string(bucket.key)),
+                doc_count: bucket.doc_count,
+                from: None,
+                to: None,
+            })
+            .collect(),
+        BucketResult::Range { buckets } => entries(buckets)
+            .into_iter()
+            .map(|bucket| AggregationBucket {
+                key: key_to_string(bucket.key),
+                doc_count: bucket.doc_count,
+                from: bucket.from,
+                to: bucket.to,
+            })
+            .collect(),
+    }
+}
+
+fn entries<T>(buckets: BucketEntries<T>) -> Vec<T> {
+    match buckets {
+        BucketEntries::Vec(buckets) => buckets,
+        BucketEntries::HashMap(buckets) => buckets.into_values().collect(),
+    }
+}
+
+fn key_to_string(key: Key) -> String {
+    match key {
+        Key::Str(text) => text,
+        Key::I64(value) => value.to_string(),
+        Key::U64(value) => value.to_string(),
+        Key::F64(value) => value.to_string(),
+    }
+}
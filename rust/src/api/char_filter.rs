@@ -0,0 +1,39 @@
+use std::sync::Arc;
+
+use tantivy::tokenizer::Tokenizer;
+
+// 텍스트 변환 함수 (문자마다 UTF-8 바이트 길이를 유지해야 토큰 위치가 원문과 맞습니다)
+// 읽기, 병음처럼 길이가 바뀌는 변환은 문자 필터 대신 별도 토크나이저(reading.rs)를 씁니다
+pub(crate) type CharFilterFn = Arc<dyn Fn(&str) -> String + Send + Sync>;
+
+// 토크나이저에 넘기기 전에 텍스트를 변환하는 문자 필터
+#[derive(Clone)]
+pub(crate) struct CharFilterTokenizer<T> {
+    tokenizer: T,
+    filter: CharFilterFn,
+    buffer: String,
+}
+
+impl<T> CharFilterTokenizer<T> {
+    pub(crate) fn new(tokenizer: T, filter: CharFilterFn) -> Self {
+        CharFilterTokenizer {
+            tokenizer,
+            filter,
+            buffer: String::new(),
+        }
+    }
+}
+
+impl<T: Tokenizer> Tokenizer for CharFilterTokenizer<T> {
+    type TokenStream<'a> = T::TokenStream<'a>;
+
+    fn token_stream<'a>(&'a mut self, text: &'a str) -> Self::TokenStream<'a> {
+        let CharFilterTokenizer {
+            tokenizer,
+            filter,
+            buffer,
+        } = self;
+        *buffer = filter(text);
+        tokenizer.token_stream(buffer)
+    }
+}
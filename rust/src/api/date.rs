@@ -0,0 +1,71 @@
+use std::collections::BTreeMap;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde_json::Value as JsonValue;
+use tantivy::schema::OwnedValue;
+use tantivy::time::format_description::well_known::Rfc3339;
+use tantivy::time::{Date, Month, OffsetDateTime, PrimitiveDateTime, Time};
+use tantivy::DateTime;
+
+// 날짜로 인식할 기본 메타데이터 경로
+pub(crate) const DEFAULT_DATE_PATHS: &[&str] = &["createdAt", "updatedAt", "date"];
+
+// 현재 시각 (밀리초 단위)
+pub(crate) fn now() -> DateTime {
+    let millis = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_millis() as i64)
+        .unwrap_or(0);
+    DateTime::from_timestamp_millis(millis)
+}
+
+// 날짜 문자열을 파싱합니다 (RFC 3339 또는 UTC 자정으로 해석하는 YYYY-MM-DD)
+pub(crate) fn parse_date(text: &str) -> Option<DateTime> {
+    let text = text.trim();
+    if let Ok(date_time) = OffsetDateTime::parse(text, &Rfc3339) {
+        return Some(DateTime::from_utc(date_time));
+    }
+
+    let mut parts = text.splitn(3, '-');
+    let year: i32 = parts.next()?.parse().ok()?;
+    let month: u8 = parts.next()?.parse().ok()?;
+    let day: u8 = parts.next()?.parse().ok()?;
+    let date = Date::from_calendar_date(year, Month::try_from(month).ok()?, day).ok()?;
+    Some(DateTime::from_primitive(PrimitiveDateTime::new(date, Time::MIDNIGHT)))
+}
+
+// 설정된 경로의 메타데이터 값 중 날짜로 해석되는 값을 같은 경로의 JSON 객체로 모읍니다
+pub(crate) fn metadata_dates(
+    metadata: &JsonValue,
+    paths: &[String],
+) -> BTreeMap<String, OwnedValue> {
+    let mut dates = BTreeMap::new();
+    for path in paths {
+        let date = path
+            .split('.')
+            .try_fold(metadata, |value, key| value.get(key))
+            .and_then(JsonValue::as_str)
+            .and_then(parse_date);
+        if let Some(date) = date {
+            insert_path(&mut dates, path, OwnedValue::Date(date));
+        }
+    }
+    dates
+}
+
+// "a.b.c" 경로에 값을 넣습니다 (중간 객체는 필요하면 만듭니다)
+fn insert_path(object: &mut BTreeMap<String, OwnedValue>, path: &str, value: OwnedValue) {
+    match path.split_once('.') {
+        None => {
+            object.insert(path.to_string(), value);
+        }
+        Some((key, rest)) => {
+            let mut child = match object.remove(key) {
+                Some(OwnedValue::Object(entries)) => entries.into_iter().collect(),
+                _ => BTreeMap::new(),
+            };
+            insert_path(&mut child, rest, value);
+            object.insert(key.to_string(), OwnedValue::Object(child.into_iter().collect()));
+        }
+    }
+}
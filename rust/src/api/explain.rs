@@ -0,0 +1,70 @@
+use serde::Deserialize;
+use tantivy::query::Explanation;
+
+/// 점수 계산 설명 트리 (BM25 구성 요소, 색인어 빈도, 필드 길이, 가중치 등)
+#[derive(Clone, Debug)]
+pub struct ScoreExplanation {
+    pub value: f32,
+    pub description: String,
+    pub details: Vec<ScoreExplanation>,
+    /// 추가 정보 (예: 매칭된 색인어)
+    pub context: Vec<String>,
+}
+
+impl ScoreExplanation {
+    /// 들여쓰기한 텍스트로 출력합니다 (디버그 로그용)
+    pub fn to_pretty_string(&self) -> String {
+        let mut output = String::new();
+        self.write_pretty(&mut output, 0);
+        output
+    }
+
+    fn write_pretty(&self, output: &mut String, depth: usize) {
+        output.push_str(&format!(
+            "{}{} = {}\n",
+            "  ".repeat(depth),
+            self.description,
+            self.value
+        ));
+        for context in &self.context {
+            output.push_str(&format!("{}  # {}\n", "  ".repeat(depth), context));
+        }
+        for detail in &self.details {
+            detail.write_pretty(output, depth + 1);
+        }
+    }
+}
+
+// Tantivy 설명을 변환합니다
+// (tantivy 0.25 의 Explanation 은 value() 외에 공개 접근자가 없어 나머지 필드는 직렬화한 값을 타입으로 읽습니다)
+pub(crate) fn to_score_explanation(explanation: &Explanation) -> Result<ScoreExplanation, String> {
+    let fields: ExplanationFields = serde_json::to_value(explanation)
+        .and_then(serde_json::from_value)
+        .map_err(|e| format!("점수 설명을 읽을 수 없습니다: {}", e))?;
+    Ok(ScoreExplanation {
+        value: explanation.value(),
+        ..fields.into()
+    })
+}
+
+// Explanation 이 직렬화하는 필드 (details, context 는 비어 있으면 생략됩니다)
+#[derive(Deserialize)]
+struct ExplanationFields {
+    value: f32,
+    description: String,
+    #[serde(default)]
+    details: Vec<ExplanationFields>,
+    #[serde(default)]
+    context: Vec<String>,
+}
+
+impl From<ExplanationFields> for ScoreExplanation {
+    fn from(fields: ExplanationFields) -> Self {
+        ScoreExplanation {
+            value: fields.value,
+            description: fields.description,
+            details: fields.details.into_iter().map(Into::into).collect(),
+            context: fields.context,
+        }
+    }
+}
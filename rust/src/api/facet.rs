@@ -0,0 +1,91 @@
+use serde_json::Value as JsonValue;
+use tantivy::collector::{FacetCollector, FacetCounts, FruitHandle, MultiCollector, MultiFruit};
+use tantivy::schema::Facet;
+
+// 카테고리 경로를 읽어 올 메타데이터 키 (문자열 또는 문자열 배열)
+pub(crate) const CATEGORY_METADATA_KEY: &str = "category";
+
+/// 하위 카테고리 문서 수 집계 결과
+#[derive(Clone, Debug)]
+pub struct CategoryCounts {
+    /// 요청한 상위 카테고리 경로 (예: "/travel/asia")
+    pub parent: String,
+    /// 바로 아래 하위 카테고리별 문서 수 (경로 순)
+    pub children: Vec<CategoryCount>,
+}
+
+/// 카테고리별 문서 수
+#[derive(Clone, Debug)]
+pub struct CategoryCount {
+    pub path: String,
+    pub count: u64,
+}
+
+// 카테고리 경로 문자열을 Facet 으로 변환합니다 ("/" 로 시작해야 함)
+pub(crate) fn parse_category(path: &str) -> Result<Facet, String> {
+    Facet::from_text(path).map_err(|_| {
+        format!(
+            "카테고리 경로는 '/' 로 시작해야 합니다 (예: /travel/asia): {}",
+            path
+        )
+    })
+}
+
+// 메타데이터의 category 값에서 색인할 카테고리를 찾습니다 (잘못된 경로는 건너뜀)
+pub(crate) fn metadata_categories(metadata: &JsonValue) -> Vec<Facet> {
+    let paths: Vec<&str> = match metadata.get(CATEGORY_METADATA_KEY) {
+        Some(JsonValue::String(path)) => vec![path.as_str()],
+        Some(JsonValue::Array(paths)) => paths.iter().filter_map(JsonValue::as_str).collect(),
+        _ => Vec::new(),
+    };
+
+    paths
+        .into_iter()
+        .filter_map(|path| parse_category(path).ok())
+        .filter(|facet| !facet.is_root())
+        .collect()
+}
+
+// 요청한 상위 카테고리마다 하위 문서 수를 세는 수집기
+// (FacetCollector 하나에는 서로 조상/자손 관계인 경로를 함께 넣을 수 없어 경로마다 따로 만듭니다)
+pub(crate) fn category_collector(
+    field_name: &str,
+    parents: &[String],
+) -> Result<(MultiCollector<'static>, Vec<FruitHandle<FacetCounts>>), String> {
+    let mut collector = MultiCollector::new();
+    let mut handles = Vec::with_capacity(parents.len());
+    for parent in parents {
+        let mut facet_collector = FacetCollector::for_field(field_name);
+        facet_collector.add_facet(parse_category(parent)?);
+        handles.push(collector.add_collector(facet_collector));
+    }
+    Ok((collector, handles))
+}
+
+pub(crate) fn to_category_counts(
+    parents: &[String],
+    handles: Vec<FruitHandle<FacetCounts>>,
+    mut fruits: MultiFruit,
+) -> Vec<CategoryCounts> {
+    parents
+        .iter()
+        .zip(handles)
+        .map(|(parent, handle)| {
+            let counts = handle.extract(&mut fruits);
+            let children = match parse_category(parent) {
+                Ok(facet) => counts
+                    .get(facet)
+                    .map(|(child, count)| CategoryCount {
+                        path: child.to_string(),
+                        count,
+                    })
+                    .collect(),
+                Err(_) => Vec::new(),
+            };
+            CategoryCounts {
+                parent: parent.clone(),
+                children,
+            }
+        })
+        .collect()
+}
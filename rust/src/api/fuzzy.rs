@@ -0,0 +1,124 @@
+use std::sync::OnceLock;
+
+use levenshtein_automata::{Distance, LevenshteinAutomatonBuilder, DFA, SINK_STATE};
+use tantivy::query::{AutomatonWeight, EnableScoring, Query, Weight};
+use tantivy::Term;
+use tantivy_fst::Automaton;
+
+// 거리(1 ~ 2)와 transposition 여부별 레벤슈타인 오토마톤 빌더 (만드는 비용이 커서 재사용합니다)
+static AUTOMATON_BUILDERS: [[OnceLock<LevenshteinAutomatonBuilder>; 2]; 3] = [
+    [OnceLock::new(), OnceLock::new()],
+    [OnceLock::new(), OnceLock::new()],
+    [OnceLock::new(), OnceLock::new()],
+];
+
+// 앞부분 prefix_length 글자는 정확히 같고 나머지가 레벤슈타인 거리 이내인 색인어를 찾는 쿼리
+// (두 쿼리의 교집합과 달리 용어 사전을 한 번만, 접두어가 같은 범위만 탐색합니다)
+#[derive(Clone, Debug)]
+pub(crate) struct PrefixFuzzyQuery {
+    term: Term,
+    prefix_length: usize,
+    distance: u8,
+    transposition: bool,
+}
+
+impl PrefixFuzzyQuery {
+    pub(crate) fn new(term: Term, prefix_length: usize, distance: u8, transposition: bool) -> Self {
+        PrefixFuzzyQuery {
+            term,
+            prefix_length,
+            distance,
+            transposition,
+        }
+    }
+}
+
+impl Query for PrefixFuzzyQuery {
+    fn weight(&self, _enable_scoring: EnableScoring<'_>) -> tantivy::Result<Box<dyn Weight>> {
+        let builder = AUTOMATON_BUILDERS
+            .get(self.distance as usize)
+            .ok_or_else(|| {
+                tantivy::TantivyError::InvalidArgument(format!(
+                    "퍼지 검색 거리는 1 ~ 2 사이여야 합니다: {}",
+                    self.distance
+                ))
+            })?[self.transposition as usize]
+            .get_or_init(|| LevenshteinAutomatonBuilder::new(self.distance, self.transposition));
+
+        let value = self.term.value();
+        let text = value.as_str().ok_or_else(|| {
+            tantivy::TantivyError::InvalidArgument("퍼지 검색어는 문자열이어야 합니다.".to_string())
+        })?;
+        let prefix_bytes = text
+            .char_indices()
+            .nth(self.prefix_length)
+            .map_or(text.len(), |(index, _)| index);
+        let (prefix, rest) = text.split_at(prefix_bytes);
+
+        Ok(Box::new(AutomatonWeight::new(
+            self.term.field(),
+            PrefixedDfa {
+                prefix: prefix.as_bytes().to_vec(),
+                dfa: builder.build_dfa(rest),
+            },
+        )))
+    }
+}
+
+// 접두어 바이트를 그대로 따라간 뒤 나머지를 레벤슈타인 DFA 로 검사하는 오토마톤
+struct PrefixedDfa {
+    prefix: Vec<u8>,
+    dfa: DFA,
+}
+
+#[derive(Clone)]
+enum PrefixedState {
+    // 접두어를 몇 바이트까지 읽었는지
+    Prefix(usize),
+    // 접두어 이후의 DFA 상태
+    Rest(u32),
+    Dead,
+}
+
+impl PrefixedDfa {
+    fn after_prefix(&self, consumed: usize) -> PrefixedState {
+        if consumed == self.prefix.len() {
+            PrefixedState::Rest(self.dfa.initial_state())
+        } else {
+            PrefixedState::Prefix(consumed)
+        }
+    }
+}
+
+impl Automaton for PrefixedDfa {
+    type State = PrefixedState;
+
+    fn start(&self) -> PrefixedState {
+        self.after_prefix(0)
+    }
+
+    fn is_match(&self, state: &PrefixedState) -> bool {
+        match state {
+            PrefixedState::Rest(state) => matches!(self.dfa.distance(*state), Distance::Exact(_)),
+            _ => false,
+        }
+    }
+
+    fn can_match(&self, state: &PrefixedState) -> bool {
+        match state {
+            PrefixedState::Prefix(_) => true,
+            PrefixedState::Rest(state) => *state != SINK_STATE,
+            PrefixedState::Dead => false,
+        }
+    }
+
+    fn accept(&self, state: &PrefixedState, byte: u8) -> PrefixedState {
+        match state {
+            PrefixedState::Prefix(consumed) if self.prefix[*consumed] == byte => {
+                self.after_prefix(consumed + 1)
+            }
+            PrefixedState::Rest(state) => PrefixedState::Rest(self.dfa.transition(*state, byte)),
+            _ => PrefixedState::Dead,
+        }
+    }
+}
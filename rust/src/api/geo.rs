@@ -0,0 +1,291 @@
+use std::ops::Bound;
+
+use serde_json::Value as JsonValue;
+use tantivy::columnar::Column;
+use tantivy::query::{
+    BooleanQuery, ConstScorer, EnableScoring, Explanation, Occur, Query, RangeQuery, Scorer, Weight,
+};
+use tantivy::schema::Field;
+use tantivy::{DocId, DocSet, Score, SegmentReader, TantivyError, Term, TERMINATED};
+
+// 좌표를 색인하는 fast field 이름
+pub(crate) const LAT_FIELD: &str = "lat";
+pub(crate) const LON_FIELD: &str = "lon";
+
+// 좌표를 읽어 올 기본 메타데이터 경로 ({"lat": 37.46, "lon": 126.44})
+pub(crate) const DEFAULT_LOCATION_PATH: &str = "location";
+
+const EARTH_RADIUS_KM: f64 = 6371.0088;
+
+// 후보 영역을 계산할 때 부동소수점 오차로 경계의 문서가 빠지지 않도록 넓히는 여유 (도)
+const BOUNDS_MARGIN_DEG: f64 = 1e-9;
+
+/// 위도/경도 좌표 (도 단위)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GeoPoint {
+    pub lat: f64,
+    pub lon: f64,
+}
+
+/// 좌표 필터
+#[derive(Clone, Debug)]
+pub enum GeoFilter {
+    /// 북서쪽(top_left)과 남동쪽(bottom_right) 꼭짓점으로 정한 영역
+    /// (top_left.lon > bottom_right.lon 이면 날짜 변경선을 넘는 영역)
+    BoundingBox {
+        top_left: GeoPoint,
+        bottom_right: GeoPoint,
+    },
+    /// 중심에서 distance_km 이내
+    Radius { center: GeoPoint, distance_km: f64 },
+}
+
+impl GeoPoint {
+    fn is_valid(&self) -> bool {
+        (-90.0..=90.0).contains(&self.lat) && (-180.0..=180.0).contains(&self.lon)
+    }
+
+    // 두 좌표 사이의 대원 거리 (haversine, km)
+    pub(crate) fn distance_km(&self, other: &GeoPoint) -> f64 {
+        let d_lat = (other.lat - self.lat).to_radians();
+        let d_lon = (other.lon - self.lon).to_radians();
+        let a = (d_lat / 2.0).sin().powi(2)
+            + self.lat.to_radians().cos() * other.lat.to_radians().cos() * (d_lon / 2.0).sin().powi(2);
+        2.0 * EARTH_RADIUS_KM * a.sqrt().min(1.0).asin()
+    }
+}
+
+impl GeoFilter {
+    pub(crate) fn validate(&self) -> Result<(), String> {
+        let points = match self {
+            GeoFilter::BoundingBox {
+                top_left,
+                bottom_right,
+            } => {
+                if top_left.lat < bottom_right.lat {
+                    return Err("top_left 의 위도는 bottom_right 의 위도보다 커야 합니다.".to_string());
+                }
+                vec![top_left, bottom_right]
+            }
+            GeoFilter::Radius {
+                center,
+                distance_km,
+            } => {
+                if distance_km.is_nan() || *distance_km < 0.0 {
+                    return Err(format!("반경은 0 이상이어야 합니다: {}", distance_km));
+                }
+                vec![center]
+            }
+        };
+        match points.into_iter().find(|point| !point.is_valid()) {
+            Some(point) => Err(format!("잘못된 좌표입니다: ({}, {})", point.lat, point.lon)),
+            None => Ok(()),
+        }
+    }
+
+    fn contains(&self, point: &GeoPoint) -> bool {
+        match self {
+            GeoFilter::BoundingBox {
+                top_left,
+                bottom_right,
+            } => {
+                let in_lat = (bottom_right.lat..=top_left.lat).contains(&point.lat);
+                let in_lon = if top_left.lon <= bottom_right.lon {
+                    (top_left.lon..=bottom_right.lon).contains(&point.lon)
+                } else {
+                    point.lon >= top_left.lon || point.lon <= bottom_right.lon
+                };
+                in_lat && in_lon
+            }
+            GeoFilter::Radius {
+                center,
+                distance_km,
+            } => center.distance_km(point) <= *distance_km,
+        }
+    }
+
+    // 필터를 감싸는 위도 범위와 경도 범위들 (날짜 변경선을 넘으면 경도 범위가 둘)
+    fn bounds(&self) -> ((f64, f64), Vec<(f64, f64)>) {
+        match self {
+            GeoFilter::BoundingBox {
+                top_left,
+                bottom_right,
+            } => {
+                let lons = if top_left.lon <= bottom_right.lon {
+                    vec![(top_left.lon, bottom_right.lon)]
+                } else {
+                    vec![(top_left.lon, 180.0), (-180.0, bottom_right.lon)]
+                };
+                ((bottom_right.lat, top_left.lat), lons)
+            }
+            GeoFilter::Radius {
+                center,
+                distance_km,
+            } => {
+                let angle = distance_km / EARTH_RADIUS_KM;
+                let min_lat = center.lat - angle.to_degrees();
+                let max_lat = center.lat + angle.to_degrees();
+                let lats = (min_lat.max(-90.0), max_lat.min(90.0));
+                // 극점을 포함하면 모든 경도가 후보
+                if min_lat <= -90.0 || max_lat >= 90.0 {
+                    return (lats, vec![(-180.0, 180.0)]);
+                }
+                let ratio = angle.sin() / center.lat.to_radians().cos();
+                if angle >= std::f64::consts::FRAC_PI_2 || ratio >= 1.0 {
+                    return (lats, vec![(-180.0, 180.0)]);
+                }
+                let delta = ratio.asin().to_degrees();
+                let (min_lon, max_lon) = (center.lon - delta, center.lon + delta);
+                let lons = if min_lon < -180.0 {
+                    vec![(min_lon + 360.0, 180.0), (-180.0, max_lon)]
+                } else if max_lon > 180.0 {
+                    vec![(min_lon, 180.0), (-180.0, max_lon - 360.0)]
+                } else {
+                    vec![(min_lon, max_lon)]
+                };
+                (lats, lons)
+            }
+        }
+    }
+
+    // 좌표 인덱스의 범위 쿼리로 후보를 좁히는 쿼리 (정확한 판정은 contains 로 다시 합니다)
+    fn candidate_query(&self, lat_field: Field, lon_field: Field) -> Box<dyn Query> {
+        let range = |field: Field, (min, max): (f64, f64)| -> Box<dyn Query> {
+            Box::new(RangeQuery::new(
+                Bound::Included(Term::from_field_f64(field, min - BOUNDS_MARGIN_DEG)),
+                Bound::Included(Term::from_field_f64(field, max + BOUNDS_MARGIN_DEG)),
+            ))
+        };
+        let (lats, lons) = self.bounds();
+        let lon_query: Box<dyn Query> = Box::new(BooleanQuery::new(
+            lons.into_iter()
+                .map(|lons| (Occur::Should, range(lon_field, lons)))
+                .collect(),
+        ));
+        Box::new(BooleanQuery::new(vec![
+            (Occur::Must, range(lat_field, lats)),
+            (Occur::Must, lon_query),
+        ]))
+    }
+}
+
+// 메타데이터 경로("a.b")의 값에서 좌표를 읽습니다 (범위를 벗어나면 무시)
+pub(crate) fn metadata_location(metadata: &JsonValue, path: &str) -> Option<GeoPoint> {
+    let location = path
+        .split('.')
+        .try_fold(metadata, |value, key| value.get(key))?;
+    let point = GeoPoint {
+        lat: location.get("lat")?.as_f64()?,
+        lon: location.get("lon")?.as_f64()?,
+    };
+    point.is_valid().then_some(point)
+}
+
+// 세그먼트의 위도/경도 컬럼
+pub(crate) struct GeoColumns {
+    lat: Column<f64>,
+    lon: Column<f64>,
+}
+
+impl GeoColumns {
+    pub(crate) fn open(segment_reader: &SegmentReader) -> tantivy::Result<Self> {
+        let fast_fields = segment_reader.fast_fields();
+        Ok(GeoColumns {
+            lat: fast_fields.f64(LAT_FIELD)?,
+            lon: fast_fields.f64(LON_FIELD)?,
+        })
+    }
+
+    pub(crate) fn point(&self, doc: DocId) -> Option<GeoPoint> {
+        Some(GeoPoint {
+            lat: self.lat.first(doc)?,
+            lon: self.lon.first(doc)?,
+        })
+    }
+}
+
+// 좌표 필터에 포함되는 문서를 찾는 쿼리 (점수는 boost 로 고정)
+#[derive(Clone, Debug)]
+pub(crate) struct GeoQuery {
+    filter: GeoFilter,
+}
+
+impl GeoQuery {
+    pub(crate) fn new(filter: GeoFilter) -> Result<Self, String> {
+        filter.validate()?;
+        Ok(GeoQuery { filter })
+    }
+}
+
+impl Query for GeoQuery {
+    fn weight(&self, _enable_scoring: EnableScoring<'_>) -> tantivy::Result<Box<dyn Weight>> {
+        Ok(Box::new(GeoWeight {
+            filter: self.filter.clone(),
+        }))
+    }
+}
+
+struct GeoWeight {
+    filter: GeoFilter,
+}
+
+impl Weight for GeoWeight {
+    fn scorer(&self, reader: &SegmentReader, boost: Score) -> tantivy::Result<Box<dyn Scorer>> {
+        let schema = reader.schema();
+        let candidates = self
+            .filter
+            .candidate_query(schema.get_field(LAT_FIELD)?, schema.get_field(LON_FIELD)?)
+            .weight(EnableScoring::disabled_from_schema(schema))?;
+        let mut candidates = candidates.scorer(reader, 1.0)?;
+
+        // 영역 안의 후보만 정확한 거리로 다시 확인합니다
+        let columns = GeoColumns::open(reader)?;
+        let mut docs = Vec::new();
+        let mut doc = candidates.doc();
+        while doc != TERMINATED {
+            if columns
+                .point(doc)
+                .is_some_and(|point| self.filter.contains(&point))
+            {
+                docs.push(doc);
+            }
+            doc = candidates.advance();
+        }
+        Ok(Box::new(ConstScorer::new(
+            GeoDocSet { docs, cursor: 0 },
+            boost,
+        )))
+    }
+
+    // 점수는 scorer 로 계산합니다 (다른 Weight 처럼 boost 1 로 계산하고, 상위 BoostQuery 가 곱합니다)
+    fn explain(&self, reader: &SegmentReader, doc: DocId) -> tantivy::Result<Explanation> {
+        let mut scorer = self.scorer(reader, 1.0)?;
+        if scorer.seek(doc) != doc {
+            return Err(TantivyError::InvalidArgument(format!(
+                "Document #({doc}) does not match"
+            )));
+        }
+        Ok(Explanation::new("GeoQuery", scorer.score()))
+    }
+}
+
+// 필터를 통과한 문서 ID 목록 (오름차순)
+struct GeoDocSet {
+    docs: Vec<DocId>,
+    cursor: usize,
+}
+
+impl DocSet for GeoDocSet {
+    fn advance(&mut self) -> DocId {
+        self.cursor += 1;
+        self.doc()
+    }
+
+    fn doc(&self) -> DocId {
+        self.docs.get(self.cursor).copied().unwrap_or(TERMINATED)
+    }
+
+    fn size_hint(&self) -> u32 {
+        self.docs.len() as u32
+    }
+}
@@ -0,0 +1,295 @@
+use std::collections::HashMap;
+
+use tantivy::collector::{Collector, ScoreSegmentTweaker, ScoreTweaker, SegmentCollector};
+use tantivy::columnar::{DynamicColumn, StrColumn};
+use tantivy::{DocAddress, DocId, Index, Score, SegmentOrdinal, SegmentReader, TantivyError};
+
+use crate::api::scoring::ScoreModifier;
+use crate::api::sort::{
+    ensure_fast_field, first_value, FastFieldSorter, SegmentSorter, SortKey, SortValue, SortValues,
+};
+
+/// 그룹별 검색 옵션
+#[derive(Clone, Debug)]
+pub struct GroupByOptions {
+    /// 그룹으로 묶을 fast field 이름 또는 메타데이터 JSON 경로 (예: `metadata.city`)
+    pub field: String,
+    /// 반환할 그룹 수 (그룹의 최상위 문서 순)
+    pub groups_limit: usize,
+    /// 그룹마다 반환할 문서 수
+    pub hits_per_group: usize,
+}
+
+// 수집된 그룹 (key 가 None 이면 필드 값이 없는 문서 하나)
+pub(crate) struct CollectedGroup {
+    pub(crate) key: Option<String>,
+    pub(crate) count: u64,
+    pub(crate) hits: Vec<(SortValues, DocAddress)>,
+}
+
+// 그룹을 나누는 키 (필드 값이 없는 문서는 서로 묶지 않고 문서마다 따로 둡니다)
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub(crate) enum GroupKey {
+    Value(String),
+    Missing(DocAddress),
+}
+
+type CollectedGroups = HashMap<GroupKey, CollectedGroup>;
+
+// 필드 값별로 문서 수와 상위 문서를 모으는 수집기
+// 문서 순서는 정렬 키(없으면 보정한 BM25 점수)를 따릅니다
+pub(crate) struct GroupCollector {
+    field: String,
+    sorter: FastFieldSorter,
+    groups_limit: usize,
+    hits_per_group: usize,
+}
+
+impl GroupCollector {
+    pub(crate) fn new(
+        index: &Index,
+        field: &str,
+        sort: &[SortKey],
+        modifiers: &[ScoreModifier],
+        groups_limit: usize,
+        hits_per_group: usize,
+    ) -> Result<Self, String> {
+        ensure_fast_field(index, field)?;
+        if groups_limit == 0 || hits_per_group == 0 {
+            return Err("그룹 수와 그룹별 문서 수는 1 이상이어야 합니다.".to_string());
+        }
+
+        Ok(GroupCollector {
+            field: field.to_string(),
+            sorter: FastFieldSorter::new(index, sort, modifiers)?,
+            groups_limit,
+            hits_per_group,
+        })
+    }
+}
+
+impl Collector for GroupCollector {
+    type Fruit = Vec<CollectedGroup>;
+    type Child = GroupSegmentCollector;
+
+    fn for_segment(
+        &self,
+        segment_ord: SegmentOrdinal,
+        segment_reader: &SegmentReader,
+    ) -> tantivy::Result<GroupSegmentCollector> {
+        let columns = segment_reader
+            .fast_fields()
+            .dynamic_column_handles(&self.field)?
+            .iter()
+            .map(|handle| handle.open())
+            .collect::<std::io::Result<Vec<DynamicColumn>>>()?;
+
+        Ok(GroupSegmentCollector {
+            segment_ord,
+            columns,
+            sorter: self.sorter.segment_tweaker(segment_reader)?,
+            hits_per_group: self.hits_per_group,
+            groups: HashMap::new(),
+        })
+    }
+
+    fn requires_scoring(&self) -> bool {
+        true
+    }
+
+    fn merge_fruits(&self, segment_groups: Vec<CollectedGroups>) -> tantivy::Result<Self::Fruit> {
+        let mut merged: CollectedGroups = HashMap::new();
+        for groups in segment_groups {
+            for (key, group) in groups {
+                match merged.get_mut(&key) {
+                    Some(existing) => {
+                        existing.count += group.count;
+                        for hit in group.hits {
+                            push_top(&mut existing.hits, hit, self.hits_per_group);
+                        }
+                    }
+                    None => {
+                        merged.insert(key, group);
+                    }
+                }
+            }
+        }
+
+        // 최상위 문서가 앞서는 그룹부터 (같으면 키 순, 값이 없는 문서끼리는 문서 순)
+        let mut groups: Vec<CollectedGroup> = merged.into_values().collect();
+        groups.sort_by(|a, b| {
+            b.hits[0]
+                .0
+                .partial_cmp(&a.hits[0].0)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then_with(|| a.key.cmp(&b.key))
+                .then_with(|| a.hits[0].1.cmp(&b.hits[0].1))
+        });
+        groups.truncate(self.groups_limit);
+        Ok(groups)
+    }
+}
+
+pub(crate) struct GroupSegmentCollector {
+    segment_ord: SegmentOrdinal,
+    columns: Vec<DynamicColumn>,
+    sorter: SegmentSorter,
+    hits_per_group: usize,
+    groups: CollectedGroups,
+}
+
+impl SegmentCollector for GroupSegmentCollector {
+    type Fruit = CollectedGroups;
+
+    fn collect(&mut self, doc: DocId, score: Score) {
+        let doc_address = DocAddress::new(self.segment_ord, doc);
+        let key = match first_value(&self.columns, doc) {
+            SortValue::Integer(value) => GroupKey::Value(value.to_string()),
+            SortValue::Float(value) => GroupKey::Value(value.to_string()),
+            SortValue::Text(text) => GroupKey::Value(text),
+            SortValue::Missing => GroupKey::Missing(doc_address),
+        };
+        let sort_values = self.sorter.score(doc, score);
+
+        let group = self
+            .groups
+            .entry(key.clone())
+            .or_insert_with(|| CollectedGroup {
+                key: match key {
+                    GroupKey::Value(value) => Some(value),
+                    GroupKey::Missing(_) => None,
+                },
+                count: 0,
+                hits: Vec::new(),
+            });
+        group.count += 1;
+        push_top(&mut group.hits, (sort_values, doc_address), self.hits_per_group);
+    }
+
+    fn harvest(self) -> CollectedGroups {
+        self.groups
+    }
+}
+
+// 정렬된(앞설수록 큰 값) 목록에 문서를 넣고 상위 limit 개만 남깁니다
+fn push_top(hits: &mut Vec<(SortValues, DocAddress)>, hit: (SortValues, DocAddress), limit: usize) {
+    if hits.len() >= limit && hits.last().is_some_and(|last| last.0 >= hit.0) {
+        return;
+    }
+    let position = hits.partition_point(|existing| existing.0 >= hit.0);
+    hits.insert(position, hit);
+    hits.truncate(limit);
+}
+
+// 문자열 fast field 값별 문서 수와 최고 점수 (값은 같은 필드의 다른 문서와 묶입니다)
+pub(crate) struct DistinctValue {
+    pub(crate) value: String,
+    pub(crate) count: u64,
+    score: Score,
+}
+
+// 문자열 fast field 값별로 문서 수와 최고 점수만 모으는 가벼운 수집기
+// 세그먼트 안에서는 값의 순번(ord)으로 모으고, 문자열은 세그먼트마다 한 번씩만 읽습니다
+pub(crate) struct DistinctValueCollector {
+    field: String,
+}
+
+impl DistinctValueCollector {
+    pub(crate) fn new(field: &str) -> Self {
+        DistinctValueCollector {
+            field: field.to_string(),
+        }
+    }
+}
+
+impl Collector for DistinctValueCollector {
+    type Fruit = Vec<DistinctValue>;
+    type Child = DistinctValueSegmentCollector;
+
+    fn for_segment(
+        &self,
+        _segment_ord: SegmentOrdinal,
+        segment_reader: &SegmentReader,
+    ) -> tantivy::Result<DistinctValueSegmentCollector> {
+        let column = segment_reader.fast_fields().str(&self.field)?.ok_or_else(|| {
+            TantivyError::SchemaError(format!("문자열 fast field 가 아닙니다: {}", self.field))
+        })?;
+        Ok(DistinctValueSegmentCollector {
+            column,
+            values: HashMap::new(),
+        })
+    }
+
+    fn requires_scoring(&self) -> bool {
+        true
+    }
+
+    fn merge_fruits(&self, segment_values: Vec<Vec<DistinctValue>>) -> tantivy::Result<Self::Fruit> {
+        let mut merged: HashMap<String, DistinctValue> = HashMap::new();
+        for value in segment_values.into_iter().flatten() {
+            match merged.get_mut(&value.value) {
+                Some(existing) => {
+                    existing.count += value.count;
+                    existing.score = existing.score.max(value.score);
+                }
+                None => {
+                    merged.insert(value.value.clone(), value);
+                }
+            }
+        }
+
+        // 최고 점수가 높은 값부터 (같으면 값 순)
+        let mut values: Vec<DistinctValue> = merged.into_values().collect();
+        values.sort_by(|a, b| {
+            b.score
+                .partial_cmp(&a.score)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then_with(|| a.value.cmp(&b.value))
+        });
+        Ok(values)
+    }
+}
+
+pub(crate) struct DistinctValueSegmentCollector {
+    column: StrColumn,
+    // 값의 순번 -> (문서 수, 최고 점수)
+    values: HashMap<u64, (u64, Score)>,
+}
+
+impl SegmentCollector for DistinctValueSegmentCollector {
+    type Fruit = Vec<DistinctValue>;
+
+    fn collect(&mut self, doc: DocId, score: Score) {
+        if let Some(ord) = self.column.term_ords(doc).next() {
+            let (count, best) = self.values.entry(ord).or_insert((0, score));
+            *count += 1;
+            *best = best.max(score);
+        }
+    }
+
+    fn harvest(self) -> Vec<DistinctValue> {
+        // 순번 순으로 사전을 한 번만 훑으며 문자열로 바꿉니다
+        let mut values: Vec<(u64, (u64, Score))> = self.values.into_iter().collect();
+        values.sort_unstable_by_key(|(ord, _)| *ord);
+        let mut texts = Vec::with_capacity(values.len());
+        let decoded = self.column.dictionary().sorted_ords_to_term_cb(
+            values.iter().map(|(ord, _)| *ord),
+            |bytes| {
+                texts.push(String::from_utf8_lossy(bytes).into_owned());
+                Ok(())
+            },
+        );
+        if decoded.is_err() {
+            return Vec::new();
+        }
+        texts
+            .into_iter()
+            .zip(values)
+            .map(|(value, (_, (count, score)))| DistinctValue {
+                value,
+                count,
+                score,
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,85 @@
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use crate::api::korean::initial_sound_rule;
+
+// 한자 -> 한글 독음 표 (직접 고른 자주 쓰는 한자 631자와 지명 3개, 표에 없는 한자는 그대로 둡니다)
+const HANJA_READINGS: &str = include_str!("hanja_readings.tsv");
+
+struct HanjaTable {
+    readings: HashMap<String, String>,
+    // 가장 긴 항목의 글자 수
+    max_chars: usize,
+}
+
+fn hanja_table() -> &'static HanjaTable {
+    static TABLE: OnceLock<HanjaTable> = OnceLock::new();
+    TABLE.get_or_init(|| {
+        let readings: HashMap<String, String> = HANJA_READINGS
+            .lines()
+            .filter(|line| !line.starts_with('#'))
+            .filter_map(|line| line.split_once('\t'))
+            .map(|(hanja, reading)| (hanja.to_string(), reading.to_string()))
+            .collect();
+        let max_chars = readings
+            .keys()
+            .map(|hanja| hanja.chars().count())
+            .max()
+            .unwrap_or(1);
+        HanjaTable {
+            readings,
+            max_chars,
+        }
+    })
+}
+
+// 한자를 한글 독음으로 바꿉니다 (예: "仁川國際空港" -> "인천국제공항", "旅行" -> "여행")
+// 여러 글자 항목을 먼저 찾고, 한자가 이어진 구간의 첫 글자에는 두음법칙을 적용합니다
+// 표에 없는 글자는 그대로 둡니다 (한자와 한글 음절은 모두 UTF-8 3바이트라 위치가 유지됩니다)
+pub(crate) fn hanja_to_hangul(text: &str) -> String {
+    let table = hanja_table();
+    let chars: Vec<char> = text.chars().collect();
+    let mut output = String::with_capacity(text.len());
+    let mut position = 0;
+    let mut in_hanja_run = false;
+
+    while position < chars.len() {
+        let longest = (1..=table.max_chars.min(chars.len() - position))
+            .rev()
+            .find_map(|length| {
+                let key: String = chars[position..position + length].iter().collect();
+                table.readings.get(&key).map(|reading| (length, reading))
+            });
+        match longest {
+            Some((length, reading)) => {
+                for (index, c) in reading.chars().enumerate() {
+                    if index == 0 && !in_hanja_run {
+                        output.push(initial_sound_rule(c));
+                    } else {
+                        output.push(c);
+                    }
+                }
+                position += length;
+                in_hanja_run = true;
+            }
+            None => {
+                output.push(chars[position]);
+                position += 1;
+                in_hanja_run = false;
+            }
+        }
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hanja_to_hangul() {
+        // 두음 법칙은 단어 첫 글자에만 적용합니다
+        assert_eq!(hanja_to_hangul("旅行 李 老人"), "여행 이 노인");
+        assert_eq!(hanja_to_hangul("國立 ABC"), "국립 ABC");
+    }
+}
@@ -0,0 +1,274 @@
+use tantivy::query::{BooleanQuery, EmptyQuery, Occur, Query, TermQuery};
+use tantivy::schema::{Field, IndexRecordOption};
+use tantivy::tokenizer::{Token, TokenFilter, TokenStream, Tokenizer};
+use tantivy::Term;
+
+use crate::api::ngram::positional_ngram_query;
+
+// 한글 음절 범위 (가 ~ 힣)
+const SYLLABLE_START: u32 = 0xAC00;
+const SYLLABLE_END: u32 = 0xD7A3;
+// 초성 하나에 딸린 음절 수 (중성 21 x 종성 28)
+const SYLLABLES_PER_CHOSUNG: u32 = 21 * 28;
+
+// 초성 순서대로 나열한 호환용 자모
+const CHOSUNG: [char; 19] = [
+    'ㄱ', 'ㄲ', 'ㄴ', 'ㄷ', 'ㄸ', 'ㄹ', 'ㅁ', 'ㅂ', 'ㅃ', 'ㅅ', 'ㅆ', 'ㅇ', 'ㅈ', 'ㅉ', 'ㅊ', 'ㅋ', 'ㅌ',
+    'ㅍ', 'ㅎ',
+];
+
+// 중성 순서대로 나열한 호환용 자모
+const JUNGSUNG: [char; 21] = [
+    'ㅏ', 'ㅐ', 'ㅑ', 'ㅒ', 'ㅓ', 'ㅔ', 'ㅕ', 'ㅖ', 'ㅗ', 'ㅘ', 'ㅙ', 'ㅚ', 'ㅛ', 'ㅜ', 'ㅝ', 'ㅞ', 'ㅟ',
+    'ㅠ', 'ㅡ', 'ㅢ', 'ㅣ',
+];
+
+// 종성 순서대로 나열한 호환용 자모 (0 은 받침 없음)
+const JONGSUNG: [Option<char>; 28] = [
+    None,
+    Some('ㄱ'),
+    Some('ㄲ'),
+    Some('ㄳ'),
+    Some('ㄴ'),
+    Some('ㄵ'),
+    Some('ㄶ'),
+    Some('ㄷ'),
+    Some('ㄹ'),
+    Some('ㄺ'),
+    Some('ㄻ'),
+    Some('ㄼ'),
+    Some('ㄽ'),
+    Some('ㄾ'),
+    Some('ㄿ'),
+    Some('ㅀ'),
+    Some('ㅁ'),
+    Some('ㅂ'),
+    Some('ㅄ'),
+    Some('ㅅ'),
+    Some('ㅆ'),
+    Some('ㅇ'),
+    Some('ㅈ'),
+    Some('ㅊ'),
+    Some('ㅋ'),
+    Some('ㅌ'),
+    Some('ㅍ'),
+    Some('ㅎ'),
+];
+
+// 자판에서 두 번 눌러 입력하는 겹모음/겹받침 (예: "고" 다음 "과", "달" 다음 "닭")
+const COMPOUND_JAMO: [(char, [char; 2]); 18] = [
+    ('ㅘ', ['ㅗ', 'ㅏ']),
+    ('ㅙ', ['ㅗ', 'ㅐ']),
+    ('ㅚ', ['ㅗ', 'ㅣ']),
+    ('ㅝ', ['ㅜ', 'ㅓ']),
+    ('ㅞ', ['ㅜ', 'ㅔ']),
+    ('ㅟ', ['ㅜ', 'ㅣ']),
+    ('ㅢ', ['ㅡ', 'ㅣ']),
+    ('ㄳ', ['ㄱ', 'ㅅ']),
+    ('ㄵ', ['ㄴ', 'ㅈ']),
+    ('ㄶ', ['ㄴ', 'ㅎ']),
+    ('ㄺ', ['ㄹ', 'ㄱ']),
+    ('ㄻ', ['ㄹ', 'ㅁ']),
+    ('ㄼ', ['ㄹ', 'ㅂ']),
+    ('ㄽ', ['ㄹ', 'ㅅ']),
+    ('ㄾ', ['ㄹ', 'ㅌ']),
+    ('ㄿ', ['ㄹ', 'ㅍ']),
+    ('ㅀ', ['ㄹ', 'ㅎ']),
+    ('ㅄ', ['ㅂ', 'ㅅ']),
+];
+
+// 자모 접두어 필드에 색인하는 단어별 최대 자모 수
+pub(crate) const JAMO_PREFIX_MAX: usize = 30;
+
+// 초성 N-gram 토크나이저의 최대 길이 (더 긴 검색어는 이 길이의 조각을 이은 구 쿼리로 찾습니다)
+pub(crate) const CHOSUNG_NGRAM_MAX: usize = 3;
+
+fn is_compatibility_jamo(c: char) -> bool {
+    ('\u{3131}'..='\u{318E}').contains(&c)
+}
+
+// 호환용 자모 중 자음 (ㄱ ~ ㅎ, 겹자음 포함)
+fn is_compatibility_consonant(c: char) -> bool {
+    ('\u{3131}'..='\u{314E}').contains(&c)
+}
+
+// 한글 음절의 초성 (음절이 아니면 None)
+fn syllable_chosung(c: char) -> Option<char> {
+    let code = c as u32;
+    if !(SYLLABLE_START..=SYLLABLE_END).contains(&code) {
+        return None;
+    }
+    Some(CHOSUNG[((code - SYLLABLE_START) / SYLLABLES_PER_CHOSUNG) as usize])
+}
+
+// 텍스트를 초성 문자열로 바꿉니다 (예: "인천 국제공항" -> "ㅇㅊㄱㅈㄱㅎ")
+// 한글 음절과 자모만 남기고 공백, 기호, 다른 문자는 버립니다
+pub(crate) fn chosung(text: &str) -> String {
+    text.chars()
+        .filter_map(|c| syllable_chosung(c).or_else(|| is_compatibility_jamo(c).then_some(c)))
+        .collect()
+}
+
+// 텍스트를 자판 입력 순서의 자모로 풀어 씁니다 (예: "닭" -> "ㄷㅏㄹㄱ", "과" -> "ㄱㅗㅏ")
+// 입력 중인 "한구" 가 "한국" 의 접두어가 되도록 음절과 겹자모를 모두 나눕니다
+pub(crate) fn decompose_jamo(text: &str) -> String {
+    let mut jamo = String::with_capacity(text.len() * 2);
+    let mut push = |c: char| match COMPOUND_JAMO.iter().find(|(compound, _)| *compound == c) {
+        Some((_, parts)) => jamo.extend(parts),
+        None => jamo.push(c),
+    };
+    for c in text.chars() {
+        let code = c as u32;
+        if (SYLLABLE_START..=SYLLABLE_END).contains(&code) {
+            let index = code - SYLLABLE_START;
+            push(CHOSUNG[(index / SYLLABLES_PER_CHOSUNG) as usize]);
+            push(JUNGSUNG[(index % SYLLABLES_PER_CHOSUNG / 28) as usize]);
+            if let Some(jong) = JONGSUNG[(index % 28) as usize] {
+                push(jong);
+            }
+        } else {
+            push(c);
+        }
+    }
+    jamo
+}
+
+// 단어별 자모 접두어 필드에 넣을 값 (단어마다 하나, 최대 JAMO_PREFIX_MAX 자모)
+pub(crate) fn jamo_words(text: &str) -> Vec<String> {
+    text.split_whitespace()
+        .map(|word| {
+            decompose_jamo(&word.to_lowercase())
+                .chars()
+                .take(JAMO_PREFIX_MAX)
+                .collect()
+        })
+        .collect()
+}
+
+// 자모 접두어 필드 쿼리: 검색어의 모든 단어가 어떤 단어의 접두어여야 합니다
+pub(crate) fn jamo_prefix_query(field: Field, text: &str) -> Box<dyn Query> {
+    let mut words = jamo_words(text);
+    words.sort();
+    words.dedup();
+    let mut clauses: Vec<(Occur, Box<dyn Query>)> = words
+        .into_iter()
+        .map(|word| {
+            let term = Term::from_field_text(field, &word);
+            let query: Box<dyn Query> =
+                Box::new(TermQuery::new(term, IndexRecordOption::WithFreqs));
+            (Occur::Must, query)
+        })
+        .collect();
+    match clauses.len() {
+        0 => Box::new(EmptyQuery),
+        1 => clauses.pop().unwrap().1,
+        _ => Box::new(BooleanQuery::new(clauses)),
+    }
+}
+
+// 단어 첫머리의 두음법칙 (예: 리 -> 이, 녀 -> 여, 로 -> 노)
+pub(crate) fn initial_sound_rule(c: char) -> char {
+    const NIEUN: u32 = 2;
+    const RIEUL: u32 = 5;
+    const IEUNG: u32 = 11;
+    // ㅑ ㅒ ㅕ ㅖ ㅛ ㅠ ㅣ
+    const Y_VOWELS: [u32; 7] = [2, 3, 6, 7, 12, 17, 20];
+
+    let code = c as u32;
+    if !(SYLLABLE_START..=SYLLABLE_END).contains(&code) {
+        return c;
+    }
+    let index = code - SYLLABLE_START;
+    let cho = index / SYLLABLES_PER_CHOSUNG;
+    let jung = index % SYLLABLES_PER_CHOSUNG / 28;
+    let new_cho = match cho {
+        RIEUL | NIEUN if Y_VOWELS.contains(&jung) => IEUNG,
+        RIEUL => NIEUN,
+        _ => return c,
+    };
+    let new_code = SYLLABLE_START + new_cho * SYLLABLES_PER_CHOSUNG + index % SYLLABLES_PER_CHOSUNG;
+    char::from_u32(new_code).unwrap_or(c)
+}
+
+// 공백을 제외한 모든 글자가 자음인 초성 검색어인지 확인합니다 (예: "ㅇㅊ ㄱㅎ", 모음이 있으면 아님)
+pub(crate) fn is_chosung_query(text: &str) -> bool {
+    let mut chars = text.chars().filter(|c| !c.is_whitespace()).peekable();
+    chars.peek().is_some() && chars.all(is_compatibility_consonant)
+}
+
+// 초성 필드 쿼리 (공백을 뺀 초성 문자열이 이어서 나오는 문서)
+pub(crate) fn chosung_query(field: Field, text: &str) -> Box<dyn Query> {
+    let text: String = text.chars().filter(|c| !c.is_whitespace()).collect();
+    positional_ngram_query(field, &text, CHOSUNG_NGRAM_MAX)
+}
+
+// 색인어를 자모로 풀어 쓰는 토큰 필터 (자모 단위 오타 허용 검색용)
+#[derive(Clone)]
+pub(crate) struct JamoDecomposer;
+
+impl TokenFilter for JamoDecomposer {
+    type Tokenizer<T: Tokenizer> = JamoDecomposerFilter<T>;
+
+    fn transform<T: Tokenizer>(self, tokenizer: T) -> Self::Tokenizer<T> {
+        JamoDecomposerFilter { tokenizer }
+    }
+}
+
+#[derive(Clone)]
+pub(crate) struct JamoDecomposerFilter<T> {
+    tokenizer: T,
+}
+
+impl<T: Tokenizer> Tokenizer for JamoDecomposerFilter<T> {
+    type TokenStream<'a> = JamoDecomposerTokenStream<T::TokenStream<'a>>;
+
+    fn token_stream<'a>(&'a mut self, text: &'a str) -> Self::TokenStream<'a> {
+        JamoDecomposerTokenStream {
+            tail: self.tokenizer.token_stream(text),
+        }
+    }
+}
+
+pub(crate) struct JamoDecomposerTokenStream<T> {
+    tail: T,
+}
+
+impl<T: TokenStream> TokenStream for JamoDecomposerTokenStream<T> {
+    fn advance(&mut self) -> bool {
+        if !self.tail.advance() {
+            return false;
+        }
+        let token = self.tail.token_mut();
+        token.text = decompose_jamo(&token.text.to_lowercase());
+        true
+    }
+
+    fn token(&self) -> &Token {
+        self.tail.token()
+    }
+
+    fn token_mut(&mut self) -> &mut Token {
+        self.tail.token_mut()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chosung() {
+        assert_eq!(chosung("인천(ICN) 공항"), "ㅇㅊㄱㅎ");
+        assert!(is_chosung_query(" ㄱㅎ "));
+        assert!(is_chosung_query("ㄳㅎ"));
+        assert!(!is_chosung_query("ㄱ공항"));
+        assert!(!is_chosung_query("ㅏㅏ"));
+        assert!(!is_chosung_query("  "));
+    }
+
+    #[test]
+    fn test_decompose_jamo() {
+        // 겹받침과 겹모음도 입력 순서대로 나눕니다
+        assert_eq!(decompose_jamo("닭과"), "ㄷㅏㄹㄱㄱㅗㅏ");
+    }
+}
@@ -1,3 +1,23 @@
 pub mod simple;
 pub mod search;
 pub mod structs;
+pub mod query;
+pub mod sort;
+pub mod aggregation;
+pub mod facet;
+pub mod date;
+pub mod geo;
+pub mod group;
+pub mod more_like_this;
+pub mod explain;
+pub mod scoring;
+pub mod projection;
+pub mod korean;
+pub mod ngram;
+pub mod prefix;
+pub mod char_filter;
+pub mod hanja;
+pub mod variant;
+pub mod reading;
+pub mod fuzzy;
+pub mod settings;
@@ -0,0 +1,53 @@
+use tantivy::query::MoreLikeThisQuery;
+use tantivy::schema::{Field, OwnedValue};
+
+/// 비슷한 문서를 찾을 기준
+#[derive(Clone, Debug)]
+pub enum MoreLikeThisSource {
+    /// 색인된 문서 ID (결과에서 이 문서는 제외됩니다)
+    DocumentId(String),
+    /// 임의의 텍스트 (제목/본문 형태소 분석기로 분석)
+    Text(String),
+}
+
+/// 비슷한 문서 검색 옵션 (TF-IDF 로 기준의 중요 색인어를 골라 검색합니다)
+#[derive(Clone, Debug)]
+pub struct MoreLikeThisOptions {
+    /// 이 수보다 적은 문서에 나오는 색인어는 무시합니다
+    pub min_doc_freq: u64,
+    /// 기준에서 이 횟수보다 적게 나오는 색인어는 무시합니다
+    pub min_term_freq: usize,
+    /// 쿼리에 사용할 최대 색인어 수
+    pub max_query_terms: usize,
+}
+
+impl Default for MoreLikeThisOptions {
+    fn default() -> Self {
+        MoreLikeThisOptions {
+            min_doc_freq: 2,
+            min_term_freq: 1,
+            max_query_terms: 25,
+        }
+    }
+}
+
+// 필드별 텍스트로 MoreLikeThis 쿼리를 만듭니다
+pub(crate) fn more_like_this_query(
+    options: &MoreLikeThisOptions,
+    fields: Vec<(Field, String)>,
+) -> Result<MoreLikeThisQuery, String> {
+    if options.max_query_terms == 0 {
+        return Err("max_query_terms 는 1 이상이어야 합니다.".to_string());
+    }
+
+    let doc_fields = fields
+        .into_iter()
+        .map(|(field, text)| (field, vec![OwnedValue::Str(text)]))
+        .collect();
+
+    Ok(MoreLikeThisQuery::builder()
+        .with_min_doc_frequency(options.min_doc_freq)
+        .with_min_term_frequency(options.min_term_freq)
+        .with_max_query_terms(options.max_query_terms)
+        .with_document_fields(doc_fields))
+}
@@ -0,0 +1,135 @@
+use tantivy::query::{EmptyQuery, PhraseQuery, Query, TermQuery};
+use tantivy::schema::{Field, IndexRecordOption};
+use tantivy::tokenizer::{Token, TokenStream, Tokenizer};
+use tantivy::Term;
+
+// 글자 위치를 기록하는 N-gram 토크나이저 (i 번째 글자에서 시작하는 1 ~ max 글자 조각을 위치 i 에)
+// 공백이 들어가는 조각은 만들지 않으므로 구(phrase) 검색이 공백을 건너 일치하지 않습니다
+#[derive(Clone)]
+pub(crate) struct PositionalNgramTokenizer {
+    max: usize,
+}
+
+impl PositionalNgramTokenizer {
+    pub(crate) fn new(max: usize) -> Self {
+        PositionalNgramTokenizer { max }
+    }
+}
+
+impl Tokenizer for PositionalNgramTokenizer {
+    type TokenStream<'a> = PositionalNgramTokenStream;
+
+    fn token_stream<'a>(&'a mut self, text: &'a str) -> Self::TokenStream<'a> {
+        PositionalNgramTokenStream::new(text, self.max)
+    }
+}
+
+pub(crate) struct PositionalNgramTokenStream {
+    tokens: Vec<Token>,
+    cursor: usize,
+}
+
+impl PositionalNgramTokenStream {
+    pub(crate) fn new(text: &str, max: usize) -> Self {
+        let chars: Vec<(usize, char)> = text.char_indices().collect();
+        let mut tokens = Vec::new();
+        for start in 0..chars.len() {
+            for len in 1..=max.min(chars.len() - start) {
+                let gram = &chars[start..start + len];
+                if gram.iter().any(|(_, c)| c.is_whitespace()) {
+                    break;
+                }
+                let (last_offset, last_char) = gram[len - 1];
+                tokens.push(Token {
+                    offset_from: chars[start].0,
+                    offset_to: last_offset + last_char.len_utf8(),
+                    position: start,
+                    text: gram.iter().map(|(_, c)| c).collect(),
+                    position_length: 1,
+                });
+            }
+        }
+        PositionalNgramTokenStream { tokens, cursor: 0 }
+    }
+
+    // 원문을 바꾼 텍스트(읽기, 병음)의 조각은 원문 글자와 대응하지 않으므로 offset 을 원문 전체로 둡니다
+    pub(crate) fn spanning(mut self, source_len: usize) -> Self {
+        for token in &mut self.tokens {
+            token.offset_from = 0;
+            token.offset_to = source_len;
+        }
+        self
+    }
+}
+
+impl TokenStream for PositionalNgramTokenStream {
+    fn advance(&mut self) -> bool {
+        self.cursor += 1;
+        self.cursor <= self.tokens.len()
+    }
+
+    fn token(&self) -> &Token {
+        &self.tokens[self.cursor - 1]
+    }
+
+    fn token_mut(&mut self) -> &mut Token {
+        &mut self.tokens[self.cursor - 1]
+    }
+}
+
+// 위치 N-gram 필드 쿼리: 짧은 검색어는 조각 하나, 긴 검색어는 max 글자 조각을 이어 붙인 구 쿼리
+// (마지막 조각은 끝에 맞춰 앞 조각과 겹칠 수 있으며, 검색어가 연속해서 나오는 문서만 일치합니다)
+pub(crate) fn positional_ngram_query(field: Field, text: &str, max: usize) -> Box<dyn Query> {
+    let chars: Vec<char> = text.chars().collect();
+    let gram_term = |start: usize, len: usize| {
+        Term::from_field_text(field, &chars[start..start + len].iter().collect::<String>())
+    };
+
+    if chars.is_empty() {
+        Box::new(EmptyQuery)
+    } else if chars.len() <= max {
+        Box::new(TermQuery::new(
+            gram_term(0, chars.len()),
+            IndexRecordOption::WithFreqs,
+        ))
+    } else {
+        let last = chars.len() - max;
+        let mut starts: Vec<usize> = (0..last).step_by(max).collect();
+        starts.push(last);
+        Box::new(PhraseQuery::new_with_offset(
+            starts
+                .into_iter()
+                .map(|start| (start, gram_term(start, max)))
+                .collect(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tantivy::schema::{Schema, TEXT};
+
+    fn texts(terms: Vec<Term>) -> Vec<String> {
+        terms
+            .iter()
+            .map(|term| term.value().as_str().unwrap().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_positional_ngram_query() {
+        let mut schema_builder = Schema::builder();
+        let field = schema_builder.add_text_field("ngram", TEXT);
+
+        assert!(positional_ngram_query(field, "", 3).is::<EmptyQuery>());
+        let query = positional_ngram_query(field, "공항", 3);
+        let query = query.downcast_ref::<TermQuery>().unwrap();
+        assert_eq!(texts(vec![query.term().clone()]), vec!["공항"]);
+
+        // max 글자 조각을 이어 붙이고, 마지막 조각은 끝에 맞춥니다
+        let query = positional_ngram_query(field, "인천국제공항", 4);
+        let query = query.downcast_ref::<PhraseQuery>().unwrap();
+        assert_eq!(texts(query.phrase_terms()), vec!["인천국제", "국제공항"]);
+    }
+}
@@ -0,0 +1,99 @@
+use tantivy_fst::Automaton;
+
+// 대소문자를 구분하지 않고 접두어로 시작하는 색인어를 찾는 오토마톤
+// (title, body 필드는 소문자로 바꾸지 않고 색인하므로 term dictionary 를 그대로 훑습니다)
+pub(crate) struct CaseInsensitivePrefix {
+    prefix: Vec<char>,
+}
+
+impl CaseInsensitivePrefix {
+    pub(crate) fn new(prefix: &str) -> Self {
+        CaseInsensitivePrefix {
+            prefix: prefix.to_lowercase().chars().collect(),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub(crate) enum PrefixState {
+    // 접두어를 몇 글자까지 맞췄는지와 아직 끝나지 않은 UTF-8 바이트
+    Prefix { matched: usize, pending: Vec<u8> },
+    // 접두어를 모두 맞췄으므로 나머지는 무엇이든 일치합니다
+    Matched,
+    Dead,
+}
+
+impl Automaton for CaseInsensitivePrefix {
+    type State = PrefixState;
+
+    fn start(&self) -> PrefixState {
+        if self.prefix.is_empty() {
+            PrefixState::Matched
+        } else {
+            PrefixState::Prefix {
+                matched: 0,
+                pending: Vec::new(),
+            }
+        }
+    }
+
+    fn is_match(&self, state: &PrefixState) -> bool {
+        matches!(state, PrefixState::Matched)
+    }
+
+    fn can_match(&self, state: &PrefixState) -> bool {
+        !matches!(state, PrefixState::Dead)
+    }
+
+    fn accept(&self, state: &PrefixState, byte: u8) -> PrefixState {
+        let (matched, mut pending) = match state {
+            PrefixState::Prefix { matched, pending } => (*matched, pending.clone()),
+            other => return other.clone(),
+        };
+        pending.push(byte);
+        let c = match std::str::from_utf8(&pending) {
+            Ok(text) => text.chars().next().unwrap(),
+            // 글자가 아직 끝나지 않았으면 다음 바이트를 기다립니다
+            Err(error) if error.error_len().is_none() => {
+                return PrefixState::Prefix { matched, pending };
+            }
+            Err(_) => return PrefixState::Dead,
+        };
+
+        let lowered: Vec<char> = c.to_lowercase().collect();
+        if !self.prefix[matched..].starts_with(&lowered) {
+            return PrefixState::Dead;
+        }
+        let matched = matched + lowered.len();
+        if matched == self.prefix.len() {
+            PrefixState::Matched
+        } else {
+            PrefixState::Prefix {
+                matched,
+                pending: Vec::new(),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn is_match(prefix: &str, key: &str) -> bool {
+        let automaton = CaseInsensitivePrefix::new(prefix);
+        let state = key
+            .bytes()
+            .fold(automaton.start(), |state, byte| automaton.accept(&state, byte));
+        automaton.is_match(&state)
+    }
+
+    #[test]
+    fn test_case_insensitive_prefix() {
+        assert!(is_match("inch", "Incheon"));
+        assert!(is_match("INCH", "incheon"));
+        assert!(is_match("인천", "인천국제공항"));
+        assert!(!is_match("인천", "인하"));
+        assert!(!is_match("incheon", "inch"));
+    }
+}
@@ -0,0 +1,127 @@
+use tantivy::columnar::{Column, StrColumn};
+use tantivy::{DateTime, DocAddress, Searcher};
+
+use crate::api::geo::{GeoColumns, GeoPoint};
+
+/// 검색 결과에 담을 저장 필드
+#[derive(Clone, Debug, Default)]
+pub enum FieldSelection {
+    /// 모든 필드 (기본값)
+    #[default]
+    All,
+    /// 문서 ID, 점수, 정렬 값, 시스템 시각, 거리만
+    /// (fast field 만 읽고 문서 저장소는 읽지 않으므로 스니펫과 함께 쓸 수 없습니다, metadata 는 "{}")
+    IdOnly,
+    /// 지정한 필드만 ("title", "body", "metadata")
+    /// 빠진 title, body 는 빈 문자열, metadata 는 "{}" 이고 ID 와 시스템 필드는 항상 포함합니다
+    /// (문서 저장소에서 문서 전체를 읽으므로 응답 크기만 줄고 읽는 비용은 같습니다)
+    Fields(Vec<String>),
+}
+
+// 결과에 담을 필드
+pub(crate) struct Projection {
+    pub(crate) title: bool,
+    pub(crate) body: bool,
+    pub(crate) metadata: bool,
+    body_max_chars: Option<usize>,
+}
+
+impl Projection {
+    pub(crate) fn new(
+        selection: &FieldSelection,
+        body_max_chars: Option<usize>,
+    ) -> Result<Self, String> {
+        let mut projection = Projection {
+            title: true,
+            body: true,
+            metadata: true,
+            body_max_chars,
+        };
+        if let FieldSelection::Fields(names) = selection {
+            projection.title = false;
+            projection.body = false;
+            projection.metadata = false;
+            for name in names {
+                match name.as_str() {
+                    "title" => projection.title = true,
+                    "body" => projection.body = true,
+                    "metadata" => projection.metadata = true,
+                    _ => return Err(format!("선택할 수 없는 필드입니다: {}", name)),
+                }
+            }
+        }
+        Ok(projection)
+    }
+
+    // 본문을 최대 글자 수로 자릅니다 (바이트가 아닌 문자 단위)
+    pub(crate) fn body(&self, body: &str) -> String {
+        match self.body_max_chars {
+            Some(max_chars) => body.chars().take(max_chars).collect(),
+            None => body.to_string(),
+        }
+    }
+}
+
+// 세그먼트별 id, 시스템 시각, 좌표 fast field 컬럼 (ID 만 반환할 때 문서 저장소 대신 읽습니다)
+pub(crate) struct FastHitFields {
+    segments: Vec<SegmentHitFields>,
+}
+
+struct SegmentHitFields {
+    id: Option<StrColumn>,
+    created_at: Column<DateTime>,
+    updated_at: Column<DateTime>,
+    geo: GeoColumns,
+}
+
+impl FastHitFields {
+    pub(crate) fn open(
+        searcher: &Searcher,
+        id_field: &str,
+        created_at_field: &str,
+        updated_at_field: &str,
+    ) -> Result<Self, String> {
+        let segments = searcher
+            .segment_readers()
+            .iter()
+            .map(|segment_reader| {
+                let fast_fields = segment_reader.fast_fields();
+                Ok(SegmentHitFields {
+                    id: fast_fields.str(id_field)?,
+                    created_at: fast_fields.date(created_at_field)?,
+                    updated_at: fast_fields.date(updated_at_field)?,
+                    geo: GeoColumns::open(segment_reader)?,
+                })
+            })
+            .collect::<tantivy::Result<Vec<_>>>()
+            .map_err(|e| e.to_string())?;
+        Ok(FastHitFields { segments })
+    }
+
+    pub(crate) fn id(&self, doc_address: DocAddress) -> Result<String, String> {
+        let mut id = String::new();
+        if let Some(column) = &self.segments[doc_address.segment_ord as usize].id {
+            if let Some(ord) = column.term_ords(doc_address.doc_id).next() {
+                column.ord_to_str(ord, &mut id).map_err(|e| e.to_string())?;
+            }
+        }
+        Ok(id)
+    }
+
+    // (생성 시각, 수정 시각) Unix 시간 밀리초
+    pub(crate) fn timestamps(&self, doc_address: DocAddress) -> (i64, i64) {
+        let segment = &self.segments[doc_address.segment_ord as usize];
+        let millis = |column: &Column<DateTime>| {
+            column
+                .first(doc_address.doc_id)
+                .map_or(0, |date| date.into_timestamp_millis())
+        };
+        (millis(&segment.created_at), millis(&segment.updated_at))
+    }
+
+    pub(crate) fn point(&self, doc_address: DocAddress) -> Option<GeoPoint> {
+        self.segments[doc_address.segment_ord as usize]
+            .geo
+            .point(doc_address.doc_id)
+    }
+}
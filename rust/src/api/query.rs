@@ -0,0 +1,395 @@
+use std::ops::Bound;
+
+use tantivy::query::{
+    AllQuery, BooleanQuery, BoostQuery, ConstScoreQuery, EmptyQuery, FuzzyTermQuery, Occur, PhrasePrefixQuery,
+    PhraseQuery, Query, RangeQuery, TermQuery,
+};
+use tantivy::schema::{Field, FieldType, IndexRecordOption};
+use tantivy::{DateTime, Index, Term};
+
+use crate::api::date::parse_date;
+use crate::api::facet::parse_category;
+use crate::api::geo::{GeoFilter, GeoQuery};
+
+/// 구조화된 검색 쿼리 트리
+///
+/// 쿼리 문자열을 파싱하지 않고 Tantivy 쿼리를 직접 만들기 때문에
+/// `:`, `"`, `(`, `-` 같은 문자를 이스케이프할 필요가 없습니다.
+/// `field` 에는 스키마 필드 이름(`title`, `body`, `title_ngram`, `body_ngram`, `id`)이나
+/// 메타데이터 JSON 경로(`metadata.country`)를 지정합니다.
+#[derive(Clone, Debug)]
+pub enum QueryNode {
+    /// 모든 문서
+    MatchAll,
+    /// 필드 토크나이저로 분석한 모든 색인어를 포함하는 문서
+    Term { field: String, text: String },
+    /// 구문 검색 (slop: 색인어 사이에 허용하는 간격)
+    Phrase {
+        field: String,
+        text: String,
+        slop: u32,
+    },
+    /// 불리언 조합 (must 와 should 가 모두 비어 있으면 전체 문서에서 must_not 을 제외)
+    Boolean {
+        must: Vec<QueryNode>,
+        should: Vec<QueryNode>,
+        must_not: Vec<QueryNode>,
+    },
+    /// 하위 쿼리 점수에 가중치(0 이상)를 곱합니다
+    Boost { query: Box<QueryNode>, boost: f32 },
+    /// 레벤슈타인 거리(1 ~ 2) 이내의 색인어
+    Fuzzy {
+        field: String,
+        text: String,
+        distance: u8,
+        transposition: bool,
+    },
+    /// 마지막 색인어가 접두어로 일치하는 문서
+    Prefix { field: String, prefix: String },
+    /// 범위 검색 (lower, upper 중 하나 이상 필요)
+    ///
+    /// 메타데이터 JSON 경로의 숫자는 정수로 색인되었든 실수로 색인되었든 Integer, Float 경계와 비교합니다.
+    Range {
+        field: String,
+        lower: Option<RangeBound>,
+        upper: Option<RangeBound>,
+    },
+    /// 계층형 카테고리 경로와 그 하위 카테고리에 속한 문서 (예: `category`, `/travel/asia`)
+    Facet { field: String, path: String },
+    /// 좌표(기본값 `metadata.location`)가 영역 또는 반경 안에 있는 문서
+    Geo { filter: GeoFilter },
+}
+
+/// 범위 검색 경계 값
+#[derive(Clone, Debug)]
+pub enum RangeValue {
+    Text(String),
+    Integer(i64),
+    Float(f64),
+    /// RFC 3339 또는 `YYYY-MM-DD` 날짜 (`dates.<경로>` 같은 날짜 필드용)
+    Date(String),
+}
+
+/// 범위 검색 경계
+#[derive(Clone, Debug)]
+pub struct RangeBound {
+    pub value: RangeValue,
+    pub inclusive: bool,
+}
+
+// 쿼리 트리를 Tantivy 쿼리로 변환합니다
+pub(crate) fn build_query(index: &Index, node: &QueryNode) -> Result<Box<dyn Query>, String> {
+    match node {
+        QueryNode::MatchAll => Ok(Box::new(AllQuery)),
+        QueryNode::Term { field, text } => {
+            let terms = analyze(index, field, text)?;
+            Ok(match terms.len() {
+                0 => Box::new(EmptyQuery),
+                1 => term_query(terms.into_iter().next().unwrap().1),
+                _ => Box::new(BooleanQuery::intersection(
+                    terms.into_iter().map(|(_, term)| term_query(term)).collect(),
+                )),
+            })
+        }
+        QueryNode::Phrase { field, text, slop } => {
+            let mut terms = analyze(index, field, text)?;
+            Ok(match terms.len() {
+                0 => Box::new(EmptyQuery),
+                1 => term_query(terms.pop().unwrap().1),
+                _ => Box::new(PhraseQuery::new_with_offset_and_slop(terms, *slop)),
+            })
+        }
+        QueryNode::Boolean {
+            must,
+            should,
+            must_not,
+        } => {
+            let mut clauses: Vec<(Occur, Box<dyn Query>)> = Vec::new();
+            for (occur, nodes) in [
+                (Occur::Must, must),
+                (Occur::Should, should),
+                (Occur::MustNot, must_not),
+            ] {
+                for node in nodes {
+                    clauses.push((occur, build_query(index, node)?));
+                }
+            }
+            // must_not 만 있는 불리언 쿼리는 아무것도 매칭하지 않으므로 전체 문서를 기준으로 합니다
+            if must.is_empty() && should.is_empty() {
+                clauses.push((Occur::Must, Box::new(AllQuery)));
+            }
+            Ok(Box::new(BooleanQuery::new(clauses)))
+        }
+        QueryNode::Boost { query, boost } => {
+            if !boost.is_finite() || *boost < 0.0 {
+                return Err(format!("boost 는 0 이상의 유한한 값이어야 합니다: {}", boost));
+            }
+            Ok(Box::new(BoostQuery::new(build_query(index, query)?, *boost)))
+        }
+        QueryNode::Fuzzy {
+            field,
+            text,
+            distance,
+            transposition,
+        } => {
+            if !(1..=2).contains(distance) {
+                return Err(format!(
+                    "퍼지 검색 거리는 1 ~ 2 사이여야 합니다: {}",
+                    distance
+                ));
+            }
+            let terms = analyze(index, field, text)?;
+            if terms.is_empty() {
+                return Ok(Box::new(EmptyQuery));
+            }
+            Ok(Box::new(BooleanQuery::intersection(
+                terms
+                    .into_iter()
+                    .map(|(_, term)| -> Box<dyn Query> {
+                        Box::new(FuzzyTermQuery::new(term, *distance, *transposition))
+                    })
+                    .collect(),
+            )))
+        }
+        QueryNode::Prefix { field, prefix } => {
+            let terms = analyze(index, field, prefix)?;
+            if terms.is_empty() {
+                return Ok(Box::new(EmptyQuery));
+            }
+            Ok(Box::new(PhrasePrefixQuery::new_with_offset(terms)))
+        }
+        QueryNode::Range {
+            field,
+            lower,
+            upper,
+        } => {
+            if lower.is_none() && upper.is_none() {
+                return Err("범위 쿼리에는 lower 또는 upper 가 필요합니다.".to_string());
+            }
+            let (field, json_path) = resolve_field(index, field)?;
+            let is_number = |bound: &Option<RangeBound>| {
+                bound.as_ref().is_none_or(|bound| {
+                    matches!(bound.value, RangeValue::Integer(_) | RangeValue::Float(_))
+                })
+            };
+            if matches!(
+                index.schema().get_field_entry(field).field_type(),
+                FieldType::JsonObject(_)
+            ) && is_number(lower)
+                && is_number(upper)
+            {
+                return json_number_range(field, json_path, lower, upper);
+            }
+            let bound = |bound: &Option<RangeBound>| -> Result<Bound<Term>, String> {
+                Ok(match bound {
+                    None => Bound::Unbounded,
+                    Some(bound) => {
+                        let term = value_term(index, field, json_path, &bound.value)?;
+                        if bound.inclusive {
+                            Bound::Included(term)
+                        } else {
+                            Bound::Excluded(term)
+                        }
+                    }
+                })
+            };
+            Ok(Box::new(RangeQuery::new(bound(lower)?, bound(upper)?)))
+        }
+        QueryNode::Facet { field, path } => {
+            let (field, _) = resolve_field(index, field)?;
+            if !matches!(index.schema().get_field_entry(field).field_type(), FieldType::Facet(_)) {
+                return Err(format!(
+                    "카테고리 필드가 아닙니다: {}",
+                    index.schema().get_field_name(field)
+                ));
+            }
+            let facet = parse_category(path)?;
+            Ok(Box::new(TermQuery::new(
+                Term::from_facet(field, &facet),
+                IndexRecordOption::Basic,
+            )))
+        }
+        QueryNode::Geo { filter } => Ok(Box::new(GeoQuery::new(filter.clone())?)),
+    }
+}
+
+fn term_query(term: Term) -> Box<dyn Query> {
+    Box::new(TermQuery::new(term, IndexRecordOption::WithFreqs))
+}
+
+// 필드 이름(또는 JSON 경로)을 필드와 JSON 하위 경로로 나눕니다
+fn resolve_field<'a>(index: &Index, name: &'a str) -> Result<(Field, &'a str), String> {
+    let schema = index.schema();
+    let (field, json_path) = schema
+        .find_field(name)
+        .ok_or_else(|| format!("알 수 없는 필드입니다: {}", name))?;
+    let field_type = schema.get_field_entry(field).field_type();
+    if !json_path.is_empty() && !matches!(field_type, FieldType::JsonObject(_)) {
+        return Err(format!("알 수 없는 필드입니다: {}", name));
+    }
+    if !field_type.is_indexed() {
+        return Err(format!("색인되지 않은 필드입니다: {}", name));
+    }
+    Ok((field, json_path))
+}
+
+// 텍스트를 필드의 토크나이저로 분석하여 (위치, 색인어) 목록을 만듭니다
+fn analyze(index: &Index, name: &str, text: &str) -> Result<Vec<(usize, Term)>, String> {
+    let (field, json_path) = resolve_field(index, name)?;
+    let mut analyzer = index
+        .tokenizer_for_field(field)
+        .map_err(|e| e.to_string())?;
+    let mut token_stream = analyzer.token_stream(text);
+    let mut terms = Vec::new();
+    token_stream.process(&mut |token| {
+        terms.push((token.position, text_term(field, json_path, &token.text)));
+    });
+    Ok(terms)
+}
+
+fn text_term(field: Field, json_path: &str, text: &str) -> Term {
+    if json_path.is_empty() {
+        Term::from_field_text(field, text)
+    } else {
+        let mut term = Term::from_field_json_path(field, json_path, false);
+        term.append_type_and_str(text);
+        term
+    }
+}
+
+// JSON 경로의 숫자 범위
+// Tantivy 는 세그먼트의 숫자 열 타입(i64, u64, f64)에 맞춰 경계 값을 바꿔 주지만, 두 경계의 타입이 다르면
+// 처리하지 못하고 소수 경계를 정수 열에 맞출 때 소수점 아래를 버리므로 (1.5 이상 -> 1 이상),
+// 경계를 한 타입으로 맞추고 잘못 포함되는 정수 하나를 빼 줍니다
+fn json_number_range(
+    field: Field,
+    json_path: &str,
+    lower: &Option<RangeBound>,
+    upper: &Option<RangeBound>,
+) -> Result<Box<dyn Query>, String> {
+    let number = |bound: &RangeBound| -> Result<f64, String> {
+        match bound.value {
+            RangeValue::Integer(value) => Ok(value as f64),
+            RangeValue::Float(value) if !value.is_nan() => Ok(value),
+            _ => Err(format!("범위 값이 숫자가 아닙니다: {:?}", bound.value)),
+        }
+    };
+    let fractional = [lower, upper]
+        .into_iter()
+        .flatten()
+        .map(|bound| number(bound).map(|value| value.fract() != 0.0))
+        .collect::<Result<Vec<bool>, String>>()?
+        .contains(&true);
+
+    // 소수 경계가 없으면 정수로 (큰 정수도 정확히), 있으면 실수로 비교합니다
+    let json_term = |bound: &RangeBound| -> Term {
+        let mut term = Term::from_field_json_path(field, json_path, false);
+        match bound.value {
+            RangeValue::Integer(value) if !fractional => term.append_type_and_fast_value(value),
+            RangeValue::Float(value) if !fractional => {
+                term.append_type_and_fast_value(value as i64)
+            }
+            RangeValue::Integer(value) => term.append_type_and_fast_value(value as f64),
+            RangeValue::Float(value) => term.append_type_and_fast_value(value),
+            _ => unreachable!("숫자 범위 값만 전달됩니다"),
+        }
+        term
+    };
+    let to_bound = |bound: &Option<RangeBound>| match bound {
+        None => Bound::Unbounded,
+        Some(bound) if bound.inclusive => Bound::Included(json_term(bound)),
+        Some(bound) => Bound::Excluded(json_term(bound)),
+    };
+    let range: Box<dyn Query> = Box::new(RangeQuery::new(to_bound(lower), to_bound(upper)));
+
+    // 정수 열에서 소수점 아래를 버린 경계가 범위 밖의 정수를 포함하는 경우 (하한 1.5 -> 1, 상한 -1.5 -> -1)
+    let mut clauses = vec![(Occur::Must, range)];
+    for (bound, is_lower) in [(lower, true), (upper, false)] {
+        let Some(bound) = bound else { continue };
+        let value = number(bound)?;
+        let truncated = value.trunc();
+        let outside = if is_lower {
+            truncated < value
+        } else {
+            truncated > value
+        };
+        if outside {
+            let mut term = Term::from_field_json_path(field, json_path, false);
+            term.append_type_and_fast_value(truncated as i64);
+            clauses.push((
+                Occur::MustNot,
+                Box::new(RangeQuery::new(
+                    Bound::Included(term.clone()),
+                    Bound::Included(term),
+                )),
+            ));
+        }
+    }
+    Ok(Box::new(ConstScoreQuery::new(
+        Box::new(BooleanQuery::new(clauses)),
+        1.0,
+    )))
+}
+
+// 범위 경계 값을 필드 타입에 맞는 색인어로 변환합니다
+fn value_term(
+    index: &Index,
+    field: Field,
+    json_path: &str,
+    value: &RangeValue,
+) -> Result<Term, String> {
+    let schema = index.schema();
+    let field_type = schema.get_field_entry(field).field_type();
+    let term = match (field_type, value) {
+        (FieldType::Str(_), RangeValue::Text(text)) => Term::from_field_text(field, text),
+        (FieldType::JsonObject(_), RangeValue::Text(text)) => text_term(field, json_path, text),
+        (FieldType::JsonObject(_), RangeValue::Integer(value)) => {
+            let mut term = Term::from_field_json_path(field, json_path, false);
+            term.append_type_and_fast_value(*value);
+            term
+        }
+        (FieldType::JsonObject(_), RangeValue::Float(value)) => {
+            let mut term = Term::from_field_json_path(field, json_path, false);
+            term.append_type_and_fast_value(*value);
+            term
+        }
+        (FieldType::JsonObject(_), RangeValue::Date(text)) => {
+            let mut term = Term::from_field_json_path(field, json_path, false);
+            term.append_type_and_fast_value(date_value(text)?);
+            term
+        }
+        (FieldType::Date(_), RangeValue::Date(text)) => {
+            Term::from_field_date(field, date_value(text)?)
+        }
+        // 날짜 필드의 정수 값은 Unix 시간 밀리초
+        (FieldType::Date(_), RangeValue::Integer(millis)) => {
+            Term::from_field_date(field, DateTime::from_timestamp_millis(*millis))
+        }
+        (FieldType::I64(_), RangeValue::Integer(value)) => Term::from_field_i64(field, *value),
+        (FieldType::U64(_), RangeValue::Integer(value)) => {
+            let value = u64::try_from(*value).map_err(|_| {
+                format!(
+                    "음수는 부호 없는 정수 필드의 범위 값이 될 수 없습니다: {} ({})",
+                    schema.get_field_name(field),
+                    value
+                )
+            })?;
+            Term::from_field_u64(field, value)
+        }
+        (FieldType::F64(_), RangeValue::Float(value)) => Term::from_field_f64(field, *value),
+        (FieldType::F64(_), RangeValue::Integer(value)) => {
+            Term::from_field_f64(field, *value as f64)
+        }
+        _ => {
+            return Err(format!(
+                "필드 타입과 범위 값의 타입이 맞지 않습니다: {}",
+                schema.get_field_name(field)
+            ))
+        }
+    };
+    Ok(term)
+}
+
+fn date_value(text: &str) -> Result<DateTime, String> {
+    parse_date(text).ok_or_else(|| format!("날짜 형식이 올바르지 않습니다: {}", text))
+}
@@ -0,0 +1,394 @@
+use std::sync::{Arc, RwLock};
+
+use lindera::tokenizer::Tokenizer;
+use tantivy::query::Query;
+use tantivy::schema::Field;
+
+use crate::api::ngram::{positional_ngram_query, PositionalNgramTokenStream};
+use crate::api::search::DictionaryType;
+
+// 읽기 N-gram 토크나이저의 최대 길이 (더 긴 검색어는 이 길이의 조각을 이은 구 쿼리로 찾습니다)
+pub(crate) const READING_NGRAM_MAX: usize = 3;
+
+// 두 글자 가타카나의 헵번식 로마자 (한 글자보다 먼저 찾습니다)
+const DIGRAPHS: [(&str, &str); 32] = [
+    ("シェ", "she"),
+    ("ジェ", "je"),
+    ("チェ", "che"),
+    ("ヂャ", "ja"),
+    ("ヂュ", "ju"),
+    ("ヂョ", "jo"),
+    ("ファ", "fa"),
+    ("フィ", "fi"),
+    ("フェ", "fe"),
+    ("フォ", "fo"),
+    ("ティ", "ti"),
+    ("ディ", "di"),
+    ("トゥ", "tu"),
+    ("ドゥ", "du"),
+    ("ウィ", "wi"),
+    ("ウェ", "we"),
+    ("ウォ", "wo"),
+    ("ヴァ", "va"),
+    ("ヴィ", "vi"),
+    ("ヴェ", "ve"),
+    ("ヴォ", "vo"),
+    ("ツァ", "tsa"),
+    ("ツィ", "tsi"),
+    ("ツェ", "tse"),
+    ("ツォ", "tso"),
+    ("イェ", "ye"),
+    ("クァ", "kwa"),
+    ("グァ", "gwa"),
+    ("テュ", "tyu"),
+    ("デュ", "dyu"),
+    ("フュ", "fyu"),
+    ("ヴュ", "vyu"),
+];
+
+// イ단 가타카나 + 작은 ャュョ 의 자음 (예: キャ -> kya, シャ -> sha)
+const YOON_CONSONANTS: [(char, &str); 12] = [
+    ('キ', "ky"),
+    ('ギ', "gy"),
+    ('シ', "sh"),
+    ('ジ', "j"),
+    ('チ', "ch"),
+    ('ニ', "ny"),
+    ('ヒ', "hy"),
+    ('ビ', "by"),
+    ('ピ', "py"),
+    ('ミ', "my"),
+    ('リ', "ry"),
+    ('ヴ', "vy"),
+];
+
+// 일본어 읽기, 중국어 병음 필드 설정 (색인 분석기와 검색이 함께 읽습니다)
+#[flutter_rust_bridge::frb(ignore)]
+#[derive(Default)]
+pub(crate) struct ReadingOptions {
+    // 사전이 읽기 정보를 제공하는지 여부 (IPADIC, UniDic)
+    pub(crate) japanese_dictionary: bool,
+    // 읽기 필드를 채우고 가나 검색어를 읽기 필드에서도 찾을지 여부
+    pub(crate) japanese: bool,
+    // 가타카나 읽기와 함께 로마자 표기도 색인하고 로마자 검색어를 읽기 필드에서도 찾을지 여부
+    pub(crate) romaji: bool,
+    // 사전이 병음 정보를 제공하는지 여부 (CC-CEDICT)
+    pub(crate) chinese_dictionary: bool,
+    // 병음 필드를 채우고 영문 검색어를 병음 필드에서도 찾을지 여부
+    pub(crate) pinyin: bool,
+}
+
+pub(crate) type SharedReadingOptions = Arc<RwLock<ReadingOptions>>;
+
+pub(crate) fn reading_options(dictionary_type: &DictionaryType) -> SharedReadingOptions {
+    Arc::new(RwLock::new(ReadingOptions {
+        japanese_dictionary: matches!(
+            dictionary_type,
+            DictionaryType::JapaneseIpadic | DictionaryType::JapaneseUnidic
+        ),
+        chinese_dictionary: matches!(dictionary_type, DictionaryType::Chinese),
+        ..Default::default()
+    }))
+}
+
+fn is_kana(c: char) -> bool {
+    ('\u{3041}'..='\u{3096}').contains(&c)
+        || ('\u{309D}'..='\u{309E}').contains(&c)
+        || ('\u{30A1}'..='\u{30FA}').contains(&c)
+        || ('\u{30FC}'..='\u{30FE}').contains(&c)
+}
+
+// 히라가나를 가타카나로 바꿉니다 (예: "かんさい" -> "カンサイ")
+pub(crate) fn to_katakana(text: &str) -> String {
+    text.chars()
+        .map(|c| match c {
+            '\u{3041}'..='\u{3096}' | '\u{309D}'..='\u{309E}' => {
+                char::from_u32(c as u32 + 0x60).unwrap_or(c)
+            }
+            _ => c,
+        })
+        .collect()
+}
+
+fn kana_romaji(c: char) -> Option<&'static str> {
+    // 행마다 유니코드 배열이 고르지 않아 글자를 직접 나열합니다 (빈칸은 없는 글자)
+    const ROWS: [(&str, [&str; 5]); 15] = [
+        ("アイウエオ", ["a", "i", "u", "e", "o"]),
+        ("カキクケコ", ["ka", "ki", "ku", "ke", "ko"]),
+        ("ガギグゲゴ", ["ga", "gi", "gu", "ge", "go"]),
+        ("サシスセソ", ["sa", "shi", "su", "se", "so"]),
+        ("ザジズゼゾ", ["za", "ji", "zu", "ze", "zo"]),
+        ("タチツテト", ["ta", "chi", "tsu", "te", "to"]),
+        ("ダヂヅデド", ["da", "ji", "zu", "de", "do"]),
+        ("ナニヌネノ", ["na", "ni", "nu", "ne", "no"]),
+        ("ハヒフヘホ", ["ha", "hi", "fu", "he", "ho"]),
+        ("バビブベボ", ["ba", "bi", "bu", "be", "bo"]),
+        ("パピプペポ", ["pa", "pi", "pu", "pe", "po"]),
+        ("マミムメモ", ["ma", "mi", "mu", "me", "mo"]),
+        ("ヤ ユ ヨ", ["ya", "", "yu", "", "yo"]),
+        ("ラリルレロ", ["ra", "ri", "ru", "re", "ro"]),
+        ("ワヰ ヱヲ", ["wa", "i", "", "e", "o"]),
+    ];
+    match c {
+        'ン' => Some("n"),
+        'ヴ' => Some("vu"),
+        'ァ' => Some("a"),
+        'ィ' => Some("i"),
+        'ゥ' => Some("u"),
+        'ェ' => Some("e"),
+        'ォ' => Some("o"),
+        'ャ' => Some("ya"),
+        'ュ' => Some("yu"),
+        'ョ' => Some("yo"),
+        'ヮ' => Some("wa"),
+        ' ' => None,
+        _ => ROWS.iter().find_map(|(kana, romaji)| {
+            let position = kana.chars().position(|k| k == c)?;
+            Some(romaji[position])
+        }),
+    }
+}
+
+// 가타카나 읽기를 헵번식 로마자로 바꿉니다 (예: "カンサイ" -> "kansai", "ッ" 는 다음 자음을 겹침)
+pub(crate) fn katakana_to_romaji(text: &str) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let mut romaji = String::with_capacity(text.len());
+    let mut geminate = false;
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        let pair: String = chars[i..(i + 2).min(chars.len())].iter().collect();
+        let (syllable, width): (Option<String>, usize) =
+            if let Some((_, r)) = DIGRAPHS.iter().find(|(kana, _)| *kana == pair) {
+                (Some(r.to_string()), 2)
+            } else if let Some(consonant) = YOON_CONSONANTS
+                .iter()
+                .find(|(kana, _)| *kana == c)
+                .and_then(|(_, consonant)| {
+                    let vowel = match chars.get(i + 1) {
+                        Some('ャ') => "a",
+                        Some('ュ') => "u",
+                        Some('ョ') => "o",
+                        _ => return None,
+                    };
+                    Some(format!("{}{}", consonant, vowel))
+                })
+            {
+                (Some(consonant), 2)
+            } else if c == 'ッ' {
+                geminate = true;
+                (None, 1)
+            } else if let Some(r) = kana_romaji(c) {
+                (Some(r.to_string()), 1)
+            } else if c.is_ascii_alphanumeric() {
+                (Some(c.to_ascii_lowercase().to_string()), 1)
+            } else {
+                // 장음 기호나 한자 등은 버립니다
+                (None, 1)
+            };
+        if let Some(syllable) = syllable {
+            if std::mem::take(&mut geminate) {
+                if syllable.starts_with("ch") {
+                    romaji.push('t');
+                } else if let Some(first) = syllable.chars().next() {
+                    if !"aiueon".contains(first) {
+                        romaji.push(first);
+                    }
+                }
+            }
+            romaji.push_str(&syllable);
+        }
+        i += width;
+    }
+    fold_long_vowels(&romaji)
+}
+
+// 장음 표기 차이를 없앱니다 (예: "toukyou", "tookyoo" -> "tokyo", "kuukou" -> "kuko")
+fn fold_long_vowels(romaji: &str) -> String {
+    let mut folded = String::with_capacity(romaji.len());
+    for c in romaji.chars() {
+        let long = match folded.chars().last() {
+            Some(prev) => "aiueo".contains(c) && (prev == c || (prev == 'o' && c == 'u')),
+            None => false,
+        };
+        if !long {
+            folded.push(c);
+        }
+    }
+    folded
+}
+
+// 로마자 검색어를 색인과 같은 형태로 바꿉니다 (소문자, 영숫자만, 장음 통일)
+pub(crate) fn normalize_romaji(text: &str) -> String {
+    let text: String = text
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .map(|c| c.to_ascii_lowercase())
+        .collect();
+    fold_long_vowels(&text)
+}
+
+// 텍스트의 가타카나 읽기 (토큰마다 사전의 읽기, 없으면 표층형을 이어 붙임)
+pub(crate) fn japanese_reading(tokenizer: &Tokenizer, text: &str) -> String {
+    let mut reading = String::with_capacity(text.len());
+    for mut token in tokenizer.tokenize(text).unwrap_or_default() {
+        let surface = token.surface.to_string();
+        let token_reading = match token.get("reading") {
+            Some(value) if !value.is_empty() && value != "*" => value.to_string(),
+            _ => surface,
+        };
+        reading.extend(
+            to_katakana(&token_reading)
+                .chars()
+                .filter(|c| c.is_alphanumeric())
+                .flat_map(|c| c.to_lowercase()),
+        );
+    }
+    reading
+}
+
+// 읽기 필드 토크나이저 (가타카나 읽기, 로마자를 켜면 공백 뒤에 로마자 표기를 위치 N-gram 으로)
+#[derive(Clone)]
+pub(crate) struct ReadingTokenizer {
+    tokenizer: Arc<Tokenizer>,
+    options: SharedReadingOptions,
+}
+
+impl ReadingTokenizer {
+    pub(crate) fn new(tokenizer: Tokenizer, options: &SharedReadingOptions) -> Self {
+        ReadingTokenizer {
+            tokenizer: Arc::new(tokenizer),
+            options: options.clone(),
+        }
+    }
+}
+
+impl tantivy::tokenizer::Tokenizer for ReadingTokenizer {
+    type TokenStream<'a> = PositionalNgramTokenStream;
+
+    fn token_stream<'a>(&'a mut self, text: &'a str) -> Self::TokenStream<'a> {
+        let mut reading = japanese_reading(&self.tokenizer, text);
+        if self.options.read().unwrap().romaji {
+            let romaji = katakana_to_romaji(&reading);
+            reading.push(' ');
+            reading.push_str(&romaji);
+        }
+        PositionalNgramTokenStream::new(&reading, READING_NGRAM_MAX).spanning(text.len())
+    }
+}
+
+// 텍스트의 병음 (성조를 뺀 음절을 이어 붙인 전체 병음과 음절 첫 글자, 예: "北京" -> "beijing bj")
+// 사전에 병음이 없는 영숫자 토큰은 전체 병음에 그대로 넣고 다른 토큰은 버립니다
+pub(crate) fn chinese_pinyin(tokenizer: &Tokenizer, text: &str) -> String {
+    let mut full = String::with_capacity(text.len());
+    let mut initials = String::new();
+    for mut token in tokenizer.tokenize(text).unwrap_or_default() {
+        let surface = token.surface.to_lowercase();
+        match token.get("pinyin") {
+            Some(value) if !value.is_empty() && value != "*" => {
+                for syllable in value.split_whitespace() {
+                    let syllable = syllable
+                        .to_lowercase()
+                        .replace("u:", "v")
+                        .replace('ü', "v");
+                    let syllable: String =
+                        syllable.chars().filter(|c| c.is_ascii_alphabetic()).collect();
+                    initials.extend(syllable.chars().next());
+                    full.push_str(&syllable);
+                }
+            }
+            _ => full.extend(surface.chars().filter(|c| c.is_ascii_alphanumeric())),
+        }
+    }
+    full.push(' ');
+    full.push_str(&initials);
+    full
+}
+
+// 병음 필드 토크나이저 (전체 병음과 음절 첫 글자를 위치 N-gram 으로)
+#[derive(Clone)]
+pub(crate) struct PinyinTokenizer {
+    tokenizer: Arc<Tokenizer>,
+}
+
+impl PinyinTokenizer {
+    pub(crate) fn new(tokenizer: Tokenizer) -> Self {
+        PinyinTokenizer {
+            tokenizer: Arc::new(tokenizer),
+        }
+    }
+}
+
+impl tantivy::tokenizer::Tokenizer for PinyinTokenizer {
+    type TokenStream<'a> = PositionalNgramTokenStream;
+
+    fn token_stream<'a>(&'a mut self, text: &'a str) -> Self::TokenStream<'a> {
+        let pinyin = chinese_pinyin(&self.tokenizer, text);
+        PositionalNgramTokenStream::new(&pinyin, READING_NGRAM_MAX).spanning(text.len())
+    }
+}
+
+// 가나로만 된 검색어인지 확인합니다 (공백 제외, 예: "かんさい", "カンサイ")
+pub(crate) fn is_kana_query(text: &str) -> bool {
+    let mut chars = text.chars().filter(|c| !c.is_whitespace()).peekable();
+    chars.peek().is_some() && chars.all(is_kana)
+}
+
+// 영문자와 숫자로만 된 검색어인지 확인합니다 (로마자, 병음 검색어, 쿼리 문법 문자가 없는 경우)
+pub(crate) fn is_latin_query(text: &str) -> bool {
+    text.chars().any(|c| c.is_ascii_alphabetic())
+        && text
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c.is_whitespace())
+}
+
+// 읽기 필드 쿼리 (가나 검색어는 가타카나로, 로마자 검색어는 색인과 같은 로마자로)
+pub(crate) fn reading_query(
+    field: Field,
+    text: &str,
+    options: &ReadingOptions,
+) -> Option<Box<dyn Query>> {
+    if !options.japanese {
+        return None;
+    }
+    let reading = if is_kana_query(text) {
+        to_katakana(&text.chars().filter(|c| !c.is_whitespace()).collect::<String>())
+    } else if options.romaji && is_latin_query(text) {
+        normalize_romaji(text)
+    } else {
+        return None;
+    };
+    Some(positional_ngram_query(field, &reading, READING_NGRAM_MAX))
+}
+
+// 병음 필드 쿼리 (영문 검색어를 소문자 영숫자로, 예: "Bei Jing" -> "beijing", "bjsd")
+pub(crate) fn pinyin_query(
+    field: Field,
+    text: &str,
+    options: &ReadingOptions,
+) -> Option<Box<dyn Query>> {
+    if !options.pinyin || !is_latin_query(text) {
+        return None;
+    }
+    let pinyin: String = text
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .map(|c| c.to_ascii_lowercase())
+        .collect();
+    Some(positional_ngram_query(field, &pinyin, READING_NGRAM_MAX))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_katakana_to_romaji() {
+        assert_eq!(katakana_to_romaji("カンサイ"), "kansai");
+        // 장음과 촉음 ("ッ")
+        assert_eq!(katakana_to_romaji("トウキョウ"), "tokyo");
+        assert_eq!(katakana_to_romaji("ガッコウ"), "gakko");
+        assert_eq!(katakana_to_romaji("マッチ"), "matchi");
+        assert_eq!(katakana_to_romaji(&to_katakana("ちゅうごく")), "chugoku");
+    }
+}
@@ -0,0 +1,169 @@
+use tantivy::columnar::DynamicColumn;
+use tantivy::{DocId, Index, Score, SegmentReader};
+
+use crate::api::date::now;
+use crate::api::sort::{ensure_fast_field, first_value, SortValue};
+
+const MILLIS_PER_DAY: f64 = 24.0 * 60.0 * 60.0 * 1000.0;
+
+/// 텍스트 점수(BM25)에 곱할 점수 보정
+///
+/// 여러 개를 지정하면 모두 곱합니다. 필드 값이 없는 문서는 보정하지 않습니다(1배).
+#[derive(Clone, Debug)]
+pub enum ScoreModifier {
+    /// 날짜 필드가 기준 시각에서 멀어질수록 점수를 낮춥니다
+    /// (offset_days 이내는 1배, 거기서 scale_days 만큼 더 멀어지면 decay 배)
+    DateDecay {
+        field: String,
+        function: DecayFunction,
+        /// 기준 시각 (Unix 시간 밀리초, None 이면 검색 시각)
+        origin: Option<i64>,
+        scale_days: f64,
+        offset_days: f64,
+        /// 0 보다 크고 1 보다 작은 값 (예: 0.5)
+        decay: f64,
+    },
+    /// 숫자 필드 값(예: 조회수)에 따라 `1 + ln(1 + factor * 값)` 배
+    LogBoost { field: String, factor: f64 },
+    /// 문서별 고정 순위 값에 따라 `1 + weight * 값` 배 (음수가 되면 0배)
+    StaticRank { field: String, weight: f64 },
+}
+
+/// 감쇠 함수
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DecayFunction {
+    Exponential,
+    Gaussian,
+}
+
+impl ScoreModifier {
+    fn field(&self) -> &str {
+        match self {
+            ScoreModifier::DateDecay { field, .. }
+            | ScoreModifier::LogBoost { field, .. }
+            | ScoreModifier::StaticRank { field, .. } => field,
+        }
+    }
+
+    fn validate(&self, index: &Index) -> Result<(), String> {
+        ensure_fast_field(index, self.field())?;
+        match self {
+            ScoreModifier::DateDecay {
+                scale_days,
+                offset_days,
+                decay,
+                ..
+            } => {
+                if scale_days.is_nan() || *scale_days <= 0.0 {
+                    return Err(format!("scale_days 는 0보다 커야 합니다: {}", scale_days));
+                }
+                if offset_days.is_nan() || *offset_days < 0.0 {
+                    return Err(format!("offset_days 는 0 이상이어야 합니다: {}", offset_days));
+                }
+                if decay.is_nan() || *decay <= 0.0 || *decay >= 1.0 {
+                    return Err(format!("decay 는 0 과 1 사이여야 합니다: {}", decay));
+                }
+            }
+            ScoreModifier::LogBoost { factor, .. } => {
+                if !factor.is_finite() || *factor < 0.0 {
+                    return Err(format!("factor 는 0 이상의 유한한 값이어야 합니다: {}", factor));
+                }
+            }
+            ScoreModifier::StaticRank { weight, .. } => {
+                if !weight.is_finite() {
+                    return Err(format!("weight 는 유한한 값이어야 합니다: {}", weight));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    // 필드 값에 대한 배수
+    fn multiplier(&self, value: f64, query_time: i64) -> f64 {
+        match self {
+            ScoreModifier::DateDecay {
+                function,
+                origin,
+                scale_days,
+                offset_days,
+                decay,
+                ..
+            } => {
+                let origin = origin.unwrap_or(query_time) as f64;
+                let distance =
+                    ((value - origin).abs() / MILLIS_PER_DAY - offset_days).max(0.0);
+                match function {
+                    DecayFunction::Exponential => (decay.ln() / scale_days * distance).exp(),
+                    DecayFunction::Gaussian => {
+                        (decay.ln() * distance.powi(2) / scale_days.powi(2)).exp()
+                    }
+                }
+            }
+            ScoreModifier::LogBoost { factor, .. } => 1.0 + (1.0 + factor * value.max(0.0)).ln(),
+            ScoreModifier::StaticRank { weight, .. } => (1.0 + weight * value).max(0.0),
+        }
+    }
+}
+
+// 점수 보정 목록 (검색 시각을 함께 고정합니다)
+#[flutter_rust_bridge::frb(ignore)]
+#[derive(Clone, Debug, Default)]
+pub(crate) struct ScoreModifiers {
+    modifiers: Vec<ScoreModifier>,
+    query_time: i64,
+}
+
+impl ScoreModifiers {
+    pub(crate) fn new(index: &Index, modifiers: &[ScoreModifier]) -> Result<Self, String> {
+        for modifier in modifiers {
+            modifier.validate(index)?;
+        }
+        Ok(ScoreModifiers {
+            modifiers: modifiers.to_vec(),
+            query_time: now().into_timestamp_millis(),
+        })
+    }
+
+    pub(crate) fn for_segment(
+        &self,
+        segment_reader: &SegmentReader,
+    ) -> tantivy::Result<SegmentScoreModifiers> {
+        let fast_fields = segment_reader.fast_fields();
+        let mut columns = Vec::with_capacity(self.modifiers.len());
+        for modifier in &self.modifiers {
+            columns.push(
+                fast_fields
+                    .dynamic_column_handles(modifier.field())?
+                    .iter()
+                    .map(|handle| handle.open())
+                    .collect::<std::io::Result<Vec<DynamicColumn>>>()?,
+            );
+        }
+        Ok(SegmentScoreModifiers {
+            modifiers: self.modifiers.clone(),
+            columns,
+            query_time: self.query_time,
+        })
+    }
+}
+
+pub(crate) struct SegmentScoreModifiers {
+    modifiers: Vec<ScoreModifier>,
+    columns: Vec<Vec<DynamicColumn>>,
+    query_time: i64,
+}
+
+impl SegmentScoreModifiers {
+    pub(crate) fn apply(&self, doc: DocId, score: Score) -> Score {
+        let mut score = score as f64;
+        for (modifier, columns) in self.modifiers.iter().zip(&self.columns) {
+            let value = match first_value(columns, doc) {
+                SortValue::Integer(value) => value as f64,
+                SortValue::Float(value) => value,
+                SortValue::Text(_) | SortValue::Missing => continue,
+            };
+            score *= modifier.multiplier(value, self.query_time);
+        }
+        score as Score
+    }
+}
@@ -1,10 +1,17 @@
-use tantivy::collector::TopDocs;
-use tantivy::query::QueryParser;
+use tantivy::collector::{Count, TopDocs};
+use tantivy::query::{
+    AllQuery, BooleanQuery, BoostQuery, Occur, Query, QueryParser, RangeQuery, TermQuery,
+};
 use tantivy::schema::{
-    Field, IndexRecordOption, JsonObjectOptions, Schema, TextFieldIndexing, TextOptions, Value,
+    DateOptions, DateTimePrecision, FacetOptions, Field, IndexRecordOption, JsonObjectOptions,
+    NumericOptions, Schema, TextFieldIndexing, TextOptions, Value,
+};
+use tantivy::snippet::{collapse_overlapped_ranges, SnippetGenerator};
+use tantivy::tokenizer::{LowerCaser, NgramTokenizer, TextAnalyzer};
+use tantivy::{
+    doc, DateTime, DocAddress, DocId, DocSet, Index, IndexWriter, Order, Searcher,
+    TantivyDocument, Term, TERMINATED,
 };
-use tantivy::tokenizer::NgramTokenizer;
-use tantivy::{doc, Index, TantivyDocument};
 
 use lindera::dictionary::load_dictionary;
 use lindera::mode::Mode;
@@ -12,8 +19,41 @@ use lindera::segmenter::Segmenter;
 use lindera::tokenizer::Tokenizer;
 use lindera_tantivy::tokenizer::LinderaTokenizer;
 
+use crate::api::aggregation::{
+    aggregation_collector, to_aggregation_results, AggregationRequest, AggregationResult,
+};
+use crate::api::char_filter::CharFilterTokenizer;
+use crate::api::date::{metadata_dates, now};
+use crate::api::explain::{to_score_explanation, ScoreExplanation};
+use crate::api::facet::{
+    category_collector, metadata_categories, parse_category, to_category_counts, CategoryCounts,
+};
+use crate::api::fuzzy::PrefixFuzzyQuery;
+use crate::api::geo::{metadata_location, GeoFilter, GeoPoint, GeoQuery, LAT_FIELD, LON_FIELD};
+use crate::api::group::{DistinctValueCollector, GroupByOptions, GroupCollector};
+use crate::api::hanja::hanja_to_hangul;
+use crate::api::korean::{
+    chosung, chosung_query, decompose_jamo, is_chosung_query, jamo_prefix_query, jamo_words,
+    JamoDecomposer, CHOSUNG_NGRAM_MAX, JAMO_PREFIX_MAX,
+};
+use crate::api::ngram::PositionalNgramTokenizer;
+use crate::api::prefix::CaseInsensitivePrefix;
+use crate::api::more_like_this::{more_like_this_query, MoreLikeThisOptions, MoreLikeThisSource};
+use crate::api::projection::{FastHitFields, FieldSelection, Projection};
+use crate::api::reading::{
+    pinyin_query, reading_options, reading_query, PinyinTokenizer, ReadingTokenizer,
+    SharedReadingOptions,
+};
+use crate::api::query::{build_query, QueryNode};
+use crate::api::scoring::ScoreModifier;
+use crate::api::settings::{IndexSettings, SCHEMA_VERSION};
+use crate::api::sort::{FastFieldSorter, SortBy, SortKey, SortValue, SortValues};
+use crate::api::variant::{folding_filter, SharedVariantFolding, VariantMapping, FOLDABLE_FIELDS};
+
 use serde_json::Value as JsonValue;
-use std::sync::Mutex;
+use std::collections::HashMap;
+use std::ops::{Bound, Range};
+use std::sync::{Arc, Mutex};
 use std::path::Path;
 
 /// 형태소 분석 토큰화 모드 (Normal / Decompose)
@@ -73,6 +113,174 @@ pub struct SearchResult {
     pub body: String,
     pub score: f32,
     pub metadata: String, // JSON string
+    /// 처음 추가된 시각 (Unix 시간 밀리초)
+    pub created_at: i64,
+    /// 마지막으로 추가/수정된 시각 (Unix 시간 밀리초)
+    pub updated_at: i64,
+}
+
+/// 검색 결과 스니펫(하이라이트) 옵션
+#[derive(Clone, Debug)]
+pub struct SnippetOptions {
+    /// 스니펫을 만들 필드 이름 ("body" 또는 "title")
+    pub field: String,
+    /// 조각(fragment)의 최대 글자 수
+    pub max_num_chars: usize,
+    /// true 이면 `<b>` 태그로 감싼 HTML 도 함께 반환합니다
+    pub html: bool,
+}
+
+impl Default for SnippetOptions {
+    fn default() -> Self {
+        SnippetOptions {
+            field: "body".to_string(),
+            max_num_chars: 150,
+            html: false,
+        }
+    }
+}
+
+/// 하이라이트 구간 (UTF-16 오프셋, Dart `String` 인덱스와 동일)
+#[derive(Clone, Debug)]
+pub struct HighlightRange {
+    pub start: u32,
+    pub end: u32,
+}
+
+/// 검색어가 매칭된 본문 조각
+#[derive(Clone, Debug)]
+pub struct SearchSnippet {
+    pub fragment: String,
+    pub highlights: Vec<HighlightRange>,
+    pub html: Option<String>,
+}
+
+/// 퍼지(오타 허용) 검색 옵션
+#[derive(Clone, Debug)]
+pub struct FuzzyOptions {
+    /// 레벤슈타인 거리 (1 ~ 2)
+    pub distance: u8,
+    /// true 이면 인접 문자 교환(transposition)을 거리 1로 계산합니다
+    pub transposition: bool,
+    /// 앞에서부터 정확히 일치해야 하는 글자 수
+    pub prefix_length: usize,
+}
+
+impl Default for FuzzyOptions {
+    fn default() -> Self {
+        FuzzyOptions {
+            distance: 1,
+            transposition: true,
+            prefix_length: 1,
+        }
+    }
+}
+
+/// 검색 옵션
+#[derive(Clone, Debug)]
+pub struct SearchOptions {
+    pub limit: usize,
+    pub snippet: Option<SnippetOptions>,
+    /// 형태소 분석 필드(title, body)의 검색어에 퍼지 매칭을 추가합니다
+    pub fuzzy: Option<FuzzyOptions>,
+    /// 검색어와 색인어를 자모로 풀어 비교하는 퍼지 매칭을 추가합니다
+    /// (예: "공헝" -> "공항", 거리와 prefix_length 는 자모 수 기준)
+    pub jamo_fuzzy: Option<FuzzyOptions>,
+    /// true 이면 쿼리 문법 오류가 있어도 해석 가능한 부분으로 검색하고
+    /// 오류는 `SearchResponse::diagnostics` 로 반환합니다
+    pub lenient: bool,
+    /// 정렬 키 (비어 있으면 BM25 점수 순)
+    pub sort: Vec<SortKey>,
+    /// 검색 결과 전체(limit 와 무관)에 대해 함께 계산할 집계
+    pub aggregations: Vec<AggregationRequest>,
+    /// 이 카테고리 경로와 그 하위 카테고리의 문서만 검색합니다 (예: "/travel/asia")
+    pub category_filter: Option<String>,
+    /// 바로 아래 하위 카테고리별 문서 수를 셀 상위 카테고리 경로 (루트는 "/")
+    pub category_counts: Vec<String>,
+    /// 좌표(기본값 `metadata.location`, `configure_location_field` 로 변경)가 영역 또는 반경 안에 있는 문서만 검색합니다
+    pub geo_filter: Option<GeoFilter>,
+    /// 이 필드 값마다 가장 앞선 문서 하나만 남깁니다 (예: `metadata.city`, 값이 없는 문서는 모두 남깁니다)
+    pub collapse: Option<String>,
+    /// 필드 값별 그룹의 상위 문서와 문서 수를 `SearchResponse::groups` 로 함께 반환합니다
+    pub group_by: Option<GroupByOptions>,
+    /// BM25 점수에 곱할 보정 (최신성 감쇠, 조회수 부스트, 고정 순위)
+    pub score_modifiers: Vec<ScoreModifier>,
+    /// 결과에 담을 저장 필드 (목록 화면처럼 일부만 필요할 때 FFI 로 넘기는 양을 줄입니다)
+    pub fields: FieldSelection,
+    /// 결과 본문을 이 글자 수까지만 반환합니다 (스니펫에는 영향 없음)
+    pub body_max_chars: Option<usize>,
+}
+
+impl Default for SearchOptions {
+    fn default() -> Self {
+        SearchOptions {
+            limit: 10,
+            snippet: None,
+            fuzzy: None,
+            jamo_fuzzy: None,
+            lenient: false,
+            sort: Vec::new(),
+            aggregations: Vec::new(),
+            category_filter: None,
+            category_counts: Vec::new(),
+            geo_filter: None,
+            collapse: None,
+            group_by: None,
+            score_modifiers: Vec::new(),
+            fields: FieldSelection::All,
+            body_max_chars: None,
+        }
+    }
+}
+
+/// 관대한(lenient) 쿼리 파싱 중 발견된 문제
+#[derive(Clone, Debug)]
+pub struct QueryDiagnostic {
+    /// 쿼리 문자열에서의 위치 (UTF-16 오프셋, 위치를 알 수 없으면 None)
+    pub position: Option<u32>,
+    pub message: String,
+}
+
+/// 옵션 검색 응답
+#[derive(Clone, Debug)]
+pub struct SearchResponse {
+    pub hits: Vec<SearchHit>,
+    pub diagnostics: Vec<QueryDiagnostic>,
+    /// `SearchOptions::aggregations` 의 요청 순서대로 집계 결과
+    pub aggregations: Vec<AggregationResult>,
+    /// `SearchOptions::category_counts` 의 요청 순서대로 하위 카테고리 문서 수
+    pub category_counts: Vec<CategoryCounts>,
+    /// `SearchOptions::group_by` 의 그룹 결과
+    pub groups: Vec<SearchGroup>,
+}
+
+/// 그룹별 검색 결과
+#[derive(Clone, Debug)]
+pub struct SearchGroup {
+    /// 그룹 필드 값 (값이 없는 문서는 문서마다 따로 key 가 None 인 그룹이 됩니다)
+    pub key: Option<String>,
+    /// 그룹에 속한 매칭 문서 수
+    pub count: u64,
+    pub hits: Vec<SearchHit>,
+}
+
+/// 옵션 검색 결과 (SearchResult + 스니펫)
+#[derive(Clone, Debug)]
+pub struct SearchHit {
+    pub id: String,
+    pub title: String,
+    pub body: String,
+    pub score: f32,
+    pub metadata: String, // JSON string
+    pub snippet: Option<SearchSnippet>,
+    /// `SearchOptions::sort` 의 키 순서대로 이 문서의 정렬 값
+    pub sort_values: Vec<SortValue>,
+    /// 처음 추가된 시각 (Unix 시간 밀리초, 수정해도 유지)
+    pub created_at: i64,
+    /// 마지막으로 추가/수정된 시각 (Unix 시간 밀리초)
+    pub updated_at: i64,
+    /// 기준 좌표까지의 거리 (km, 거리 정렬 키 또는 반경 필터의 중심 기준, 좌표가 없으면 None)
+    pub distance_km: Option<f64>,
 }
 
 // 인덱스를 관리하는 전역 상태
@@ -88,19 +296,231 @@ struct SearchIndex {
     // N-gram 필드 추가 (부분 검색용)
     title_ngram_field: Field,
     body_ngram_field: Field,
+    // 제목 접두어 필드 (자동완성용)
+    title_prefix_field: Field,
+    category_field: Field,
+    dates_field: Field,
+    created_at_field: Field,
+    updated_at_field: Field,
+    lat_field: Field,
+    lon_field: Field,
+    // 초성 필드 (초성 검색용)
+    chosung_field: Field,
+    // 제목 단어별 자모 접두어 필드 (입력 중 검색용)
+    title_jamo_field: Field,
+    // 제목/본문 색인어를 자모로 풀어 쓴 필드 (자모 단위 퍼지 검색용)
+    text_jamo_field: Field,
+    // 제목/본문의 한자를 한글 독음으로 바꿔 색인한 필드 (한자 <-> 한글 검색용)
+    text_hangul_field: Field,
+    // 날짜로 인식하여 dates 필드에 색인할 메타데이터 경로
+    date_paths: Vec<String>,
+    // 좌표를 읽어 lat/lon 필드에 색인할 메타데이터 경로
+    location_path: String,
+    // 초성 필드를 채울 원본 필드 (제목, 본문)
+    chosung_title: bool,
+    chosung_body: bool,
+    // 한자 독음 필드를 채우고 검색할지 여부
+    hanja_readings: bool,
+    // 필드별 이체자 통일 설정 (분석기와 공유)
+    variant_folding: SharedVariantFolding,
+    // 제목/본문의 일본어 읽기(가타카나, 로마자) N-gram 필드 (가나/로마자 검색용)
+    reading_field: Field,
+    // 제목/본문의 중국어 병음(전체 음절, 음절 첫 글자) N-gram 필드 (병음 검색용)
+    pinyin_field: Field,
+    // 일본어 읽기, 중국어 병음 필드 설정 (분석기와 공유)
+    readings: SharedReadingOptions,
 }
 
-/// 검색 인덱스를 초기화합니다
-#[flutter_rust_bridge::frb(sync)]
-pub fn initialize_search_index(dictionary_type: DictionaryType) -> Result<String, String> {
+impl SearchIndex {
+    // 인덱스 스키마에서 필드를 찾아 검색 인덱스 상태를 만듭니다
+    fn new(
+        index: Index,
+        variant_folding: SharedVariantFolding,
+        readings: SharedReadingOptions,
+    ) -> Result<Self, String> {
+        let schema = index.schema();
+        let defaults = IndexSettings::default();
+        let field = |name: &str| {
+            schema.get_field(name).map_err(|_| {
+                format!(
+                    "인덱스 스키마에 '{}' 필드가 없습니다. 인덱스를 다시 생성하세요.",
+                    name
+                )
+            })
+        };
+
+        Ok(SearchIndex {
+            id_field: field("id")?,
+            title_field: field("title")?,
+            body_field: field("body")?,
+            metadata_field: field("metadata")?,
+            title_ngram_field: field("title_ngram")?,
+            body_ngram_field: field("body_ngram")?,
+            title_prefix_field: field("title_prefix")?,
+            category_field: field("category")?,
+            dates_field: field("dates")?,
+            created_at_field: field("created_at")?,
+            updated_at_field: field("updated_at")?,
+            lat_field: field(LAT_FIELD)?,
+            lon_field: field(LON_FIELD)?,
+            chosung_field: field("chosung")?,
+            title_jamo_field: field("title_jamo")?,
+            text_jamo_field: field("text_jamo")?,
+            text_hangul_field: field("text_hangul")?,
+            date_paths: defaults.date_paths,
+            location_path: defaults.location_path,
+            chosung_title: defaults.chosung_title,
+            chosung_body: defaults.chosung_body,
+            hanja_readings: defaults.hanja_readings,
+            variant_folding,
+            reading_field: field("reading")?,
+            pinyin_field: field("pinyin")?,
+            readings,
+            schema: schema.clone(),
+            index,
+        })
+    }
+
+    // 인덱스와 함께 저장할 설정
+    fn settings(&self) -> IndexSettings {
+        let readings = self.readings.read().unwrap();
+        let variant_folding = self.variant_folding.read().unwrap();
+        IndexSettings {
+            schema_version: SCHEMA_VERSION,
+            date_paths: self.date_paths.clone(),
+            location_path: self.location_path.clone(),
+            chosung_title: self.chosung_title,
+            chosung_body: self.chosung_body,
+            hanja_readings: self.hanja_readings,
+            japanese_readings: readings.japanese,
+            romaji: readings.romaji,
+            pinyin: readings.pinyin,
+            variant_fields: variant_folding.fields(),
+            variant_mappings: variant_folding.mappings().to_vec(),
+        }
+    }
+
+    // 인덱스에 저장된 설정을 되살립니다
+    fn apply_settings(&mut self, settings: IndexSettings) -> Result<(), String> {
+        let mut readings = self.readings.write().unwrap();
+        if settings.japanese_readings && !readings.japanese_dictionary {
+            return Err("일본어 읽기 검색을 켠 인덱스는 일본어 사전(IPADIC, UniDic)으로 열어야 합니다.".to_string());
+        }
+        if settings.pinyin && !readings.chinese_dictionary {
+            return Err("병음 검색을 켠 인덱스는 중국어 사전(CC-CEDICT)으로 열어야 합니다.".to_string());
+        }
+        readings.japanese = settings.japanese_readings;
+        readings.romaji = settings.romaji;
+        readings.pinyin = settings.pinyin;
+        drop(readings);
+        self.variant_folding
+            .write()
+            .unwrap()
+            .configure(settings.variant_fields, settings.variant_mappings)?;
+
+        self.date_paths = settings.date_paths;
+        self.location_path = settings.location_path;
+        self.chosung_title = settings.chosung_title;
+        self.chosung_body = settings.chosung_body;
+        self.hanja_readings = settings.hanja_readings;
+        Ok(())
+    }
+
+    // 설정을 바꾸고 인덱스에 기록합니다 (기록에 실패하면 이전 설정으로 되돌립니다)
+    fn update_settings(&mut self, settings: IndexSettings) -> Result<(), String> {
+        let previous = self.settings();
+        self.apply_settings(settings)?;
+        if let Err(error) = self.save_settings() {
+            self.apply_settings(previous)?;
+            return Err(error);
+        }
+        Ok(())
+    }
+
+    // 인덱스 설정을 payload 로 함께 기록하며 커밋합니다 (payload 없이 커밋하면 기록이 지워집니다)
+    fn commit(&self, index_writer: &mut IndexWriter) -> Result<(), String> {
+        let mut prepared_commit = index_writer.prepare_commit().map_err(|e| e.to_string())?;
+        prepared_commit.set_payload(&self.settings().to_payload());
+        prepared_commit.commit().map_err(|e| e.to_string())?;
+        Ok(())
+    }
+
+    // 바뀐 설정만 기록하는 빈 커밋을 만듭니다
+    fn save_settings(&self) -> Result<(), String> {
+        let mut index_writer = self.index.writer(50_000_000).map_err(|e| e.to_string())?;
+        self.commit(&mut index_writer)
+    }
+
+    // 입력 값으로 색인할 문서를 만듭니다
+    // (N-gram, 자동완성, 초성, 자모, 한자 독음, 일본어 읽기, 병음, 카테고리, 날짜, 시스템 시각 필드 포함)
+    fn build_document(
+        &self,
+        id: String,
+        title: &str,
+        body: &str,
+        metadata: JsonValue,
+        created_at: DateTime,
+        updated_at: DateTime,
+    ) -> TantivyDocument {
+        let categories = metadata_categories(&metadata);
+        let dates = metadata_dates(&metadata, &self.date_paths);
+        let location = metadata_location(&metadata, &self.location_path);
+        let mut document = doc!(
+            self.id_field => id,
+            self.title_field => title,
+            self.body_field => body,
+            self.metadata_field => metadata,
+            self.title_ngram_field => title,
+            self.body_ngram_field => body,
+            self.title_prefix_field => title,
+            self.created_at_field => created_at,
+            self.updated_at_field => updated_at
+        );
+        for category in categories {
+            document.add_facet(self.category_field, category);
+        }
+        if !dates.is_empty() {
+            document.add_object(self.dates_field, dates);
+        }
+        if let Some(location) = location {
+            document.add_f64(self.lat_field, location.lat);
+            document.add_f64(self.lon_field, location.lon);
+        }
+        document.add_text(self.text_jamo_field, title);
+        document.add_text(self.text_jamo_field, body);
+        for word in jamo_words(title) {
+            document.add_text(self.title_jamo_field, word);
+        }
+        if self.hanja_readings {
+            document.add_text(self.text_hangul_field, title);
+            document.add_text(self.text_hangul_field, body);
+        }
+        let readings = self.readings.read().unwrap();
+        if readings.japanese {
+            document.add_text(self.reading_field, title);
+            document.add_text(self.reading_field, body);
+        }
+        if readings.pinyin {
+            document.add_text(self.pinyin_field, title);
+            document.add_text(self.pinyin_field, body);
+        }
+        if self.chosung_title {
+            document.add_text(self.chosung_field, chosung(title));
+        }
+        if self.chosung_body {
+            document.add_text(self.chosung_field, chosung(body));
+        }
+        document
+    }
+}
+
+// 검색 인덱스 스키마를 생성합니다
+fn build_schema(tokenizer_name: &str) -> Schema {
     // create schema builder
     let mut schema_builder = Schema::builder();
 
-    // 선택한 사전에 맞는 토크나이저 이름 가져오기
-    let tokenizer_name = dictionary_type.to_tokenizer_name();
-
-    // add id field (UUID 문자열)
-    let id = schema_builder.add_text_field(
+    // add id field (UUID 문자열, ID 만 반환하는 검색을 위해 fast field)
+    schema_builder.add_text_field(
         "id",
         TextOptions::default()
             .set_indexing_options(
@@ -108,59 +528,61 @@ pub fn initialize_search_index(dictionary_type: DictionaryType) -> Result<String
                     .set_tokenizer("raw")
                     .set_index_option(IndexRecordOption::Basic),
             )
-            .set_stored(),
+            .set_stored()
+            .set_fast(Some("raw")),
     );
 
-    // add title field (형태소 분석)
-    let title = schema_builder.add_text_field(
+    // add title field (형태소 분석, 자동완성에서 같은 제목을 묶기 위해 fast field 로도 저장)
+    schema_builder.add_text_field(
         "title",
         TextOptions::default()
             .set_indexing_options(
                 TextFieldIndexing::default()
-                    .set_tokenizer(tokenizer_name)
+                    .set_tokenizer(&field_tokenizer_name(tokenizer_name, "title"))
                     .set_index_option(IndexRecordOption::WithFreqsAndPositions),
             )
-            .set_stored(),
+            .set_stored()
+            .set_fast(Some("raw")),
     );
 
     // add body field (형태소 분석)
-    let body = schema_builder.add_text_field(
+    schema_builder.add_text_field(
         "body",
         TextOptions::default()
             .set_indexing_options(
                 TextFieldIndexing::default()
-                    .set_tokenizer(tokenizer_name)
+                    .set_tokenizer(&field_tokenizer_name(tokenizer_name, "body"))
                     .set_index_option(IndexRecordOption::WithFreqsAndPositions),
             )
             .set_stored(),
     );
 
     // add title_ngram field (부분 검색용)
-    let title_ngram = schema_builder.add_text_field(
+    schema_builder.add_text_field(
         "title_ngram",
         TextOptions::default()
             .set_indexing_options(
                 TextFieldIndexing::default()
-                    .set_tokenizer("ngram_tokenizer")
+                    .set_tokenizer(&field_tokenizer_name("ngram_tokenizer", "title_ngram"))
                     .set_index_option(IndexRecordOption::WithFreqsAndPositions),
             )
             .set_stored(),
     );
 
     // add body_ngram field (부분 검색용)
-    let body_ngram = schema_builder.add_text_field(
+    schema_builder.add_text_field(
         "body_ngram",
         TextOptions::default()
             .set_indexing_options(
                 TextFieldIndexing::default()
-                    .set_tokenizer("ngram_tokenizer")
+                    .set_tokenizer(&field_tokenizer_name("ngram_tokenizer", "body_ngram"))
                     .set_index_option(IndexRecordOption::WithFreqsAndPositions),
             )
             .set_stored(),
     );
 
-    // add metadata field (JSON Object)
-    let metadata = schema_builder.add_json_field(
+    // add metadata field (JSON Object, 범위 검색을 위해 fast field 로도 저장)
+    schema_builder.add_json_field(
         "metadata",
         JsonObjectOptions::default()
             .set_indexing_options(
@@ -168,42 +590,236 @@ pub fn initialize_search_index(dictionary_type: DictionaryType) -> Result<String
                     .set_tokenizer("raw")
                     .set_index_option(IndexRecordOption::Basic),
             )
-            .set_stored(),
+            .set_stored()
+            .set_fast(Some("raw")),
+    );
+
+    // add title_prefix field (자동완성용 edge n-gram, 저장하지 않음)
+    schema_builder.add_text_field(
+        "title_prefix",
+        TextOptions::default().set_indexing_options(
+            TextFieldIndexing::default()
+                .set_tokenizer("edge_ngram_tokenizer")
+                .set_index_option(IndexRecordOption::Basic),
+        ),
+    );
+
+    // add category field (계층형 카테고리, 메타데이터의 "category" 경로로 채움)
+    schema_builder.add_facet_field("category", FacetOptions::default());
+
+    // add created_at / updated_at fields (시스템이 관리하는 추가/수정 시각)
+    let timestamp_options = DateOptions::default()
+        .set_indexed()
+        .set_stored()
+        .set_fast()
+        .set_precision(DateTimePrecision::Milliseconds);
+    schema_builder.add_date_field("created_at", timestamp_options.clone());
+    schema_builder.add_date_field("updated_at", timestamp_options);
+
+    // add lat / lon fields (메타데이터 좌표, 기본값 "location", 거리 계산용 fast field)
+    let coordinate_options = NumericOptions::default()
+        .set_indexed()
+        .set_stored()
+        .set_fast();
+    schema_builder.add_f64_field(LAT_FIELD, coordinate_options.clone());
+    schema_builder.add_f64_field(LON_FIELD, coordinate_options);
+
+    // add dates field (메타데이터의 날짜 값을 같은 경로의 DateTime 으로 색인, 저장하지 않음)
+    schema_builder.add_json_field(
+        "dates",
+        JsonObjectOptions::default()
+            .set_indexing_options(
+                TextFieldIndexing::default()
+                    .set_tokenizer("raw")
+                    .set_index_option(IndexRecordOption::Basic),
+            )
+            .set_fast(None),
+    );
+
+    // add chosung field (제목/본문의 초성 N-gram과 위치, 초성 검색용, 저장하지 않음)
+    schema_builder.add_text_field(
+        "chosung",
+        TextOptions::default().set_indexing_options(
+            TextFieldIndexing::default()
+                .set_tokenizer("chosung_ngram_tokenizer")
+                .set_index_option(IndexRecordOption::WithFreqsAndPositions),
+        ),
+    );
+
+    // add reading field (제목/본문의 일본어 읽기 N-gram과 위치, 가나/로마자 검색용, 저장하지 않음)
+    schema_builder.add_text_field(
+        "reading",
+        TextOptions::default().set_indexing_options(
+            TextFieldIndexing::default()
+                .set_tokenizer("reading_tokenizer")
+                .set_index_option(IndexRecordOption::WithFreqsAndPositions),
+        ),
+    );
+
+    // add pinyin field (제목/본문의 성조 없는 병음 N-gram과 위치, 병음 검색용, 저장하지 않음)
+    schema_builder.add_text_field(
+        "pinyin",
+        TextOptions::default().set_indexing_options(
+            TextFieldIndexing::default()
+                .set_tokenizer("pinyin_tokenizer")
+                .set_index_option(IndexRecordOption::WithFreqsAndPositions),
+        ),
+    );
+
+    // add title_jamo field (제목 단어마다 자모로 풀어 쓴 edge n-gram, 입력 중 검색용, 저장하지 않음)
+    schema_builder.add_text_field(
+        "title_jamo",
+        TextOptions::default().set_indexing_options(
+            TextFieldIndexing::default()
+                .set_tokenizer("jamo_edge_ngram_tokenizer")
+                .set_index_option(IndexRecordOption::WithFreqs),
+        ),
+    );
+
+    // add text_jamo field (제목/본문 형태소를 자모로 풀어 씀, 자모 단위 퍼지 검색용, 저장하지 않음)
+    schema_builder.add_text_field(
+        "text_jamo",
+        TextOptions::default().set_indexing_options(
+            TextFieldIndexing::default()
+                .set_tokenizer(&jamo_tokenizer_name(tokenizer_name))
+                .set_index_option(IndexRecordOption::WithFreqs),
+        ),
+    );
+
+    // add text_hangul field (제목/본문의 한자를 한글 독음으로 바꿔 형태소 분석, 저장하지 않음)
+    schema_builder.add_text_field(
+        "text_hangul",
+        TextOptions::default().set_indexing_options(
+            TextFieldIndexing::default()
+                .set_tokenizer(&hangul_tokenizer_name(tokenizer_name))
+                .set_index_option(IndexRecordOption::WithFreqsAndPositions),
+        ),
     );
 
     // build schema
-    let schema = schema_builder.build();
+    schema_builder.build()
+}
 
-    // create index on memory
-    let index = Index::create_in_ram(schema.clone());
+// 형태소 분석 결과를 자모로 풀어 쓰는 분석기 이름
+fn jamo_tokenizer_name(tokenizer_name: &str) -> String {
+    format!("{}_jamo", tokenizer_name)
+}
+
+// 한자를 한글 독음으로 바꾼 뒤 형태소 분석하는 분석기 이름
+fn hangul_tokenizer_name(tokenizer_name: &str) -> String {
+    format!("{}_hangul", tokenizer_name)
+}
+
+// 필드마다 따로 등록하는 분석기 이름 (이체자 통일을 필드별로 켤 수 있도록)
+fn field_tokenizer_name(tokenizer_name: &str, field_name: &str) -> String {
+    format!("{}_{}", tokenizer_name, field_name)
+}
 
+// 인덱스에 N-gram 토크나이저와 선택한 사전의 Lindera 토크나이저를 등록합니다
+fn register_tokenizers(
+    index: &Index,
+    dictionary_type: &DictionaryType,
+    variant_folding: &SharedVariantFolding,
+    readings: &SharedReadingOptions,
+) -> Result<(), String> {
     // Register N-gram tokenizer (2-gram ~ 3-gram for Korean, prefix_only=false)
     index
         .tokenizers()
         .register("ngram_tokenizer", NgramTokenizer::new(2, 3, false).unwrap());
 
+    // Register edge N-gram tokenizer (제목 앞부분 1 ~ MAX_PREFIX_CHARS 글자, 소문자 변환)
+    index.tokenizers().register(
+        "edge_ngram_tokenizer",
+        TextAnalyzer::builder(NgramTokenizer::prefix_only(1, MAX_PREFIX_CHARS).unwrap())
+            .filter(LowerCaser)
+            .build(),
+    );
+
+    // Register chosung N-gram tokenizer (초성 문자열의 위치마다 1 ~ CHOSUNG_NGRAM_MAX 글자 조각)
+    index.tokenizers().register(
+        "chosung_ngram_tokenizer",
+        PositionalNgramTokenizer::new(CHOSUNG_NGRAM_MAX),
+    );
+
+    // Register jamo edge N-gram tokenizer (단어의 자모 앞부분 1 ~ JAMO_PREFIX_MAX 글자)
+    index.tokenizers().register(
+        "jamo_edge_ngram_tokenizer",
+        NgramTokenizer::prefix_only(1, JAMO_PREFIX_MAX).unwrap(),
+    );
+
     // Tokenizer with selected dictionary
     let mode = Mode::Normal;
     let dictionary = load_dictionary(dictionary_type.to_embedded_path()).map_err(|e| e.to_string())?;
     let user_dictionary = None;
     let segmenter = Segmenter::new(mode, dictionary, user_dictionary);
-    let tokenizer = LinderaTokenizer::from_segmenter(segmenter);
+    let tokenizer = LinderaTokenizer::from_segmenter(segmenter.clone());
+
+    // register reading tokenizer (사전의 읽기를 가타카나/로마자로 바꾼 위치마다 1 ~ READING_NGRAM_MAX 글자 조각)
+    index.tokenizers().register(
+        "reading_tokenizer",
+        ReadingTokenizer::new(Tokenizer::new(segmenter.clone()), readings),
+    );
+    // register pinyin tokenizer (사전의 병음을 이어 붙인 위치마다 1 ~ READING_NGRAM_MAX 글자 조각)
+    index.tokenizers().register(
+        "pinyin_tokenizer",
+        PinyinTokenizer::new(Tokenizer::new(segmenter)),
+    );
 
-    // register Lindera tokenizer
-    index.tokenizers().register(tokenizer_name, tokenizer);
+    // register Lindera tokenizer (+ 색인어를 자모로 풀어 쓰는 분석기)
+    index.tokenizers().register(
+        &jamo_tokenizer_name(dictionary_type.to_tokenizer_name()),
+        TextAnalyzer::builder(tokenizer.clone())
+            .filter(JamoDecomposer)
+            .build(),
+    );
+    // register Lindera tokenizer (+ 한자를 한글 독음으로 바꾼 뒤 분석하는 분석기)
+    index.tokenizers().register(
+        &hangul_tokenizer_name(dictionary_type.to_tokenizer_name()),
+        TextAnalyzer::builder(CharFilterTokenizer::new(
+            tokenizer.clone(),
+            Arc::new(hanja_to_hangul),
+        ))
+        .build(),
+    );
+    // register title/body/N-gram field analyzers (이체자 통일 문자 필터 + 기본 분석기)
+    for field_name in FOLDABLE_FIELDS {
+        let filter = folding_filter(variant_folding, field_name);
+        let (name, analyzer) = if field_name.ends_with("_ngram") {
+            (
+                field_tokenizer_name("ngram_tokenizer", field_name),
+                TextAnalyzer::from(CharFilterTokenizer::new(
+                    NgramTokenizer::new(2, 3, false).unwrap(),
+                    filter,
+                )),
+            )
+        } else {
+            (
+                field_tokenizer_name(dictionary_type.to_tokenizer_name(), field_name),
+                TextAnalyzer::from(CharFilterTokenizer::new(tokenizer.clone(), filter)),
+            )
+        };
+        index.tokenizers().register(&name, analyzer);
+    }
+    index.tokenizers().register(dictionary_type.to_tokenizer_name(), tokenizer);
+
+    Ok(())
+}
+
+/// 검색 인덱스를 초기화합니다
+#[flutter_rust_bridge::frb(sync)]
+pub fn initialize_search_index(dictionary_type: DictionaryType) -> Result<String, String> {
+    // 선택한 사전에 맞는 토크나이저 이름으로 스키마 생성
+    let schema = build_schema(dictionary_type.to_tokenizer_name());
+
+    // create index on memory
+    let index = Index::create_in_ram(schema);
+    let variant_folding = SharedVariantFolding::default();
+    let readings = reading_options(&dictionary_type);
+    register_tokenizers(&index, &dictionary_type, &variant_folding, &readings)?;
 
     // 전역 상태에 저장
     let mut search_index = SEARCH_INDEX.lock().unwrap();
-    *search_index = Some(SearchIndex {
-        index,
-        schema,
-        id_field: id,
-        title_field: title,
-        body_field: body,
-        metadata_field: metadata,
-        title_ngram_field: title_ngram,
-        body_ngram_field: body_ngram,
-    });
+    *search_index = Some(SearchIndex::new(index, variant_folding, readings)?);
 
     Ok("검색 인덱스가 초기화되었습니다.".to_string())
 }
@@ -258,423 +874,1320 @@ pub fn tokenize_text_detailed(
 }
 
 /// 디스크에 인덱스를 생성하거나 로드합니다
+///
+/// configure_* 로 바꾼 설정은 인덱스에 함께 저장되어 다시 열 때 복원됩니다.
+/// 다른 스키마 버전으로 만든 인덱스(이전 버전에서 만든 인덱스 포함)는 열지 않고 오류를 반환합니다.
 #[flutter_rust_bridge::frb(sync)]
 pub fn initialize_search_index_with_path(dictionary_type: DictionaryType, index_path: String) -> Result<String, String> {
-    // create schema builder
-    let mut schema_builder = Schema::builder();
-
-    // 선택한 사전에 맞는 토크나이저 이름 가져오기
-    let tokenizer_name = dictionary_type.to_tokenizer_name();
-
-    // add id field (UUID 문자열)
-    let id = schema_builder.add_text_field(
-        "id",
-        TextOptions::default()
-            .set_indexing_options(
-                TextFieldIndexing::default()
-                    .set_tokenizer("raw")
-                    .set_index_option(IndexRecordOption::Basic),
-            )
-            .set_stored(),
-    );
-
-    // add title field (형태소 분석)
-    let title = schema_builder.add_text_field(
-        "title",
-        TextOptions::default()
-            .set_indexing_options(
-                TextFieldIndexing::default()
-                    .set_tokenizer(tokenizer_name)
-                    .set_index_option(IndexRecordOption::WithFreqsAndPositions),
-            )
-            .set_stored(),
-    );
-
-    // add body field (형태소 분석)
-    let body = schema_builder.add_text_field(
-        "body",
-        TextOptions::default()
-            .set_indexing_options(
-                TextFieldIndexing::default()
-                    .set_tokenizer(tokenizer_name)
-                    .set_index_option(IndexRecordOption::WithFreqsAndPositions),
-            )
-            .set_stored(),
-    );
-
-    // add title_ngram field (부분 검색용)
-    let title_ngram = schema_builder.add_text_field(
-        "title_ngram",
-        TextOptions::default()
-            .set_indexing_options(
-                TextFieldIndexing::default()
-                    .set_tokenizer("ngram_tokenizer")
-                    .set_index_option(IndexRecordOption::WithFreqsAndPositions),
-            )
-            .set_stored(),
-    );
-
-    // add body_ngram field (부분 검색용)
-    let body_ngram = schema_builder.add_text_field(
-        "body_ngram",
-        TextOptions::default()
-            .set_indexing_options(
-                TextFieldIndexing::default()
-                    .set_tokenizer("ngram_tokenizer")
-                    .set_index_option(IndexRecordOption::WithFreqsAndPositions),
-            )
-            .set_stored(),
-    );
-
-    // add metadata field (JSON Object)
-    let metadata = schema_builder.add_json_field(
-        "metadata",
-        JsonObjectOptions::default()
-            .set_indexing_options(
-                TextFieldIndexing::default()
-                    .set_tokenizer("raw")
-                    .set_index_option(IndexRecordOption::Basic),
-            )
-            .set_stored(),
-    );
-
-    // build schema
-    let schema = schema_builder.build();
+    // 선택한 사전에 맞는 토크나이저 이름으로 스키마 생성
+    let schema = build_schema(dictionary_type.to_tokenizer_name());
 
     // 디스크 경로가 존재하는지 확인
     let path = Path::new(&index_path);
-    let index = if path.exists() {
-        // 기존 인덱스 로드
-        Index::open_in_dir(path).map_err(|e| format!("인덱스 로드 실패: {}", e))?
+    let (index, settings) = if path.exists() {
+        // 기존 인덱스 로드 (스키마 버전이 다르면 필드가 맞지 않으므로 오류)
+        let index = Index::open_in_dir(path).map_err(|e| format!("인덱스 로드 실패: {}", e))?;
+        let metas = index.load_metas().map_err(|e| format!("인덱스 로드 실패: {}", e))?;
+        let settings = IndexSettings::from_payload(metas.payload.as_deref())?;
+        (index, Some(settings))
     } else {
         // 새 인덱스 생성
         std::fs::create_dir_all(path).map_err(|e| format!("디렉토리 생성 실패: {}", e))?;
-        Index::create_in_dir(path, schema.clone()).map_err(|e| format!("인덱스 생성 실패: {}", e))?
+        let index =
+            Index::create_in_dir(path, schema).map_err(|e| format!("인덱스 생성 실패: {}", e))?;
+        (index, None)
     };
-
-    // Register N-gram tokenizer (2-gram ~ 3-gram for Korean, prefix_only=false)
-    index
-        .tokenizers()
-        .register("ngram_tokenizer", NgramTokenizer::new(2, 3, false).unwrap());
-
-    // Tokenizer with selected dictionary
-    let mode = Mode::Normal;
-    let dictionary = load_dictionary(dictionary_type.to_embedded_path()).map_err(|e| e.to_string())?;
-    let user_dictionary = None;
-    let segmenter = Segmenter::new(mode, dictionary, user_dictionary);
-    let tokenizer = LinderaTokenizer::from_segmenter(segmenter);
-
-    // register Lindera tokenizer
-    index.tokenizers().register(tokenizer_name, tokenizer);
+    let variant_folding = SharedVariantFolding::default();
+    let readings = reading_options(&dictionary_type);
+    register_tokenizers(&index, &dictionary_type, &variant_folding, &readings)?;
+    let mut new_index = SearchIndex::new(index, variant_folding, readings)?;
+    match settings {
+        Some(settings) => new_index.apply_settings(settings)?,
+        // 문서를 추가하기 전에 다시 열어도 버전을 확인할 수 있도록 바로 기록합니다
+        None => new_index.save_settings()?,
+    }
 
     // 전역 상태에 저장
     let mut search_index = SEARCH_INDEX.lock().unwrap();
-    *search_index = Some(SearchIndex {
-        index,
-        schema,
-        id_field: id,
-        title_field: title,
-        body_field: body,
-        metadata_field: metadata,
-        title_ngram_field: title_ngram,
-        body_ngram_field: body_ngram,
-    });
+    *search_index = Some(new_index);
 
     Ok(format!("검색 인덱스가 초기화되었습니다. (경로: {})", index_path))
 }
 
-/// 샘플 문서를 인덱싱합니다
+/// 날짜로 인식할 메타데이터 경로를 설정합니다 (기본값: createdAt, updatedAt, date)
+///
+/// 값이 RFC 3339 또는 `YYYY-MM-DD` 문자열이면 `dates.<경로>` 에 DateTime 으로 색인되어
+/// 범위 검색, 정렬, 날짜 히스토그램에 사용할 수 있습니다.
+/// 설정 이후에 추가하거나 수정한 문서부터 적용됩니다.
 #[flutter_rust_bridge::frb(sync)]
-pub fn index_sample_documents() -> Result<String, String> {
+pub fn configure_date_fields(paths: Vec<String>) -> Result<String, String> {
     let mut search_index = SEARCH_INDEX.lock().unwrap();
     let search_index = search_index.as_mut().ok_or(
         "검색 인덱스가 초기화되지 않았습니다. initialize_search_index()를 먼저 호출하세요.",
     )?;
 
-    let id = search_index.id_field;
-    let title = search_index.title_field;
-    let body = search_index.body_field;
-    let metadata = search_index.metadata_field;
-    let title_ngram = search_index.title_ngram_field;
-    let body_ngram = search_index.body_ngram_field;
-
-    // create index writer
-    let mut index_writer = search_index
-        .index
-        .writer(50_000_000)
-        .map_err(|e| e.to_string())?;
+    if let Some(path) = paths.iter().find(|path| path.split('.').any(str::is_empty)) {
+        return Err(format!("잘못된 메타데이터 경로입니다: {}", path));
+    }
+    search_index.update_settings(IndexSettings {
+        date_paths: paths,
+        ..search_index.settings()
+    })?;
+
+    Ok(format!(
+        "날짜 필드가 설정되었습니다: {}",
+        search_index.date_paths.join(", ")
+    ))
+}
 
-    // add documents with metadata
-    index_writer.add_document(doc!(
-        id => generate_uuid(),
-        title => "나리타 국제공항",
-        body => "나리타 국제공항(일본어: 成田国際空港, 영어: Narita International Airport, IATA: NRT, ICAO: RJAA)은 일본 지바현 나리타시에 위치한 국제공항으로, 도쿄도 도심에서 동북쪽으로 약 62km 떨어져 있다.",
-        metadata => serde_json::json!({"country": "일본", "iata": "NRT", "icao": "RJAA", "city": "나리타"}),
-        title_ngram => "나리타 국제",
-        body_ngram => "나리타 국제공항"
-    )).map_err(|e| e.to_string())?;
-
-    index_writer.add_document(doc!(
-        id => generate_uuid(),
-        title => "도쿄 국제공항",
-        body => "도쿄국제공항(일본어: 東京国際空港、とうきょうこくさいくうこう, 영어: Tokyo International Airport)은 일본 도쿄도 오타구에 있는 공항이다. 보통 이 일대의 옛 지명을 본뜬 하네다 공항(일본어: 羽田空港, 영어: Haneda Airport)이라고 불린다.",
-        metadata => serde_json::json!({"country": "일본", "iata": "HND", "icao": "RJTT", "city": "도쿄"}),
-        title_ngram => "도쿄 국제",
-        body_ngram => "도쿄국제공항"
-    )).map_err(|e| e.to_string())?;
-
-    index_writer.add_document(doc!(
-        id => generate_uuid(),
-        title => "간사이 국제공항",
-        body => "간사이 국제공항(일본어: 関西国際空港, IATA: KIX, ICAO: RJBB)은 일본 오사카부 오사카 만에 조성된 인공섬에 위치한 일본의 공항으로, 대한민국의 인천국제공항보다 6년 반 앞선 1994년 9월 4일에 개항했다.",
-        metadata => serde_json::json!({"country": "일본", "iata": "KIX", "icao": "RJBB", "city": "오사카"}),
-        title_ngram => "간사이 국제",
-        body_ngram => "간사이 국제공항"
-    )).map_err(|e| e.to_string())?;
-
-    index_writer.add_document(doc!(
-        id => generate_uuid(),
-        title => "인천국제공항",
-        body => "인천국제공항(仁川國際空港, Incheon International Airport, IATA: ICN, ICAO: RKSI)은 대한민국 인천광역시 중구 운서동에 있는 국제공항이다. 2001년 3월 29일 개항하였다.",
-        metadata => serde_json::json!({"country": "한국", "iata": "ICN", "icao": "RKSI", "city": "인천"}),
-        title_ngram => "인천국제",
-        body_ngram => "인천국제공항"
-    )).map_err(|e| e.to_string())?;
-
-    index_writer.add_document(doc!(
-        id => generate_uuid(),
-        title => "김포국제공항",
-        body => "김포국제공항(金浦國際空港, Gimpo International Airport, IATA: GMP, ICAO: RKSS)은 대한민국 서울특별시 강서구 공항동에 있는 국제공항이다. 서울 도심에서 서쪽으로 약 15km 떨어져 있다.",
-        metadata => serde_json::json!({"country": "한국", "iata": "GMP", "icao": "RKSS", "city": "서울"}),
-        title_ngram => "김포국제",
-        body_ngram => "김포국제공항"
-    )).map_err(|e| e.to_string())?;
-
-    index_writer.add_document(doc!(
-        id => generate_uuid(),
-        title => "제주국제공항",
-        body => "제주국제공항(濟州國際空港, Jeju International Airport, IATA: CJU, ICAO: RKPC)은 대한민국 제주특별자치도 제주시 용담동에 있는 국제공항이다. 한국에서 가장 많은 승객이 이용하는 공항이다.",
-        metadata => serde_json::json!({"country": "한국", "iata": "CJU", "icao": "RKPC", "city": "제주"}),
-        title_ngram => "제주국제",
-        body_ngram => "제주국제공항"
-    )).map_err(|e| e.to_string())?;
-
-    index_writer.add_document(doc!(
-        id => generate_uuid(),
-        title => "싱가포르 창이공항",
-        body => "싱가포르 창이공항(Singapore Changi Airport, IATA: SIN, ICAO: WSSS)은 싱가포르에 있는 국제공항이다. 세계적으로 유명한 허브공항이며, 최고의 서비스로 ��러 차례 수상한 바 있다.",
-        metadata => serde_json::json!({"country": "싱가포르", "iata": "SIN", "icao": "WSSS", "city": "싱가포르"}),
-        title_ngram => "싱가포르 창이",
-        body_ngram => "싱가포르 창이공항"
-    )).map_err(|e| e.to_string())?;
-
-    index_writer.add_document(doc!(
-        id => generate_uuid(),
-        title => "홍콩국제공항",
-        body => "홍콩국제공항(香港國際機場, Hong Kong International Airport, IATA: HKG, ICAO: VHHH)은 중화인민공화국 홍콩특별행정구에 있는 국제공항이다. 란타우섬 북쪽 해상의 인공섬에 위치한다.",
-        metadata => serde_json::json!({"country": "홍콩", "iata": "HKG", "icao": "VHHH", "city": "홍콩"}),
-        title_ngram => "홍콩국제",
-        body_ngram => "홍콩국제공항"
-    )).map_err(|e| e.to_string())?;
-
-    // 일본어 예제 추가
-    index_writer.add_document(doc!(
-        id => generate_uuid(),
-        title => "東京国際空港",
-        body => "東京国際空港（とうきょうこくさいくうこう）は、東京都大田区にある日本最大の空港である。通称は羽田空港。国内線・国際線ともに多くの路線を持つ重要な拠点空港である。",
-        metadata => serde_json::json!({"country": "日本", "iata": "HND", "icao": "RJTT", "city": "東京", "language": "ja"}),
-        title_ngram => "東京国際空港",
-        body_ngram => "東京国際空港"
-    )).map_err(|e| e.to_string())?;
-
-    index_writer.add_document(doc!(
-        id => generate_uuid(),
-        title => "関西国際空港",
-        body => "関西国際空港（かんさいこくさいくうこう）は、大阪府泉佐野市にある国際空港である。愛称は「関空」。大阪湾の人工島に建設され、24時間運用可能な空港として知られている。",
-        metadata => serde_json::json!({"country": "日本", "iata": "KIX", "icao": "RJBB", "city": "大阪", "language": "ja"}),
-        title_ngram => "関西国際空港",
-        body_ngram => "関西国際空港"
-    )).map_err(|e| e.to_string())?;
-
-    index_writer.add_document(doc!(
-        id => generate_uuid(),
-        title => "中部国際空港",
-        body => "中部国際空港（ちゅうぶこくさいくうこう）は、愛知県常滑市にある国際空港である。愛称はセントレア。名古屋の玄関口として、中部地方の経済発展に貢献している。",
-        metadata => serde_json::json!({"country": "日本", "iata": "NGO", "icao": "RJGG", "city": "名古屋", "language": "ja"}),
-        title_ngram => "中部国際空港",
-        body_ngram => "中部国際空港"
-    )).map_err(|e| e.to_string())?;
-
-    // 중국어 예제 추가
-    index_writer.add_document(doc!(
-        id => generate_uuid(),
-        title => "北京首都国际机场",
-        body => "北京首都国际机场是中国最繁忙的机场之一，位于北京市顺义区。作为中国国际航空的主要枢纽，连接世界各地的重要航线。机场设施完善，服务优质。",
-        metadata => serde_json::json!({"country": "中国", "iata": "PEK", "icao": "ZBAA", "city": "北京", "language": "zh"}),
-        title_ngram => "北京首都国际机场",
-        body_ngram => "北京首都国际机场"
-    )).map_err(|e| e.to_string())?;
-
-    index_writer.add_document(doc!(
-        id => generate_uuid(),
-        title => "上海浦东国际机场",
-        body => "上海浦东国际机场是中国三大门户复合枢纽之一，位于上海市浦东新区。是上海两座国际机场之一，主要服务国际航班。机场现代化程度高，吞吐量巨大。",
-        metadata => serde_json::json!({"country": "中国", "iata": "PVG", "icao": "ZSPD", "city": "上海", "language": "zh"}),
-        title_ngram => "上海浦东国际机场",
-        body_ngram => "上海浦东国际机场"
-    )).map_err(|e| e.to_string())?;
-
-    index_writer.add_document(doc!(
-        id => generate_uuid(),
-        title => "广州白云国际机场",
-        body => "广州白云国际机场位于广州市白云区，是中国三大航空枢纽之一。作为华南地区最大的交通枢纽，连接国内外众多城市。机场配套设施齐全，交通便利。",
-        metadata => serde_json::json!({"country": "中国", "iata": "CAN", "icao": "ZGGG", "city": "广州", "language": "zh"}),
-        title_ngram => "广州白云国际机场",
-        body_ngram => "广州白云国际机场"
-    )).map_err(|e| e.to_string())?;
-
-    // 한국어 추가 예제 (부분 검색 테스트용)
-    index_writer.add_document(doc!(
-        id => generate_uuid(),
-        title => "우리 할아버지",
-        body => "우리 할아버지는 항상 아버지에게 좋은 가르침을 주셨다. 할머니와 함께 시골에서 농사를 지으며 평화롭게 살고 계신다.",
-        metadata => serde_json::json!({"category": "가족", "language": "ko"}),
-        title_ngram => "우리 할아버지",
-        body_ngram => "우리 할아버지"
-    )).map_err(|e| e.to_string())?;
+/// 좌표를 읽을 메타데이터 경로를 설정합니다 (기본값: location)
+///
+/// 경로의 값이 `{"lat": 37.46, "lon": 126.44}` 형태이면 lat/lon fast field 에 색인되어
+/// 좌표 필터, 거리 정렬, 거리 계산에 사용됩니다 (예: "place.coordinates").
+/// 설정 이후에 추가하거나 수정한 문서부터 적용됩니다.
+#[flutter_rust_bridge::frb(sync)]
+pub fn configure_location_field(path: String) -> Result<String, String> {
+    let mut search_index = SEARCH_INDEX.lock().unwrap();
+    let search_index = search_index.as_mut().ok_or(
+        "검색 인덱스가 초기화되지 않았습니다. initialize_search_index()를 먼저 호출하세요.",
+    )?;
 
-    // commit
-    index_writer.commit().map_err(|e| e.to_string())?;
+    if path.split('.').any(str::is_empty) {
+        return Err(format!("잘못된 메타데이터 경로입니다: {}", path));
+    }
+    search_index.update_settings(IndexSettings {
+        location_path: path.clone(),
+        ..search_index.settings()
+    })?;
 
-    Ok("총 15개의 문서가 인덱싱되었습니다. (한국어 9개, 일본어 3개, 중국어 3개)".to_string())
+    Ok(format!("좌표 필드가 설정되었습니다: {}", path))
 }
 
-/// 문서를 검색합니다 (형태소 분석 + N-gram 부분 검색)
+/// 초성 필드를 채울 원본 필드를 설정합니다 (기본값: 제목만)
+///
+/// 자음/모음(호환용 자모)으로만 된 검색어(예: "ㅇㅊㄱㅈㄱㅎ")는 초성 필드에서 검색합니다.
+/// 설정 이후에 추가하거나 수정한 문서부터 적용됩니다.
 #[flutter_rust_bridge::frb(sync)]
-pub fn search_documents(query_str: String, limit: usize) -> Result<Vec<SearchResult>, String> {
-    let search_index = SEARCH_INDEX.lock().unwrap();
-    let search_index = search_index.as_ref().ok_or(
+pub fn configure_chosung_fields(title: bool, body: bool) -> Result<String, String> {
+    let mut search_index = SEARCH_INDEX.lock().unwrap();
+    let search_index = search_index.as_mut().ok_or(
         "검색 인덱스가 초기화되지 않았습니다. initialize_search_index()를 먼저 호출하세요.",
     )?;
 
-    let id = search_index.id_field;
-    let title = search_index.title_field;
+    search_index.update_settings(IndexSettings {
+        chosung_title: title,
+        chosung_body: body,
+        ..search_index.settings()
+    })?;
+
+    Ok(format!(
+        "초성 필드가 설정되었습니다: 제목 {}, 본문 {}",
+        title, body
+    ))
+}
+
+/// 한자 <-> 한글 검색을 켜거나 끕니다 (기본값: 끔)
+///
+/// 켜면 제목/본문의 한자를 내장된 독음 표로 한글로 바꾼 필드를 함께 색인하고,
+/// 검색어도 같은 방식으로 바꿔 검색하므로 "인천국제공항" 으로 "仁川國際空港" 을,
+/// "仁川" 으로 "인천" 을 찾을 수 있습니다. 설정 이후에 추가하거나 수정한 문서부터 적용됩니다.
+/// 독음 표는 자주 쓰는 한자 631자와 일부 지명만 담고 있어 표에 없는 한자는 바뀌지 않습니다.
+#[flutter_rust_bridge::frb(sync)]
+pub fn configure_hanja_readings(enabled: bool) -> Result<String, String> {
+    let mut search_index = SEARCH_INDEX.lock().unwrap();
+    let search_index = search_index.as_mut().ok_or(
+        "검색 인덱스가 초기화되지 않았습니다. initialize_search_index()를 먼저 호출하세요.",
+    )?;
+
+    search_index.update_settings(IndexSettings {
+        hanja_readings: enabled,
+        ..search_index.settings()
+    })?;
+
+    Ok(format!("한자 독음 검색이 설정되었습니다: {}", enabled))
+}
+
+/// 일본어 읽기(요미) 검색을 켜거나 끕니다 (기본값: 끔, IPADIC/UniDic 사전 전용)
+///
+/// 켜면 제목/본문을 형태소 분석한 사전의 읽기를 가타카나로 이어 붙여 읽기 필드에 색인하고,
+/// 가나로만 된 검색어("かんさい", "カンサイ")를 읽기 필드에서도 찾으므로 "関西国際空港" 을 찾을 수 있습니다.
+/// romaji 를 켜면 헵번식 로마자 표기도 함께 색인하여 "kansai", "tokyo" 같은 영문 검색어로도 찾습니다
+/// (장음은 구분하지 않습니다). 설정 이후에 추가하거나 수정한 문서부터 적용됩니다.
+#[flutter_rust_bridge::frb(sync)]
+pub fn configure_japanese_readings(enabled: bool, romaji: bool) -> Result<String, String> {
+    let mut search_index = SEARCH_INDEX.lock().unwrap();
+    let search_index = search_index.as_mut().ok_or(
+        "검색 인덱스가 초기화되지 않았습니다. initialize_search_index()를 먼저 호출하세요.",
+    )?;
+
+    if !search_index.readings.read().unwrap().japanese_dictionary {
+        return Err("일본어 읽기 검색은 일본어 사전(IPADIC, UniDic)에서만 사용할 수 있습니다.".to_string());
+    }
+    search_index.update_settings(IndexSettings {
+        japanese_readings: enabled,
+        romaji,
+        ..search_index.settings()
+    })?;
+
+    Ok(format!(
+        "일본어 읽기 검색이 설정되었습니다: {}, 로마자 {}",
+        enabled, romaji
+    ))
+}
+
+/// 중국어 병음 검색을 켜거나 끕니다 (기본값: 끔, CC-CEDICT 사전 전용)
+///
+/// 켜면 제목/본문을 형태소 분석한 사전의 병음에서 성조를 빼고 이어 붙인 전체 병음과
+/// 음절 첫 글자를 병음 필드에 색인하고, 영문 검색어를 병음 필드에서도 찾으므로
+/// "beijing" 이나 "bjsd" 로 "北京首都国际机场" 을 찾을 수 있습니다 (ü 는 v 로 입력합니다).
+/// 설정 이후에 추가하거나 수정한 문서부터 적용됩니다.
+#[flutter_rust_bridge::frb(sync)]
+pub fn configure_pinyin(enabled: bool) -> Result<String, String> {
+    let mut search_index = SEARCH_INDEX.lock().unwrap();
+    let search_index = search_index.as_mut().ok_or(
+        "검색 인덱스가 초기화되지 않았습니다. initialize_search_index()를 먼저 호출하세요.",
+    )?;
+
+    if !search_index.readings.read().unwrap().chinese_dictionary {
+        return Err("병음 검색은 중국어 사전(CC-CEDICT)에서만 사용할 수 있습니다.".to_string());
+    }
+    search_index.update_settings(IndexSettings {
+        pinyin: enabled,
+        ..search_index.settings()
+    })?;
+
+    Ok(format!("병음 검색이 설정되었습니다: {}", enabled))
+}
+
+/// CJK 이체자 통일을 켤 필드와 이체자 표를 설정합니다 (기본값: 모든 필드 끔)
+///
+/// 켠 필드는 색인과 검색 모두 이체자를 대표 글자로 바꾼 뒤 분석하므로
+/// "国际机场", "國際機場", "国際空港" 처럼 글자 모양이 다른 텍스트가 서로 일치합니다.
+/// fields 는 "title", "body", "title_ngram", "body_ngram" 중에서 고르고,
+/// mappings 가 비어 있으면 내장된 표(번체자, 일본 신자체 -> 간체자)를 사용합니다.
+/// 검색어 분석이 바로 바뀌므로 문서가 없는 인덱스에서만 바꿀 수 있습니다
+/// (먼저 clear_all_documents() 를 호출하고 문서를 다시 추가하세요).
+#[flutter_rust_bridge::frb(sync)]
+pub fn configure_variant_folding(
+    fields: Vec<String>,
+    mappings: Vec<VariantMapping>,
+) -> Result<String, String> {
+    let mut search_index = SEARCH_INDEX.lock().unwrap();
+    let search_index = search_index.as_mut().ok_or(
+        "검색 인덱스가 초기화되지 않았습니다. initialize_search_index()를 먼저 호출하세요.",
+    )?;
+
+    let reader = search_index.index.reader().map_err(|e| e.to_string())?;
+    if reader.searcher().num_docs() > 0 {
+        return Err(
+            "문서가 있는 인덱스에서는 이체자 통일 설정을 바꿀 수 없습니다. clear_all_documents() 후 다시 설정하세요."
+                .to_string(),
+        );
+    }
+    search_index.update_settings(IndexSettings {
+        variant_fields: fields.clone(),
+        variant_mappings: mappings,
+        ..search_index.settings()
+    })?;
+
+    Ok(format!("이체자 통일 필드가 설정되었습니다: {}", fields.join(", ")))
+}
+
+/// 샘플 문서를 인덱싱합니다
+#[flutter_rust_bridge::frb(sync)]
+pub fn index_sample_documents() -> Result<String, String> {
+    let mut search_index = SEARCH_INDEX.lock().unwrap();
+    let search_index = search_index.as_mut().ok_or(
+        "검색 인덱스가 초기화되지 않았습니다. initialize_search_index()를 먼저 호출하세요.",
+    )?;
+
+    // create index writer
+    let mut index_writer = search_index
+        .index
+        .writer(50_000_000)
+        .map_err(|e| e.to_string())?;
+
+    // 샘플 문서 (제목, 본문, 제목 N-gram, 본문 N-gram, 메타데이터)
+    let sample_documents = [
+        (
+            "나리타 국제공항",
+            "나리타 국제공항(일본어: 成田国際空港, 영어: Narita International Airport, IATA: NRT, ICAO: RJAA)은 일본 지바현 나리타시에 위치한 국제공항으로, 도쿄도 도심에서 동북쪽으로 약 62km 떨어져 있다.",
+            "나리타 국제",
+            "나리타 국제공항",
+            serde_json::json!({"country": "일본", "iata": "NRT", "icao": "RJAA", "city": "나리타"}),
+        ),
+        (
+            "도쿄 국제공항",
+            "도쿄국제공항(일본어: 東京国際空港、とうきょうこくさいくうこう, 영어: Tokyo International Airport)은 일본 도쿄도 오타구에 있는 공항이다. 보통 이 일대의 옛 지명을 본뜬 하네다 공항(일본어: 羽田空港, 영어: Haneda Airport)이라고 불린다.",
+            "도쿄 국제",
+            "도쿄국제공항",
+            serde_json::json!({"country": "일본", "iata": "HND", "icao": "RJTT", "city": "도쿄"}),
+        ),
+        (
+            "간사이 국제공항",
+            "간사이 국제공항(일본어: 関西国際空港, IATA: KIX, ICAO: RJBB)은 일본 오사카부 오사카 만에 조성된 인공섬에 위치한 일본의 공항으로, 대한민국의 인천국제공항보다 6년 반 앞선 1994년 9월 4일에 개항했다.",
+            "간사이 국제",
+            "간사이 국제공항",
+            serde_json::json!({"country": "일본", "iata": "KIX", "icao": "RJBB", "city": "오사카"}),
+        ),
+        (
+            "인천국제공항",
+            "인천국제공항(仁川國際空港, Incheon International Airport, IATA: ICN, ICAO: RKSI)은 대한민국 인천광역시 중구 운서동에 있는 국제공항이다. 2001년 3월 29일 개항하였다.",
+            "인천국제",
+            "인천국제공항",
+            serde_json::json!({"country": "한국", "iata": "ICN", "icao": "RKSI", "city": "인천"}),
+        ),
+        (
+            "김포국제공항",
+            "김포국제공항(金浦國際空港, Gimpo International Airport, IATA: GMP, ICAO: RKSS)은 대한민국 서울특별시 강서구 공항동에 있는 국제공항이다. 서울 도심에서 서쪽으로 약 15km 떨어져 있다.",
+            "김포국제",
+            "김포국제공항",
+            serde_json::json!({"country": "한국", "iata": "GMP", "icao": "RKSS", "city": "서울"}),
+        ),
+        (
+            "제주국제공항",
+            "제주국제공항(濟州國際空港, Jeju International Airport, IATA: CJU, ICAO: RKPC)은 대한민국 제주특별자치도 제주시 용담동에 있는 국제공항이다. 한국에서 가장 많은 승객이 이용하는 공항이다.",
+            "제주국제",
+            "제주국제공항",
+            serde_json::json!({"country": "한국", "iata": "CJU", "icao": "RKPC", "city": "제주"}),
+        ),
+        (
+            "싱가포르 창이공항",
+            "싱가포르 창이공항(Singapore Changi Airport, IATA: SIN, ICAO: WSSS)은 싱가포르에 있는 국제공항이다. 세계적으로 유명한 허브공항이며, 최고의 서비스로 ��러 차례 수상한 바 있다.",
+            "싱가포르 창이",
+            "싱가포르 창이공항",
+            serde_json::json!({"country": "싱가포르", "iata": "SIN", "icao": "WSSS", "city": "싱가포르"}),
+        ),
+        (
+            "홍콩국제공항",
+            "홍콩국제공항(香港國際機場, Hong Kong International Airport, IATA: HKG, ICAO: VHHH)은 중화인민공화국 홍콩특별행정구에 있는 국제공항이다. 란타우섬 북쪽 해상의 인공섬에 위치한다.",
+            "홍콩국제",
+            "홍콩국제공항",
+            serde_json::json!({"country": "홍콩", "iata": "HKG", "icao": "VHHH", "city": "홍콩"}),
+        ),
+        // 일본어 예제 추가
+        (
+            "東京国際空港",
+            "東京国際空港（とうきょうこくさいくうこう）は、東京都大田区にある日本最大の空港である。通称は羽田空港。国内線・国際線ともに多くの路線を持つ重要な拠点空港である。",
+            "東京国際空港",
+            "東京国際空港",
+            serde_json::json!({"country": "日本", "iata": "HND", "icao": "RJTT", "city": "東京", "language": "ja"}),
+        ),
+        (
+            "関西国際空港",
+            "関西国際空港（かんさいこくさいくうこう）は、大阪府泉佐野市にある国際空港である。愛称は「関空」。大阪湾の人工島に建設され、24時間運用可能な空港として知られている。",
+            "関西国際空港",
+            "関西国際空港",
+            serde_json::json!({"country": "日本", "iata": "KIX", "icao": "RJBB", "city": "大阪", "language": "ja"}),
+        ),
+        (
+            "中部国際空港",
+            "中部国際空港（ちゅうぶこくさいくうこう）は、愛知県常滑市にある国際空港である。愛称はセントレア。名古屋の玄関口として、中部地方の経済発展に貢献している。",
+            "中部国際空港",
+            "中部国際空港",
+            serde_json::json!({"country": "日本", "iata": "NGO", "icao": "RJGG", "city": "名古屋", "language": "ja"}),
+        ),
+        // 중국어 예제 추가
+        (
+            "北京首都国际机场",
+            "北京首都国际机场是中国最繁忙的机场之一，位于北京市顺义区。作为中国国际航空的主要枢纽，连接世界各地的重要航线。机场设施完善，服务优质。",
+            "北京首都国际机场",
+            "北京首都国际机场",
+            serde_json::json!({"country": "中国", "iata": "PEK", "icao": "ZBAA", "city": "北京", "language": "zh"}),
+        ),
+        (
+            "上海浦东国际机场",
+            "上海浦东国际机场是中国三大门户复合枢纽之一，位于上海市浦东新区。是上海两座国际机场之一，主要服务国际航班。机场现代化程度高，吞吐量巨大。",
+            "上海浦东国际机场",
+            "上海浦东国际机场",
+            serde_json::json!({"country": "中国", "iata": "PVG", "icao": "ZSPD", "city": "上海", "language": "zh"}),
+        ),
+        (
+            "广州白云国际机场",
+            "广州白云国际机场位于广州市白云区，是中国三大航空枢纽之一。作为华南地区最大的交通枢纽，连接国内外众多城市。机场配套设施齐全，交通便利。",
+            "广州白云国际机场",
+            "广州白云国际机场",
+            serde_json::json!({"country": "中国", "iata": "CAN", "icao": "ZGGG", "city": "广州", "language": "zh"}),
+        ),
+        // 한국어 추가 예제 (부분 검색 테스트용)
+        (
+            "우리 할아버지",
+            "우리 할아버지는 항상 아버지에게 좋은 가르침을 주셨다. 할머니와 함께 시골에서 농사를 지으며 평화롭게 살고 계신다.",
+            "우리 할아버지",
+            "우리 할아버지",
+            serde_json::json!({"category": "가족", "language": "ko"}),
+        ),
+    ];
+
+    // add documents with metadata
+    let timestamp = now();
+    for (title, body, title_ngram, body_ngram, metadata) in sample_documents {
+        let document = search_index.build_document(
+            generate_uuid(),
+            title,
+            body,
+            metadata,
+            timestamp,
+            timestamp,
+        );
+
+        // 샘플 문서는 N-gram 필드에 제목/본문의 앞부분만 색인합니다
+        let mut sample_document = TantivyDocument::new();
+        for (field, value) in document.field_values() {
+            if field != search_index.title_ngram_field && field != search_index.body_ngram_field {
+                sample_document.add_field_value(field, value);
+            }
+        }
+        sample_document.add_text(search_index.title_ngram_field, title_ngram);
+        sample_document.add_text(search_index.body_ngram_field, body_ngram);
+
+        index_writer
+            .add_document(sample_document)
+            .map_err(|e| e.to_string())?;
+    }
+
+    // commit
+    search_index.commit(&mut index_writer)?;
+
+    Ok("총 15개의 문서가 인덱싱되었습니다. (한국어 9개, 일본어 3개, 중국어 3개)".to_string())
+}
+
+/// 문서를 검색합니다 (형태소 분석 + N-gram 부분 검색)
+#[flutter_rust_bridge::frb(sync)]
+pub fn search_documents(query_str: String, limit: usize) -> Result<Vec<SearchResult>, String> {
+    let options = SearchOptions {
+        limit,
+        ..Default::default()
+    };
+    let response = search_documents_with_options(query_str, options)?;
+
+    Ok(response
+        .hits
+        .into_iter()
+        .map(|hit| SearchResult {
+            id: hit.id,
+            title: hit.title,
+            body: hit.body,
+            score: hit.score,
+            metadata: hit.metadata,
+            created_at: hit.created_at,
+            updated_at: hit.updated_at,
+        })
+        .collect())
+}
+
+/// 옵션을 지정하여 문서를 검색합니다 (스니펫/하이라이트 포함)
+#[flutter_rust_bridge::frb(sync)]
+pub fn search_documents_with_options(
+    query_str: String,
+    options: SearchOptions,
+) -> Result<SearchResponse, String> {
+    let search_index = SEARCH_INDEX.lock().unwrap();
+    let search_index = search_index.as_ref().ok_or(
+        "검색 인덱스가 초기화되지 않았습니다. initialize_search_index()를 먼저 호출하세요.",
+    )?;
+
+    let (query, diagnostics) = parse_user_query(search_index, &query_str, options.lenient)?;
+
+    let mut response = execute_search(search_index, query, &options)?;
+    response.diagnostics = diagnostics;
+    Ok(response)
+}
+
+// 쿼리 문자열을 형태소 분석 필드와 N-gram 필드 대상으로 파싱합니다
+// (자모로만 된 검색어는 초성 필드에서, 가나/로마자 검색어는 일본어 읽기 필드에서,
+// 영문 검색어는 병음 필드에서도 찾습니다)
+fn parse_user_query(
+    search_index: &SearchIndex,
+    query_str: &str,
+    lenient: bool,
+) -> Result<(Box<dyn Query>, Vec<QueryDiagnostic>), String> {
+    if is_chosung_query(query_str) {
+        return Ok((chosung_query(search_index.chosung_field, query_str), Vec::new()));
+    }
+
+    let title = search_index.title_field;
     let body = search_index.body_field;
-    let metadata = search_index.metadata_field;
     let title_ngram = search_index.title_ngram_field;
     let body_ngram = search_index.body_ngram_field;
 
-    // create reader
+    // create query parser - 형태소 분석 필드와 N-gram 필드 모두 포함 (+ 한자 독음 필드)
+    let mut default_fields = vec![title, body, title_ngram, body_ngram];
+    if search_index.hanja_readings {
+        default_fields.push(search_index.text_hangul_field);
+    }
+    let query_parser = QueryParser::for_index(&search_index.index, default_fields);
+
+    // parse query (lenient 모드에서는 오류를 진단 정보로 모읍니다)
+    let (query, diagnostics) = if lenient {
+        parse_query_lenient(&query_parser, query_str)
+    } else {
+        let query = query_parser
+            .parse_query(query_str)
+            .map_err(|e| e.to_string())?;
+        (query, Vec::new())
+    };
+
+    let readings = search_index.readings.read().unwrap();
+    match reading_query(search_index.reading_field, query_str, &readings)
+        .or_else(|| pinyin_query(search_index.pinyin_field, query_str, &readings))
+    {
+        Some(reading) => Ok((
+            Box::new(BooleanQuery::new(vec![
+                (Occur::Should, query),
+                (Occur::Should, reading),
+            ])),
+            diagnostics,
+        )),
+        None => Ok((query, diagnostics)),
+    }
+}
+
+// 쿼리를 관대하게 파싱하여 최선의 쿼리와 진단 정보를 반환합니다
+fn parse_query_lenient(
+    query_parser: &QueryParser,
+    query_str: &str,
+) -> (Box<dyn Query>, Vec<QueryDiagnostic>) {
+    // 문법 오류는 위치 정보와 함께 얻기 위해 먼저 문법 파서로 파싱합니다
+    let (user_input_ast, syntax_errors) = tantivy::query_grammar::parse_query_lenient(query_str);
+    let mut diagnostics: Vec<QueryDiagnostic> = syntax_errors
+        .into_iter()
+        .map(|error| QueryDiagnostic {
+            position: Some(utf16_offset(query_str, error.pos)),
+            message: error.message,
+        })
+        .collect();
+
+    // 알 수 없는 필드 등 의미 오류는 위치 정보가 없습니다
+    let (query, semantic_errors) =
+        query_parser.build_query_from_user_input_ast_lenient(user_input_ast);
+    diagnostics.extend(semantic_errors.into_iter().map(|error| QueryDiagnostic {
+        position: None,
+        message: error.to_string(),
+    }));
+
+    (query, diagnostics)
+}
+
+/// 입력 중인 검색어로 제목을 검색합니다 (search-as-you-type)
+///
+/// 검색어와 제목을 자모로 풀어 비교하므로 마지막 음절이 완성되지 않아도
+/// 접두어로 일치합니다 (예: "한구" -> "한국어", "인천 국제고" -> "인천 국제공항").
+/// 검색어의 모든 단어가 제목의 어떤 단어의 접두어여야 합니다.
+#[flutter_rust_bridge::frb(sync)]
+pub fn search_as_you_type(
+    query_str: String,
+    options: SearchOptions,
+) -> Result<SearchResponse, String> {
+    let search_index = SEARCH_INDEX.lock().unwrap();
+    let search_index = search_index.as_ref().ok_or(
+        "검색 인덱스가 초기화되지 않았습니다. initialize_search_index()를 먼저 호출하세요.",
+    )?;
+
+    let query = jamo_prefix_query(search_index.title_jamo_field, &query_str);
+
+    execute_search(search_index, query, &options)
+}
+
+/// 구조화된 쿼리로 문서를 검색합니다 (쿼리 문자열 파싱/이스케이프 불필요)
+#[flutter_rust_bridge::frb(sync)]
+pub fn search_with_query(
+    query: QueryNode,
+    options: SearchOptions,
+) -> Result<SearchResponse, String> {
+    let search_index = SEARCH_INDEX.lock().unwrap();
+    let search_index = search_index.as_ref().ok_or(
+        "검색 인덱스가 초기화되지 않았습니다. initialize_search_index()를 먼저 호출하세요.",
+    )?;
+
+    let query = build_query(&search_index.index, &query)?;
+
+    execute_search(search_index, query, &options)
+}
+
+/// 기준 문서(또는 텍스트)와 비슷한 문서를 검색합니다 (기준 문서는 결과에서 제외)
+#[flutter_rust_bridge::frb(sync)]
+pub fn more_like_this(
+    source: MoreLikeThisSource,
+    mlt_options: MoreLikeThisOptions,
+    options: SearchOptions,
+) -> Result<SearchResponse, String> {
+    let search_index = SEARCH_INDEX.lock().unwrap();
+    let search_index = search_index.as_ref().ok_or(
+        "검색 인덱스가 초기화되지 않았습니다. initialize_search_index()를 먼저 호출하세요.",
+    )?;
+
+    let title = search_index.title_field;
+    let body = search_index.body_field;
+
+    let (fields, excluded_id) = match source {
+        MoreLikeThisSource::DocumentId(id) => {
+            let reader = search_index.index.reader().map_err(|e| e.to_string())?;
+            let id_term = Term::from_field_text(search_index.id_field, &id);
+            let (_, doc) = find_document(&reader.searcher(), &id_term)?
+                .ok_or_else(|| format!("문서를 찾을 수 없습니다: {}", id))?;
+            let fields = vec![
+                (title, stored_text(&doc, title)),
+                (body, stored_text(&doc, body)),
+            ];
+            (fields, Some(id_term))
+        }
+        MoreLikeThisSource::Text(text) => (vec![(title, text.clone()), (body, text)], None),
+    };
+
+    let query: Box<dyn Query> = Box::new(more_like_this_query(&mlt_options, fields)?);
+    let query: Box<dyn Query> = match excluded_id {
+        Some(id_term) => Box::new(BooleanQuery::new(vec![
+            (Occur::Must, query),
+            (
+                Occur::MustNot,
+                Box::new(TermQuery::new(id_term, IndexRecordOption::Basic)),
+            ),
+        ])),
+        None => query,
+    };
+
+    execute_search(search_index, query, &options)
+}
+
+/// 쿼리 문자열에 대해 문서의 점수가 어떻게 계산되었는지 설명합니다
+///
+/// 검색 옵션의 퍼지 매칭과 필터도 검색할 때와 같이 적용됩니다.
+#[flutter_rust_bridge::frb(sync)]
+pub fn explain_document(
+    query_str: String,
+    id: String,
+    options: SearchOptions,
+) -> Result<ScoreExplanation, String> {
+    let search_index = SEARCH_INDEX.lock().unwrap();
+    let search_index = search_index.as_ref().ok_or(
+        "검색 인덱스가 초기화되지 않았습니다. initialize_search_index()를 먼저 호출하세요.",
+    )?;
+
+    let (query, _) = parse_user_query(search_index, &query_str, options.lenient)?;
+    explain_query(search_index, query, &id, &options)
+}
+
+/// 구조화된 쿼리에 대해 문서의 점수가 어떻게 계산되었는지 설명합니다
+#[flutter_rust_bridge::frb(sync)]
+pub fn explain_structured_query(
+    query: QueryNode,
+    id: String,
+    options: SearchOptions,
+) -> Result<ScoreExplanation, String> {
+    let search_index = SEARCH_INDEX.lock().unwrap();
+    let search_index = search_index.as_ref().ok_or(
+        "검색 인덱스가 초기화되지 않았습니다. initialize_search_index()를 먼저 호출하세요.",
+    )?;
+
+    let query = build_query(&search_index.index, &query)?;
+    explain_query(search_index, query, &id, &options)
+}
+
+fn explain_query(
+    search_index: &SearchIndex,
+    query: Box<dyn Query>,
+    id: &str,
+    options: &SearchOptions,
+) -> Result<ScoreExplanation, String> {
+    let query = with_search_options(search_index, query, options)?;
+
     let reader = search_index.index.reader().map_err(|e| e.to_string())?;
+    let searcher = reader.searcher();
+    let id_term = Term::from_field_text(search_index.id_field, id);
+    let (doc_address, _) = find_document(&searcher, &id_term)?
+        .ok_or_else(|| format!("문서를 찾을 수 없습니다: {}", id))?;
+
+    let explanation = query
+        .explain(&searcher, doc_address)
+        .map_err(|_| format!("문서가 쿼리와 일치하지 않습니다: {}", id))?;
+    to_score_explanation(&explanation)
+}
 
-    // create query parser - 형태소 분석 필드와 N-gram 필드 모두 포함
-    let query_parser = QueryParser::for_index(
-        &search_index.index,
-        vec![title, body, title_ngram, body_ngram],
-    );
+// 쿼리를 실행하고 검색 옵션(퍼지, 스니펫, 정렬, 집계)에 따라 결과를 만듭니다
+fn execute_search(
+    search_index: &SearchIndex,
+    query: Box<dyn Query>,
+    options: &SearchOptions,
+) -> Result<SearchResponse, String> {
+    let id = search_index.id_field;
+    let title = search_index.title_field;
+    let body = search_index.body_field;
+    let metadata = search_index.metadata_field;
 
-    // parse query
-    let query = query_parser
-        .parse_query(&query_str)
-        .map_err(|e| e.to_string())?;
+    let query = with_search_options(search_index, query, options)?;
+    let distance_origin = distance_origin(options);
+    let projection = Projection::new(&options.fields, options.body_max_chars)?;
+    if matches!(options.fields, FieldSelection::IdOnly) && options.snippet.is_some() {
+        return Err("ID 만 반환하는 검색(IdOnly)에서는 스니펫을 만들 수 없습니다.".to_string());
+    }
 
-    // create searcher
+    // create reader & searcher
+    let reader = search_index.index.reader().map_err(|e| e.to_string())?;
     let searcher = reader.searcher();
 
-    // search
-    let top_docs = searcher
-        .search(&query, &TopDocs::with_limit(limit))
-        .map_err(|e| e.to_string())?;
+    // ID 만 반환할 때는 문서 저장소 대신 id, 시스템 시각, 좌표 fast field 를 읽습니다
+    let fast_fields = match options.fields {
+        FieldSelection::IdOnly => {
+            let field_name = |field: Field| search_index.schema.get_field_name(field);
+            Some(FastHitFields::open(
+                &searcher,
+                field_name(id),
+                field_name(search_index.created_at_field),
+                field_name(search_index.updated_at_field),
+            )?)
+        }
+        _ => None,
+    };
+
+    // 스니펫 생성기 (형태소 분석 필드 + N-gram 필드)
+    let snippet_generators = match &options.snippet {
+        Some(snippet_options) if fast_fields.is_none() => Some(create_snippet_generators(
+            search_index,
+            &searcher,
+            query.as_ref(),
+            snippet_options,
+        )?),
+        _ => None,
+    };
+
+    // 집계와 카테고리 문서 수는 상위 문서 수집과 같은 패스에서 계산합니다
+    let aggregations = aggregation_collector(&options.aggregations)?;
+    let (categories, category_handles) = if options.category_counts.is_empty() {
+        (None, Vec::new())
+    } else {
+        let category_field_name = search_index.schema.get_field_name(search_index.category_field);
+        let (collector, handles) =
+            category_collector(category_field_name, &options.category_counts)?;
+        (Some(collector), handles)
+    };
+
+    let groups = match &options.group_by {
+        Some(group_by) => Some(GroupCollector::new(
+            &search_index.index,
+            &group_by.field,
+            &options.sort,
+            &options.score_modifiers,
+            group_by.groups_limit,
+            group_by.hits_per_group,
+        )?),
+        None => None,
+    };
+
+    // search (정렬 키나 점수 보정이 있으면 tweak_score 로 정렬, collapse 는 필드 값마다 최상위 문서 하나)
+    let (top_docs, aggregation_results, category_results, group_results): (
+        Vec<(f32, Vec<SortValue>, DocAddress)>,
+        _,
+        _,
+        _,
+    ) = if let Some(field) = &options.collapse {
+        let collector = GroupCollector::new(
+            &search_index.index,
+            field,
+            &options.sort,
+            &options.score_modifiers,
+            options.limit,
+            1,
+        )?;
+        let (collapsed, aggregation_results, category_results, group_results) = searcher
+            .search(&query, &(collector, aggregations, categories, groups))
+            .map_err(|e| e.to_string())?;
+        let top_docs = collapsed
+            .into_iter()
+            .flat_map(|group| group.hits)
+            .map(|(sort_values, doc_address)| ranked_doc(options, sort_values, doc_address))
+            .collect();
+        (top_docs, aggregation_results, category_results, group_results)
+    } else if options.sort.is_empty() && options.score_modifiers.is_empty() {
+        let collector = TopDocs::with_limit(options.limit);
+        let (top_docs, aggregation_results, category_results, group_results) = searcher
+            .search(&query, &(collector, aggregations, categories, groups))
+            .map_err(|e| e.to_string())?;
+        let top_docs = top_docs
+            .into_iter()
+            .map(|(score, doc_address)| (score, Vec::new(), doc_address))
+            .collect();
+        (top_docs, aggregation_results, category_results, group_results)
+    } else {
+        let sorter = FastFieldSorter::new(
+            &search_index.index,
+            &options.sort,
+            &options.score_modifiers,
+        )?;
+        let collector = TopDocs::with_limit(options.limit).tweak_score(sorter);
+        let (top_docs, aggregation_results, category_results, group_results) = searcher
+            .search(&query, &(collector, aggregations, categories, groups))
+            .map_err(|e| e.to_string())?;
+        let top_docs = top_docs
+            .into_iter()
+            .map(|(sort_values, doc_address)| ranked_doc(options, sort_values, doc_address))
+            .collect();
+        (top_docs, aggregation_results, category_results, group_results)
+    };
 
     // 결과 변환
-    let mut results = Vec::new();
-    for (score, doc_address) in top_docs {
+    let to_hit = |(score, sort_values, doc_address): (f32, Vec<SortValue>, DocAddress)| {
+        if let Some(fast_fields) = &fast_fields {
+            let (created_at, updated_at) = fast_fields.timestamps(doc_address);
+            return Ok(SearchHit {
+                id: fast_fields.id(doc_address)?,
+                title: String::new(),
+                body: String::new(),
+                score,
+                metadata: "{}".to_string(),
+                snippet: None,
+                sort_values,
+                created_at,
+                updated_at,
+                distance_km: distance_origin.and_then(|origin| {
+                    Some(origin.distance_km(&fast_fields.point(doc_address)?))
+                }),
+            });
+        }
+
         let retrieved_doc: TantivyDocument =
             searcher.doc(doc_address).map_err(|e| e.to_string())?;
 
-        let id_value = retrieved_doc
-            .get_first(id)
-            .and_then(|v| v.as_str())
-            .unwrap_or("")
-            .to_string();
-
-        let title_value = retrieved_doc
-            .get_first(title)
-            .and_then(|v| v.as_str())
-            .unwrap_or("")
-            .to_string();
-
-        let body_value = retrieved_doc
-            .get_first(body)
-            .and_then(|v| v.as_str())
-            .unwrap_or("")
-            .to_string();
-
-        let metadata_value = retrieved_doc
-            .get_first(metadata)
-            .map(|v| {
-                // Convert to JSON string
-                if let Some(s) = v.as_str() {
-                    s.to_string()
-                } else if let Some(obj_iter) = v.as_object() {
-                    // Convert iterator to HashMap and serialize
-                    let map: std::collections::HashMap<String, serde_json::Value> = obj_iter
-                        .map(|(k, v)| {
-                            // Convert each value to serde_json::Value
-                            let json_val = if let Some(s) = v.as_str() {
-                                serde_json::Value::String(s.to_string())
-                            } else if let Some(num) = v.as_u64() {
-                                serde_json::Value::Number(num.into())
-                            } else if let Some(num) = v.as_i64() {
-                                serde_json::Value::Number(num.into())
-                            } else if let Some(num) = v.as_f64() {
-                                serde_json::json!(num)
-                            } else if let Some(b) = v.as_bool() {
-                                serde_json::Value::Bool(b)
-                            } else if let Some(arr) = v.as_array() {
-                                // 배열 처리 추가!
-                                // CompactDocArrayIter는 이미 iterator이므로 collect()로 바로 변환
-                                let array_values: Vec<serde_json::Value> = arr
-                                    .map(|item| {
-                                        if let Some(s) = item.as_str() {
-                                            serde_json::Value::String(s.to_string())
-                                        } else if let Some(num) = item.as_u64() {
-                                            serde_json::Value::Number(num.into())
-                                        } else if let Some(num) = item.as_i64() {
-                                            serde_json::Value::Number(num.into())
-                                        } else if let Some(num) = item.as_f64() {
-                                            serde_json::json!(num)
-                                        } else if let Some(b) = item.as_bool() {
-                                            serde_json::Value::Bool(b)
-                                        } else {
-                                            serde_json::Value::Null
-                                        }
-                                    })
-                                    .collect();
-                                serde_json::Value::Array(array_values)
-                            } else {
-                                serde_json::Value::Null
-                            };
-                            (k.to_string(), json_val)
-                        })
-                        .collect();
-                    serde_json::to_string(&map).unwrap_or_else(|_| "{}".to_string())
-                } else {
-                    "{}".to_string()
-                }
-            })
-            .unwrap_or_else(|| "{}".to_string());
+        let snippet = snippet_generators.as_ref().map(|generators| {
+            let text = stored_text(&retrieved_doc, generators.field);
+            generators.snippet(&text)
+        });
 
-        results.push(SearchResult {
-            id: id_value,
-            title: title_value,
-            body: body_value,
+        Ok::<_, String>(SearchHit {
+            id: stored_text(&retrieved_doc, id),
+            title: if projection.title {
+                stored_text(&retrieved_doc, title)
+            } else {
+                String::new()
+            },
+            body: match retrieved_doc.get_first(body).and_then(|v| v.as_str()) {
+                Some(text) if projection.body => projection.body(text),
+                _ => String::new(),
+            },
             score,
-            metadata: metadata_value,
-        });
+            metadata: if projection.metadata {
+                metadata_to_json_string(&retrieved_doc, metadata)
+            } else {
+                "{}".to_string()
+            },
+            snippet,
+            sort_values,
+            created_at: stored_timestamp(&retrieved_doc, search_index.created_at_field),
+            updated_at: stored_timestamp(&retrieved_doc, search_index.updated_at_field),
+            distance_km: distance_origin.and_then(|origin| {
+                let lat = retrieved_doc.get_first(search_index.lat_field)?.as_f64()?;
+                let lon = retrieved_doc.get_first(search_index.lon_field)?.as_f64()?;
+                Some(origin.distance_km(&GeoPoint { lat, lon }))
+            }),
+        })
+    };
+    let results = top_docs
+        .into_iter()
+        .map(to_hit)
+        .collect::<Result<Vec<_>, _>>()?;
+    let groups = group_results
+        .unwrap_or_default()
+        .into_iter()
+        .map(|group| {
+            Ok(SearchGroup {
+                key: group.key,
+                count: group.count,
+                hits: group
+                    .hits
+                    .into_iter()
+                    .map(|(sort_values, doc_address)| {
+                        to_hit(ranked_doc(options, sort_values, doc_address))
+                    })
+                    .collect::<Result<Vec<_>, String>>()?,
+            })
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+
+    Ok(SearchResponse {
+        hits: results,
+        diagnostics: Vec::new(),
+        aggregations: aggregation_results
+            .map(|results| to_aggregation_results(&options.aggregations, results))
+            .unwrap_or_default(),
+        category_counts: category_results
+            .map(|results| to_category_counts(&options.category_counts, category_handles, results))
+            .unwrap_or_default(),
+        groups,
+    })
+}
+
+// 검색 옵션의 퍼지 매칭과 필터(카테고리, 좌표)를 쿼리에 적용합니다
+fn with_search_options(
+    search_index: &SearchIndex,
+    query: Box<dyn Query>,
+    options: &SearchOptions,
+) -> Result<Box<dyn Query>, String> {
+    let title = search_index.title_field;
+    let body = search_index.body_field;
+
+    // 퍼지 검색 (오타 허용)
+    let query = match &options.fuzzy {
+        Some(fuzzy_options) => with_fuzzy_terms(query, &[title, body], None, fuzzy_options)?,
+        None => query,
+    };
+
+    // 자모 단위 퍼지 검색 (한 음절 안의 자모 하나 차이도 거리 1)
+    let query = match &options.jamo_fuzzy {
+        Some(fuzzy_options) => with_fuzzy_terms(
+            query,
+            &[title, body],
+            Some(search_index.text_jamo_field),
+            fuzzy_options,
+        )?,
+        None => query,
+    };
+
+    // 카테고리 필터 (하위 카테고리 포함, 점수에는 영향 없음)
+    let query: Box<dyn Query> = match &options.category_filter {
+        Some(path) => {
+            let category = Term::from_facet(search_index.category_field, &parse_category(path)?);
+            let filter = TermQuery::new(category, IndexRecordOption::Basic);
+            Box::new(BooleanQuery::new(vec![
+                (Occur::Must, query),
+                (Occur::Must, Box::new(BoostQuery::new(Box::new(filter), 0.0))),
+            ]))
+        }
+        None => query,
+    };
+
+    // 좌표 필터 (점수에는 영향 없음)
+    let query: Box<dyn Query> = match &options.geo_filter {
+        Some(geo_filter) => Box::new(BooleanQuery::new(vec![
+            (Occur::Must, query),
+            (
+                Occur::Must,
+                Box::new(BoostQuery::new(Box::new(GeoQuery::new(geo_filter.clone())?), 0.0)),
+            ),
+        ])),
+        None => query,
+    };
+
+    Ok(query)
+}
+
+// 정렬 값을 (BM25 점수, 결과에 돌려줄 정렬 값, 문서 주소)로 나눕니다
+// (정렬 키를 지정하지 않았으면 정렬 값은 비워 둡니다)
+fn ranked_doc(
+    options: &SearchOptions,
+    sort_values: SortValues,
+    doc_address: DocAddress,
+) -> (f32, Vec<SortValue>, DocAddress) {
+    let values = if options.sort.is_empty() {
+        Vec::new()
+    } else {
+        sort_values.values
+    };
+    (sort_values.score, values, doc_address)
+}
+
+// 결과마다 거리를 계산할 기준 좌표 (첫 번째 거리 정렬 키, 없으면 반경 필터의 중심)
+fn distance_origin(options: &SearchOptions) -> Option<GeoPoint> {
+    options
+        .sort
+        .iter()
+        .find_map(|key| match key.by {
+            SortBy::Distance(origin) => Some(origin),
+            _ => None,
+        })
+        .or(match options.geo_filter {
+            Some(GeoFilter::Radius { center, .. }) => Some(center),
+            _ => None,
+        })
+}
+
+// 퍼지 매칭 점수 가중치 (정확히 일치하는 문서가 항상 위에 오도록 낮게 설정)
+const FUZZY_BOOST: f32 = 0.1;
+
+// 쿼리 파서가 필드별로 만든 검색어에 퍼지 쿼리를 추가합니다
+// 정확히 일치하는 쿼리는 그대로 두고, 퍼지 쿼리를 낮은 가중치의 should 절로 더합니다
+// (jamo_field 를 지정하면 검색어를 자모로 풀어 그 필드에서 퍼지 매칭합니다)
+fn with_fuzzy_terms(
+    query: Box<dyn Query>,
+    fields: &[Field],
+    jamo_field: Option<Field>,
+    options: &FuzzyOptions,
+) -> Result<Box<dyn Query>, String> {
+    if !(1..=2).contains(&options.distance) {
+        return Err(format!(
+            "퍼지 검색 거리는 1 ~ 2 사이여야 합니다: {}",
+            options.distance
+        ));
     }
 
-    Ok(results)
+    let mut terms = Vec::new();
+    query.query_terms(&mut |term, _| {
+        if fields.contains(&term.field()) && !terms.contains(term) {
+            terms.push(term.clone());
+        }
+    });
+
+    let mut fuzzy_clauses: Vec<(Occur, Box<dyn Query>)> = Vec::new();
+    let mut terms_seen: Vec<Term> = Vec::new();
+    for term in terms {
+        let Some(text) = term.value().as_str().map(str::to_string) else {
+            continue;
+        };
+        let (term, text) = match jamo_field {
+            Some(jamo_field) => {
+                let jamo = decompose_jamo(&text.to_lowercase());
+                (Term::from_field_text(jamo_field, &jamo), jamo)
+            }
+            None => (term, text),
+        };
+        if terms_seen.contains(&term) {
+            continue;
+        }
+        terms_seen.push(term.clone());
+        // 짧은 검색어는 거의 모든 단어와 매칭되므로 제외합니다
+        let num_chars = text.chars().count();
+        if num_chars <= options.prefix_length || num_chars <= options.distance as usize {
+            continue;
+        }
+
+        // 앞부분 prefix_length 글자는 정확히 일치해야 합니다
+        let fuzzy: Box<dyn Query> = Box::new(PrefixFuzzyQuery::new(
+            term,
+            options.prefix_length,
+            options.distance,
+            options.transposition,
+        ));
+        fuzzy_clauses.push((Occur::Should, fuzzy));
+    }
+
+    if fuzzy_clauses.is_empty() {
+        return Ok(query);
+    }
+
+    let fuzzy_query = BoostQuery::new(Box::new(BooleanQuery::new(fuzzy_clauses)), FUZZY_BOOST);
+    Ok(Box::new(BooleanQuery::new(vec![
+        (Occur::Should, query),
+        (Occur::Should, Box::new(fuzzy_query)),
+    ])))
+}
+
+// 형태소 분석 필드와 N-gram 필드용 스니펫 생성기 묶음
+struct SnippetGenerators {
+    field: Field,
+    lindera: SnippetGenerator,
+    ngram: SnippetGenerator,
+    html: bool,
+}
+
+impl SnippetGenerators {
+    // 형태소 분석 결과로 하이라이트를 찾고, 없으면 N-gram 결과를 사용합니다
+    fn snippet(&self, text: &str) -> SearchSnippet {
+        let mut snippet = self.lindera.snippet(text);
+        if snippet.is_empty() {
+            let ngram_snippet = self.ngram.snippet(text);
+            if !ngram_snippet.is_empty() {
+                snippet = ngram_snippet;
+            }
+        }
+
+        let fragment = snippet.fragment();
+        SearchSnippet {
+            fragment: fragment.to_string(),
+            highlights: to_utf16_ranges(fragment, snippet.highlighted()),
+            html: self.html.then(|| snippet.to_html()),
+        }
+    }
+}
+
+fn create_snippet_generators(
+    search_index: &SearchIndex,
+    searcher: &Searcher,
+    query: &dyn Query,
+    options: &SnippetOptions,
+) -> Result<SnippetGenerators, String> {
+    let field = search_index
+        .schema
+        .get_field(&options.field)
+        .map_err(|e| e.to_string())?;
+    let ngram_field = if field == search_index.title_field {
+        search_index.title_ngram_field
+    } else if field == search_index.body_field {
+        search_index.body_ngram_field
+    } else {
+        return Err(format!(
+            "스니펫을 지원하지 않는 필드입니다: {}",
+            options.field
+        ));
+    };
+
+    let mut lindera = SnippetGenerator::create(searcher, query, field).map_err(|e| e.to_string())?;
+    lindera.set_max_num_chars(options.max_num_chars);
+    let mut ngram =
+        SnippetGenerator::create(searcher, query, ngram_field).map_err(|e| e.to_string())?;
+    ngram.set_max_num_chars(options.max_num_chars);
+
+    Ok(SnippetGenerators {
+        field,
+        lindera,
+        ngram,
+        html: options.html,
+    })
+}
+
+// 바이트 오프셋 구간을 UTF-16 오프셋 구간으로 변환 (겹치는 구간은 병합)
+fn to_utf16_ranges(text: &str, ranges: &[Range<usize>]) -> Vec<HighlightRange> {
+    collapse_overlapped_ranges(ranges)
+        .into_iter()
+        .map(|range| HighlightRange {
+            start: utf16_offset(text, range.start),
+            end: utf16_offset(text, range.end),
+        })
+        .collect()
+}
+
+// 바이트 오프셋을 UTF-16 오프셋으로 변환 (Dart `String` 인덱스와 동일)
+fn utf16_offset(text: &str, byte_offset: usize) -> u32 {
+    let byte_offset = byte_offset.min(text.len());
+    text.char_indices()
+        .take_while(|(i, _)| *i < byte_offset)
+        .map(|(_, c)| c.len_utf16() as u32)
+        .sum()
+}
+
+// 저장된 텍스트 필드 값을 가져오는 헬퍼 함수
+fn stored_text(doc: &TantivyDocument, field: Field) -> String {
+    doc.get_first(field)
+        .and_then(|v| v.as_str())
+        .unwrap_or("")
+        .to_string()
+}
+
+// 저장된 시각을 Unix 시간 밀리초로 읽습니다 (없으면 0)
+fn stored_timestamp(doc: &TantivyDocument, field: Field) -> i64 {
+    doc.get_first(field)
+        .and_then(|v| v.as_datetime())
+        .map(|date_time| date_time.into_timestamp_millis())
+        .unwrap_or(0)
+}
+
+// 저장된 JSON 메타데이터를 문자열로 변환하는 헬퍼 함수
+fn metadata_to_json_string(doc: &TantivyDocument, metadata: Field) -> String {
+    doc.get_first(metadata)
+        .map(|v| {
+            // Convert to JSON string
+            if let Some(s) = v.as_str() {
+                s.to_string()
+            } else if let Some(obj_iter) = v.as_object() {
+                // Convert iterator to HashMap and serialize
+                let map: std::collections::HashMap<String, serde_json::Value> = obj_iter
+                    .map(|(k, v)| {
+                        // Convert each value to serde_json::Value
+                        let json_val = if let Some(s) = v.as_str() {
+                            serde_json::Value::String(s.to_string())
+                        } else if let Some(num) = v.as_u64() {
+                            serde_json::Value::Number(num.into())
+                        } else if let Some(num) = v.as_i64() {
+                            serde_json::Value::Number(num.into())
+                        } else if let Some(num) = v.as_f64() {
+                            serde_json::json!(num)
+                        } else if let Some(b) = v.as_bool() {
+                            serde_json::Value::Bool(b)
+                        } else if let Some(arr) = v.as_array() {
+                            // 배열 처리 추가!
+                            // CompactDocArrayIter는 이미 iterator이므로 collect()로 바로 변환
+                            let array_values: Vec<serde_json::Value> = arr
+                                .map(|item| {
+                                    if let Some(s) = item.as_str() {
+                                        serde_json::Value::String(s.to_string())
+                                    } else if let Some(num) = item.as_u64() {
+                                        serde_json::Value::Number(num.into())
+                                    } else if let Some(num) = item.as_i64() {
+                                        serde_json::Value::Number(num.into())
+                                    } else if let Some(num) = item.as_f64() {
+                                        serde_json::json!(num)
+                                    } else if let Some(b) = item.as_bool() {
+                                        serde_json::Value::Bool(b)
+                                    } else {
+                                        serde_json::Value::Null
+                                    }
+                                })
+                                .collect();
+                            serde_json::Value::Array(array_values)
+                        } else {
+                            serde_json::Value::Null
+                        };
+                        (k.to_string(), json_val)
+                    })
+                    .collect();
+                serde_json::to_string(&map).unwrap_or_else(|_| "{}".to_string())
+            } else {
+                "{}".to_string()
+            }
+        })
+        .unwrap_or_else(|| "{}".to_string())
+}
+
+// 자동완성용 제목 접두어 최대 글자 수
+const MAX_PREFIX_CHARS: usize = 20;
+
+// 색인어 자동완성에서 필드/세그먼트당 살펴볼 최대 색인어 수
+// (접두어로 시작하는 색인어가 이보다 많으면 사전 순으로 앞쪽 색인어만 순위를 매깁니다)
+const MAX_SCANNED_TERMS: usize = 1_000;
+
+/// 자동완성 후보의 출처
+#[derive(Clone, Debug, PartialEq)]
+pub enum SuggestionSource {
+    /// 문서 제목 (제목 앞부분이 접두어와 일치)
+    Title,
+    /// 형태소 분석된 색인어 (title, body)
+    Term,
+}
+
+/// 자동완성 후보
+#[derive(Clone, Debug)]
+pub struct Suggestion {
+    pub text: String,
+    pub source: SuggestionSource,
+    /// 이 후보를 포함하는 문서 수
+    pub doc_count: u64,
+}
+
+/// 입력 중인 접두어로 자동완성 후보를 반환합니다
+///
+/// 제목은 edge n-gram 필드의 색인어 하나를, 색인어는 term dictionary(FST)의
+/// 접두어 구간만 조회하므로 검색 파이프라인보다 훨씬 가볍습니다.
+/// 색인어 후보는 대소문자를 구분하지 않으며, 접두어로 시작하는 색인어가 아주 많으면
+/// 사전 순으로 앞쪽 색인어만 문서 수를 세므로 순위가 근사값일 수 있습니다.
+#[flutter_rust_bridge::frb(sync)]
+pub fn autocomplete(
+    prefix: String,
+    limit: usize,
+    source: SuggestionSource,
+) -> Result<Vec<Suggestion>, String> {
+    let search_index = SEARCH_INDEX.lock().unwrap();
+    let search_index = search_index.as_ref().ok_or(
+        "검색 인덱스가 초기화되지 않았습니다. initialize_search_index()를 먼저 호출하세요.",
+    )?;
+
+    let prefix = prefix.trim();
+    if prefix.is_empty() || limit == 0 {
+        return Ok(Vec::new());
+    }
+
+    let reader = search_index.index.reader().map_err(|e| e.to_string())?;
+    let searcher = reader.searcher();
+
+    match source {
+        SuggestionSource::Title => title_suggestions(search_index, &searcher, prefix, limit),
+        SuggestionSource::Term => term_suggestions(search_index, &searcher, prefix, limit),
+    }
+}
+
+// 제목 접두어 필드로 제목 후보를 찾습니다 (짧은 제목이 먼저 나옵니다)
+// 같은 제목의 문서는 title fast field 값으로 한 번에 묶으므로 저장된 문서를 읽지 않습니다
+fn title_suggestions(
+    search_index: &SearchIndex,
+    searcher: &Searcher,
+    prefix: &str,
+    limit: usize,
+) -> Result<Vec<Suggestion>, String> {
+    let normalized = prefix.to_lowercase();
+    let key: String = normalized.chars().take(MAX_PREFIX_CHARS).collect();
+    let query = TermQuery::new(
+        Term::from_field_text(search_index.title_prefix_field, &key),
+        IndexRecordOption::Basic,
+    );
+
+    let title_field_name = search_index.schema.get_field_name(search_index.title_field);
+    let titles = searcher
+        .search(&query, &DistinctValueCollector::new(title_field_name))
+        .map_err(|e| e.to_string())?;
+
+    // 접두어가 색인된 길이보다 길면 나머지 부분을 직접 비교합니다
+    Ok(titles
+        .into_iter()
+        .filter(|title| title.value.to_lowercase().starts_with(&normalized))
+        .map(|title| Suggestion {
+            text: title.value,
+            source: SuggestionSource::Title,
+            doc_count: title.count,
+        })
+        .take(limit)
+        .collect())
+}
+
+// title, body 필드의 term dictionary 에서 접두어로 시작하는 색인어를 찾습니다
+// (접두어는 필드 분석기처럼 이체자를 통일하고, 대소문자는 구분하지 않습니다)
+fn term_suggestions(
+    search_index: &SearchIndex,
+    searcher: &Searcher,
+    prefix: &str,
+    limit: usize,
+) -> Result<Vec<Suggestion>, String> {
+    let mut doc_counts: HashMap<String, u64> = HashMap::new();
+
+    for segment_reader in searcher.segment_readers() {
+        // 색인어별로 title, body 중 하나라도 포함하는 삭제되지 않은 문서
+        let mut segment_docs: HashMap<String, Vec<DocId>> = HashMap::new();
+        for (field, field_name) in [
+            (search_index.title_field, "title"),
+            (search_index.body_field, "body"),
+        ] {
+            let folded = folding_filter(&search_index.variant_folding, field_name)(prefix);
+            let inverted_index = segment_reader
+                .inverted_index(field)
+                .map_err(|e| e.to_string())?;
+            let mut stream = inverted_index
+                .terms()
+                .search(CaseInsensitivePrefix::new(&folded))
+                .into_stream()
+                .map_err(|e| e.to_string())?;
+
+            let mut scanned = 0;
+            while scanned < MAX_SCANNED_TERMS && stream.advance() {
+                scanned += 1;
+                let Ok(text) = std::str::from_utf8(stream.key()) else {
+                    continue;
+                };
+                let docs = segment_docs.entry(text.to_string()).or_default();
+                let mut postings = inverted_index
+                    .read_postings_from_terminfo(stream.value(), IndexRecordOption::Basic)
+                    .map_err(|e| e.to_string())?;
+                let mut doc = postings.doc();
+                while doc != TERMINATED {
+                    if !segment_reader.is_deleted(doc) {
+                        docs.push(doc);
+                    }
+                    doc = postings.advance();
+                }
+            }
+        }
+        for (text, mut docs) in segment_docs {
+            docs.sort_unstable();
+            docs.dedup();
+            if !docs.is_empty() {
+                *doc_counts.entry(text).or_insert(0) += docs.len() as u64;
+            }
+        }
+    }
+
+    let mut suggestions: Vec<Suggestion> = doc_counts
+        .into_iter()
+        .map(|(text, doc_count)| Suggestion {
+            text,
+            source: SuggestionSource::Term,
+            doc_count,
+        })
+        .collect();
+    // 문서 수가 많은 순, 같으면 짧은 색인어 순
+    suggestions.sort_by(|a, b| {
+        b.doc_count
+            .cmp(&a.doc_count)
+            .then_with(|| a.text.chars().count().cmp(&b.text.chars().count()))
+            .then_with(|| a.text.cmp(&b.text))
+    });
+    suggestions.truncate(limit);
+
+    Ok(suggestions)
 }
 
 /// 커스텀 문서를 추가합니다 (UUID 자동 생성)
@@ -685,19 +2198,13 @@ pub fn add_document(title: String, body: String, metadata_json: String) -> Resul
         "검색 인덱스가 초기화되지 않았습니다. initialize_search_index()를 먼저 호출하세요.",
     )?;
 
-    let id_field = search_index.id_field;
-    let title_field = search_index.title_field;
-    let body_field = search_index.body_field;
-    let metadata_field = search_index.metadata_field;
-    let title_ngram_field = search_index.title_ngram_field;
-    let body_ngram_field = search_index.body_ngram_field;
-
     // Parse JSON metadata
     let metadata: JsonValue =
         serde_json::from_str(&metadata_json).unwrap_or_else(|_| serde_json::json!({}));
 
     // Generate UUID
     let uuid = generate_uuid();
+    let timestamp = now();
 
     // create index writer
     let mut index_writer = search_index
@@ -707,23 +2214,26 @@ pub fn add_document(title: String, body: String, metadata_json: String) -> Resul
 
     // add document
     index_writer
-        .add_document(doc!(
-            id_field => uuid.clone(),
-            title_field => title,
-            body_field => body,
-            metadata_field => metadata,
-            title_ngram_field => title,
-            body_ngram_field => body
+        .add_document(search_index.build_document(
+            uuid.clone(),
+            &title,
+            &body,
+            metadata,
+            timestamp,
+            timestamp,
         ))
         .map_err(|e| e.to_string())?;
 
     // commit
-    index_writer.commit().map_err(|e| e.to_string())?;
+    search_index.commit(&mut index_writer)?;
 
     Ok(format!("문서 ID '{}'가 추가되었습니다.", uuid))
 }
 
-/// 여러 문서를 한 번에 추가합니다 (UUID 자동 생성)
+/// 여러 문서를 한 번에 추가합니다
+///
+/// id 가 비어 있으면 UUID 를 만들고, 같은 ID 의 문서가 이미 있으면 `update_document` 처럼
+/// 생성 시각을 유지한 채 바꿉니다 (한 번에 같은 ID 를 여러 번 넘기면 마지막 문서가 남습니다).
 #[flutter_rust_bridge::frb(sync)]
 pub fn add_documents(documents: Vec<DocumentInput>) -> Result<String, String> {
     let mut search_index = SEARCH_INDEX.lock().unwrap();
@@ -731,44 +2241,49 @@ pub fn add_documents(documents: Vec<DocumentInput>) -> Result<String, String> {
         "검색 인덱스가 초기화되지 않았습니다. initialize_search_index()를 먼저 호출하세요.",
     )?;
 
-    let id_field = search_index.id_field;
-    let title_field = search_index.title_field;
-    let body_field = search_index.body_field;
-    let metadata_field = search_index.metadata_field;
-    let title_ngram_field = search_index.title_ngram_field;
-    let body_ngram_field = search_index.body_ngram_field;
-
     // create index writer
     let mut index_writer = search_index
         .index
         .writer(50_000_000)
         .map_err(|e| e.to_string())?;
+    let reader = search_index.index.reader().map_err(|e| e.to_string())?;
+    let searcher = reader.searcher();
 
     // add documents
+    let timestamp = now();
     for doc_input in &documents {
         let metadata: JsonValue =
             serde_json::from_str(&doc_input.metadata).unwrap_or_else(|_| serde_json::json!({}));
 
-        let uuid = if doc_input.id.is_empty() {
-            generate_uuid()
+        let (uuid, created_at) = if doc_input.id.is_empty() {
+            (generate_uuid(), timestamp)
         } else {
-            doc_input.id.clone()
+            // ID로 기존 문서 삭제
+            let id_term = Term::from_field_text(search_index.id_field, &doc_input.id);
+            let created_at = find_document(&searcher, &id_term)?
+                .and_then(|(_, doc)| {
+                    doc.get_first(search_index.created_at_field)
+                        .and_then(|v| v.as_datetime())
+                })
+                .unwrap_or(timestamp);
+            index_writer.delete_term(id_term);
+            (doc_input.id.clone(), created_at)
         };
 
         index_writer
-            .add_document(doc!(
-                id_field => uuid,
-                title_field => doc_input.title.clone(),
-                body_field => doc_input.body.clone(),
-                metadata_field => metadata,
-                title_ngram_field => doc_input.title.clone(),
-                body_ngram_field => doc_input.body.clone()
+            .add_document(search_index.build_document(
+                uuid,
+                &doc_input.title,
+                &doc_input.body,
+                metadata,
+                created_at,
+                timestamp,
             ))
             .map_err(|e| e.to_string())?;
     }
 
     // commit
-    index_writer.commit().map_err(|e| e.to_string())?;
+    search_index.commit(&mut index_writer)?;
 
     Ok(format!("총 {}개의 문서가 추가되었습니다.", documents.len()))
 }
@@ -787,11 +2302,6 @@ pub fn update_document(
     )?;
 
     let id_field = search_index.id_field;
-    let title_field = search_index.title_field;
-    let body_field = search_index.body_field;
-    let metadata_field = search_index.metadata_field;
-    let title_ngram_field = search_index.title_ngram_field;
-    let body_ngram_field = search_index.body_ngram_field;
 
     // Parse JSON metadata
     let metadata: JsonValue =
@@ -803,28 +2313,60 @@ pub fn update_document(
         .writer(50_000_000)
         .map_err(|e| e.to_string())?;
 
-    // ID로 기존 문서 삭제
+    // 기존 문서의 생성 시각은 유지합니다 (문서가 없으면 지금 생성된 것으로 봅니다)
     let id_term = tantivy::Term::from_field_text(id_field, &id);
+    let created_at = find_created_at(search_index, &id_term)?.unwrap_or_else(now);
+
+    // ID로 기존 문서 삭제
     index_writer.delete_term(id_term);
 
     // 새 문서 추가
     index_writer
-        .add_document(doc!(
-            id_field => id.clone(),
-            title_field => title,
-            body_field => body,
-            metadata_field => metadata,
-            title_ngram_field => title,
-            body_ngram_field => body
+        .add_document(search_index.build_document(
+            id.clone(),
+            &title,
+            &body,
+            metadata,
+            created_at,
+            now(),
         ))
         .map_err(|e| e.to_string())?;
 
     // commit
-    index_writer.commit().map_err(|e| e.to_string())?;
+    search_index.commit(&mut index_writer)?;
 
     Ok(format!("문서 ID '{}'가 업데이트되었습니다.", id))
 }
 
+// ID 색인어로 문서를 찾아 생성 시각을 읽습니다
+fn find_created_at(search_index: &SearchIndex, id_term: &Term) -> Result<Option<DateTime>, String> {
+    let reader = search_index.index.reader().map_err(|e| e.to_string())?;
+    let searcher = reader.searcher();
+    Ok(find_document(&searcher, id_term)?.and_then(|(_, doc)| {
+        doc.get_first(search_index.created_at_field)
+            .and_then(|v| v.as_datetime())
+    }))
+}
+
+// ID 색인어로 문서를 찾습니다
+fn find_document(
+    searcher: &Searcher,
+    id_term: &Term,
+) -> Result<Option<(DocAddress, TantivyDocument)>, String> {
+    let query = TermQuery::new(id_term.clone(), IndexRecordOption::Basic);
+    let top_docs = searcher
+        .search(&query, &TopDocs::with_limit(1))
+        .map_err(|e| e.to_string())?;
+
+    match top_docs.first() {
+        Some((_, doc_address)) => {
+            let doc: TantivyDocument = searcher.doc(*doc_address).map_err(|e| e.to_string())?;
+            Ok(Some((*doc_address, doc)))
+        }
+        None => Ok(None),
+    }
+}
+
 /// ID로 문서를 삭제합니다
 #[flutter_rust_bridge::frb(sync)]
 pub fn delete_document(id: String) -> Result<String, String> {
@@ -846,7 +2388,7 @@ pub fn delete_document(id: String) -> Result<String, String> {
     index_writer.delete_term(id_term);
 
     // commit
-    index_writer.commit().map_err(|e| e.to_string())?;
+    search_index.commit(&mut index_writer)?;
 
     Ok(format!("문서 ID '{}'가 삭제되었습니다.", id))
 }
@@ -874,7 +2416,7 @@ pub fn delete_documents(ids: Vec<String>) -> Result<String, String> {
     }
 
     // commit
-    index_writer.commit().map_err(|e| e.to_string())?;
+    search_index.commit(&mut index_writer)?;
 
     Ok(format!("총 {}개의 문서가 삭제되었습니다.", ids.len()))
 }
@@ -897,7 +2439,7 @@ pub fn clear_all_documents() -> Result<String, String> {
     let _ = index_writer.delete_all_documents();
 
     // commit
-    index_writer.commit().map_err(|e| e.to_string())?;
+    search_index.commit(&mut index_writer)?;
 
     Ok("모든 문서가 삭제되었습니다.".to_string())
 }
@@ -916,13 +2458,191 @@ pub fn get_document_count() -> Result<u64, String> {
     Ok(searcher.num_docs())
 }
 
-// 여러 문서 추가를 위한 입력 구조체
+/// 쿼리 문자열과 일치하는 문서 수를 반환합니다 (문서를 읽지 않습니다)
+///
+/// 퍼지 매칭과 필터(카테고리, 좌표) 옵션은 검색할 때와 같이 적용되고
+/// limit, 정렬, 집계 등 결과에 관한 옵션은 무시됩니다.
+#[flutter_rust_bridge::frb(sync)]
+pub fn count_documents(query_str: String, options: SearchOptions) -> Result<u64, String> {
+    let search_index = SEARCH_INDEX.lock().unwrap();
+    let search_index = search_index.as_ref().ok_or(
+        "검색 인덱스가 초기화되지 않았습니다. initialize_search_index()를 먼저 호출하세요.",
+    )?;
+
+    let (query, _) = parse_user_query(search_index, &query_str, options.lenient)?;
+    count_query(search_index, query, &options)
+}
+
+/// 구조화된 쿼리와 일치하는 문서 수를 반환합니다
+#[flutter_rust_bridge::frb(sync)]
+pub fn count_with_query(query: QueryNode, options: SearchOptions) -> Result<u64, String> {
+    let search_index = SEARCH_INDEX.lock().unwrap();
+    let search_index = search_index.as_ref().ok_or(
+        "검색 인덱스가 초기화되지 않았습니다. initialize_search_index()를 먼저 호출하세요.",
+    )?;
+
+    let query = build_query(&search_index.index, &query)?;
+    count_query(search_index, query, &options)
+}
+
+fn count_query(
+    search_index: &SearchIndex,
+    query: Box<dyn Query>,
+    options: &SearchOptions,
+) -> Result<u64, String> {
+    let query = with_search_options(search_index, query, options)?;
+
+    let reader = search_index.index.reader().map_err(|e| e.to_string())?;
+    let count = reader
+        .searcher()
+        .search(&query, &Count)
+        .map_err(|e| e.to_string())?;
+    Ok(count as u64)
+}
+
+/// 해당 ID의 문서가 있는지 확인합니다
+#[flutter_rust_bridge::frb(sync)]
+pub fn document_exists(id: String) -> Result<bool, String> {
+    let search_index = SEARCH_INDEX.lock().unwrap();
+    let search_index = search_index.as_ref().ok_or(
+        "검색 인덱스가 초기화되지 않았습니다. initialize_search_index()를 먼저 호출하세요.",
+    )?;
+
+    let reader = search_index.index.reader().map_err(|e| e.to_string())?;
+    let id_term = Term::from_field_text(search_index.id_field, &id);
+    let query = TermQuery::new(id_term, IndexRecordOption::Basic);
+    let count = reader
+        .searcher()
+        .search(&query, &Count)
+        .map_err(|e| e.to_string())?;
+    Ok(count > 0)
+}
+
+/// ID로 저장된 문서를 가져옵니다 (없으면 None)
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_document(id: String) -> Result<Option<StoredDocument>, String> {
+    let search_index = SEARCH_INDEX.lock().unwrap();
+    let search_index = search_index.as_ref().ok_or(
+        "검색 인덱스가 초기화되지 않았습니다. initialize_search_index()를 먼저 호출하세요.",
+    )?;
+
+    let reader = search_index.index.reader().map_err(|e| e.to_string())?;
+    let id_term = Term::from_field_text(search_index.id_field, &id);
+    Ok(find_document(&reader.searcher(), &id_term)?
+        .map(|(_, doc)| to_stored_document(search_index, &doc)))
+}
+
+/// 여러 ID의 문서를 요청한 순서대로 가져옵니다 (없는 ID는 건너뜁니다)
+#[flutter_rust_bridge::frb(sync)]
+pub fn get_documents(ids: Vec<String>) -> Result<Vec<StoredDocument>, String> {
+    let search_index = SEARCH_INDEX.lock().unwrap();
+    let search_index = search_index.as_ref().ok_or(
+        "검색 인덱스가 초기화되지 않았습니다. initialize_search_index()를 먼저 호출하세요.",
+    )?;
+
+    let reader = search_index.index.reader().map_err(|e| e.to_string())?;
+    let searcher = reader.searcher();
+    let mut documents = Vec::with_capacity(ids.len());
+    for id in &ids {
+        let id_term = Term::from_field_text(search_index.id_field, id);
+        if let Some((_, doc)) = find_document(&searcher, &id_term)? {
+            documents.push(to_stored_document(search_index, &doc));
+        }
+    }
+    Ok(documents)
+}
+
+/// 삭제되지 않은 모든 문서를 ID 순으로 페이지 단위로 읽습니다
+///
+/// 처음에는 cursor 를 None 으로 호출하고, 이후에는 이전 페이지의 `next_cursor` 를 넘깁니다.
+/// 커서는 마지막 문서의 ID 이므로 페이지 사이에 문서가 추가/삭제되어도 순서가 흔들리지 않습니다.
+#[flutter_rust_bridge::frb(sync)]
+pub fn scan_documents(cursor: Option<String>, limit: usize) -> Result<DocumentPage, String> {
+    let search_index = SEARCH_INDEX.lock().unwrap();
+    let search_index = search_index.as_ref().ok_or(
+        "검색 인덱스가 초기화되지 않았습니다. initialize_search_index()를 먼저 호출하세요.",
+    )?;
+    if limit == 0 {
+        return Err("limit 는 1 이상이어야 합니다.".to_string());
+    }
+
+    let reader = search_index.index.reader().map_err(|e| e.to_string())?;
+    let searcher = reader.searcher();
+
+    // 커서 다음 ID부터 (다음 페이지가 있는지 알기 위해 하나 더 읽습니다)
+    let query: Box<dyn Query> = match &cursor {
+        Some(cursor) => Box::new(RangeQuery::new(
+            Bound::Excluded(Term::from_field_text(search_index.id_field, cursor)),
+            Bound::Unbounded,
+        )),
+        None => Box::new(AllQuery),
+    };
+    let id_field_name = search_index.schema.get_field_name(search_index.id_field);
+    let collector =
+        TopDocs::with_limit(limit + 1).order_by_string_fast_field(id_field_name, Order::Asc);
+    let mut top_docs = searcher.search(&query, &collector).map_err(|e| e.to_string())?;
+
+    let has_more = top_docs.len() > limit;
+    top_docs.truncate(limit);
+    let documents = top_docs
+        .iter()
+        .map(|(_, doc_address)| {
+            let doc: TantivyDocument = searcher.doc(*doc_address).map_err(|e| e.to_string())?;
+            Ok(to_stored_document(search_index, &doc))
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+    let next_cursor = if has_more {
+        top_docs.last().map(|(id, _)| id.clone())
+    } else {
+        None
+    };
+
+    Ok(DocumentPage {
+        documents,
+        next_cursor,
+    })
+}
+
+// 저장된 필드로 문서를 만듭니다
+fn to_stored_document(search_index: &SearchIndex, doc: &TantivyDocument) -> StoredDocument {
+    StoredDocument {
+        id: stored_text(doc, search_index.id_field),
+        title: stored_text(doc, search_index.title_field),
+        body: stored_text(doc, search_index.body_field),
+        metadata: metadata_to_json_string(doc, search_index.metadata_field),
+        created_at: stored_timestamp(doc, search_index.created_at_field),
+        updated_at: stored_timestamp(doc, search_index.updated_at_field),
+    }
+}
+
+// 여러 문서 추가를 위한 입력 구조체
+#[derive(Clone, Debug)]
+pub struct DocumentInput {
+    pub id: String,
+    pub title: String,
+    pub body: String,
+    pub metadata: String, // JSON string
+}
+
+/// 저장된 문서 (시스템 필드 포함)
 #[derive(Clone, Debug)]
-pub struct DocumentInput {
+pub struct StoredDocument {
     pub id: String,
     pub title: String,
     pub body: String,
     pub metadata: String, // JSON string
+    /// 문서를 처음 추가한 시각 (Unix 시간 밀리초)
+    pub created_at: i64,
+    /// 문서를 마지막으로 추가/수정한 시각 (Unix 시간 밀리초)
+    pub updated_at: i64,
+}
+
+/// 전체 문서 목록의 한 페이지
+#[derive(Clone, Debug)]
+pub struct DocumentPage {
+    pub documents: Vec<StoredDocument>,
+    /// 다음 페이지를 읽을 때 넘길 커서 (마지막 페이지면 None)
+    pub next_cursor: Option<String>,
 }
 
 // UUID 생성 헬퍼 함수
@@ -946,6 +2666,44 @@ fn generate_uuid() -> String {
 mod tests {
     use super::*;
 
+    // 전역 인덱스를 사용하는 테스트는 병렬로 실행되지 않도록 직렬화합니다
+    static INDEX_TEST_LOCK: Mutex<()> = Mutex::new(());
+
+    fn lock_index() -> std::sync::MutexGuard<'static, ()> {
+        INDEX_TEST_LOCK.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    // (id, title, body, metadata) 목록을 한 번에 색인합니다
+    fn index_docs(documents: &[(&str, &str, &str, &str)]) {
+        add_documents(
+            documents
+                .iter()
+                .map(|(id, title, body, metadata)| DocumentInput {
+                    id: id.to_string(),
+                    title: title.to_string(),
+                    body: body.to_string(),
+                    metadata: metadata.to_string(),
+                })
+                .collect(),
+        )
+        .unwrap();
+    }
+
+    // 검색 결과의 문서 ID (결과 순서대로)
+    fn hit_ids(hits: &[SearchHit]) -> Vec<String> {
+        hits.iter().map(|hit| hit.id.clone()).collect()
+    }
+
+    // 기본 옵션으로 검색한 문서 ID (정렬해서 점수 순서와 무관하게 비교합니다)
+    fn sorted_hit_ids(query: &str) -> Vec<String> {
+        let hits = search_documents_with_options(query.to_string(), SearchOptions::default())
+            .unwrap()
+            .hits;
+        let mut ids = hit_ids(&hits);
+        ids.sort();
+        ids
+    }
+
     #[test]
     fn test_tokenize_text_korean() {
         let tokens = tokenize_text(DictionaryType::Korean, "한국어 형태소 분석".to_string(), TokenMode::Normal).unwrap();
@@ -988,6 +2746,7 @@ mod tests {
 
     #[test]
     fn test_initialize_search_index_with_path() {
+        let _guard = lock_index();
         let temp_dir = std::env::temp_dir().join("test_tantivy_index_dir");
         let path_str = temp_dir.to_str().unwrap().to_string();
 
@@ -998,8 +2757,100 @@ mod tests {
         let _ = std::fs::remove_dir_all(temp_dir);
     }
 
+    #[test]
+    fn test_reopen_index_checks_schema_version() {
+        let _guard = lock_index();
+        let temp_dir = std::env::temp_dir().join("test_tantivy_index_version");
+        let _ = std::fs::remove_dir_all(&temp_dir);
+        let path_str = temp_dir.to_str().unwrap().to_string();
+
+        // 새로 만든 인덱스는 문서를 추가하기 전에도, 추가한 뒤에도 다시 열 수 있습니다
+        initialize_search_index_with_path(DictionaryType::Korean, path_str.clone()).unwrap();
+        initialize_search_index_with_path(DictionaryType::Korean, path_str.clone()).unwrap();
+        add_document("인천 공항".to_string(), "본문".to_string(), "{}".to_string()).unwrap();
+        initialize_search_index_with_path(DictionaryType::Korean, path_str.clone()).unwrap();
+        assert_eq!(get_document_count().unwrap(), 1);
+        *SEARCH_INDEX.lock().unwrap() = None;
+        std::fs::remove_dir_all(&temp_dir).unwrap();
+
+        // 버전을 기록하지 않은 인덱스는 한 가지 오류로 거부합니다
+        std::fs::create_dir_all(&temp_dir).unwrap();
+        let schema = build_schema(DictionaryType::Korean.to_tokenizer_name());
+        let index = Index::create_in_dir(&temp_dir, schema).unwrap();
+        let mut index_writer: IndexWriter = index.writer(50_000_000).unwrap();
+        index_writer.commit().unwrap();
+        drop(index_writer);
+        let error =
+            initialize_search_index_with_path(DictionaryType::Korean, path_str).unwrap_err();
+        assert!(error.contains("스키마 버전(1)"), "{}", error);
+
+        let _ = std::fs::remove_dir_all(temp_dir);
+    }
+
+    #[test]
+    fn test_configured_settings_persist_with_index() {
+        let _guard = lock_index();
+        let temp_dir = std::env::temp_dir().join("test_tantivy_index_settings");
+        let _ = std::fs::remove_dir_all(&temp_dir);
+        let path_str = temp_dir.to_str().unwrap().to_string();
+
+        initialize_search_index_with_path(DictionaryType::JapaneseIpadic, path_str.clone()).unwrap();
+        configure_date_fields(vec!["published".to_string()]).unwrap();
+        configure_location_field("place.location".to_string()).unwrap();
+        configure_chosung_fields(false, true).unwrap();
+        configure_hanja_readings(true).unwrap();
+        configure_japanese_readings(true, true).unwrap();
+        add_document(
+            "関西国際空港".to_string(),
+            "大阪".to_string(),
+            r#"{"published":"2024-05-01"}"#.to_string(),
+        )
+        .unwrap();
+
+        // 문서를 추가한 커밋도 설정을 지우지 않고, 다시 열면 복원됩니다
+        initialize_search_index_with_path(DictionaryType::JapaneseIpadic, path_str.clone()).unwrap();
+        let settings = SEARCH_INDEX.lock().unwrap().as_ref().unwrap().settings();
+        assert_eq!(
+            settings,
+            IndexSettings {
+                date_paths: vec!["published".to_string()],
+                location_path: "place.location".to_string(),
+                chosung_title: false,
+                chosung_body: true,
+                hanja_readings: true,
+                japanese_readings: true,
+                romaji: true,
+                ..IndexSettings::default()
+            }
+        );
+        add_document("成田国際空港".to_string(), "千葉".to_string(), "{}".to_string()).unwrap();
+        let hits = search_documents_with_options("narita".to_string(), SearchOptions::default())
+            .unwrap()
+            .hits;
+        assert_eq!(hits.len(), 1);
+
+        // 설정을 기록하지 못하면 메모리의 설정도 바뀌지 않습니다 (다른 쓰기 중에는 기록할 수 없습니다)
+        let index = SEARCH_INDEX.lock().unwrap().as_ref().unwrap().index.clone();
+        let index_writer: IndexWriter = index.writer(50_000_000).unwrap();
+        assert!(configure_date_fields(vec!["updated".to_string()]).is_err());
+        assert!(configure_location_field("location".to_string()).is_err());
+        assert!(configure_chosung_fields(true, false).is_err());
+        assert!(configure_hanja_readings(false).is_err());
+        assert!(configure_japanese_readings(false, false).is_err());
+        let unchanged = SEARCH_INDEX.lock().unwrap().as_ref().unwrap().settings();
+        assert_eq!(unchanged, settings);
+        drop(index_writer);
+
+        // 다른 사전으로는 일본어 읽기 설정을 되살릴 수 없습니다
+        *SEARCH_INDEX.lock().unwrap() = None;
+        assert!(initialize_search_index_with_path(DictionaryType::Korean, path_str).is_err());
+
+        let _ = std::fs::remove_dir_all(temp_dir);
+    }
+
     #[test]
     fn test_full_document_crud_flow() {
+        let _guard = lock_index();
         // 1. Initialize RAM index
         let init_res = initialize_search_index(DictionaryType::Korean);
         assert!(init_res.is_ok());
@@ -1075,6 +2926,1683 @@ mod tests {
         assert!(clear_res.is_ok());
         assert_eq!(get_document_count().unwrap(), 0);
     }
-}
 
+    #[test]
+    fn test_search_with_snippet_highlights() {
+        let _guard = lock_index();
+        initialize_search_index(DictionaryType::Korean).unwrap();
+        clear_all_documents().unwrap();
+        add_document(
+            "인천국제공항".to_string(),
+            "인천국제공항은 대한민국 인천광역시 중구 운서동에 있는 국제공항이다.".to_string(),
+            "{}".to_string(),
+        )
+        .unwrap();
+
+        let options = SearchOptions {
+            snippet: Some(SnippetOptions {
+                html: true,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let hits = search_documents_with_options("운서동".to_string(), options).unwrap().hits;
+        assert_eq!(hits.len(), 1);
+
+        let snippet = hits[0].snippet.as_ref().unwrap();
+        assert!(!snippet.highlights.is_empty());
+        let utf16: Vec<u16> = snippet.fragment.encode_utf16().collect();
+        let first = &snippet.highlights[0];
+        let highlighted = String::from_utf16(&utf16[first.start as usize..first.end as usize]).unwrap();
+        assert_eq!(highlighted, "운서동");
+        assert!(snippet.html.as_ref().unwrap().contains("<b>운서동</b>"));
+
+        // N-gram 필드로만 매칭되는 부분 검색어도 하이라이트됩니다
+        let options = SearchOptions {
+            snippet: Some(SnippetOptions::default()),
+            ..Default::default()
+        };
+        let hits = search_documents_with_options("광역".to_string(), options).unwrap().hits;
+        assert_eq!(hits.len(), 1);
+        assert!(!hits[0].snippet.as_ref().unwrap().highlights.is_empty());
+        assert!(hits[0].snippet.as_ref().unwrap().html.is_none());
+    }
+
+    #[test]
+    fn test_to_utf16_ranges() {
+        let text = "가a😀b";
+        // "가"=3바이트, "a"=1, "😀"=4(UTF-16 2 단위), "b"=1
+        let ranges = to_utf16_ranges(text, &[4..8, 4..9]);
+        assert_eq!(ranges.len(), 1);
+        assert_eq!(ranges[0].start, 2);
+        assert_eq!(ranges[0].end, 5);
+    }
+
+    #[test]
+    fn test_fuzzy_search_ranks_exact_matches_first() {
+        let _guard = lock_index();
+        initialize_search_index(DictionaryType::Korean).unwrap();
+        clear_all_documents().unwrap();
+        index_docs(&[
+            ("exact", "Incheon Airport", "Incheon International Airport", "{}"),
+            ("typo", "Inchoen Airport", "Inchoen International Airport", "{}"),
+        ]);
+
+        // 퍼지 검색을 사용하지 않으면 오타는 결과가 없습니다
+        assert!(search_documents("Incheonn".to_string(), 10).unwrap().is_empty());
+
+        let options = SearchOptions {
+            fuzzy: Some(FuzzyOptions::default()),
+            ..Default::default()
+        };
+        let hits = search_documents_with_options("Incheonn".to_string(), options.clone())
+            .unwrap()
+            .hits;
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].id, "exact");
+
+        // transposition 을 허용하면 정확히 일치하는 문서가 먼저 나옵니다
+        let hits = search_documents_with_options("Incheon".to_string(), options)
+            .unwrap()
+            .hits;
+        assert_eq!(hits.len(), 2);
+        assert_eq!(hits[0].id, "exact");
+        assert_eq!(hits[1].id, "typo");
+
+        // 앞 글자가 다르면 prefix_length 가드에 걸립니다
+        let options = SearchOptions {
+            fuzzy: Some(FuzzyOptions::default()),
+            ..Default::default()
+        };
+        assert!(search_documents_with_options("Jncheon".to_string(), options)
+            .unwrap()
+            .hits
+            .is_empty());
+
+        let invalid = SearchOptions {
+            fuzzy: Some(FuzzyOptions {
+                distance: 3,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        assert!(search_documents_with_options("Incheon".to_string(), invalid).is_err());
+    }
+
+    #[test]
+    fn test_autocomplete_titles_and_terms() {
+        let _guard = lock_index();
+        initialize_search_index(DictionaryType::Korean).unwrap();
+        clear_all_documents().unwrap();
+        index_sample_documents().unwrap();
+
+        let titles = autocomplete("인천국".to_string(), 5, SuggestionSource::Title).unwrap();
+        assert_eq!(titles.len(), 1);
+        assert_eq!(titles[0].text, "인천국제공항");
+
+        let titles = autocomplete("関西".to_string(), 5, SuggestionSource::Title).unwrap();
+        assert_eq!(titles[0].text, "関西国際空港");
+
+        // 제목 앞부분이 아닌 경우는 후보가 아닙니다
+        assert!(autocomplete("국제공항".to_string(), 5, SuggestionSource::Title)
+            .unwrap()
+            .is_empty());
+
+        let terms = autocomplete("국제".to_string(), 5, SuggestionSource::Term).unwrap();
+        assert!(!terms.is_empty());
+        assert!(terms.iter().all(|s| s.text.starts_with("국제")));
+        assert!(terms.windows(2).all(|w| w[0].doc_count >= w[1].doc_count));
+
+        assert!(autocomplete(" ".to_string(), 5, SuggestionSource::Term)
+            .unwrap()
+            .is_empty());
+
+        // 대소문자를 구분하지 않고, 제목과 본문에 모두 나와도 한 문서로 세며, 삭제된 문서는 세지 않습니다
+        clear_all_documents().unwrap();
+        index_docs(&[
+            ("x", "Incheon", "Incheon", "{}"),
+            ("y", "Incheon", "", "{}"),
+            ("z", "Incheon", "", "{}"),
+        ]);
+        delete_document("z".to_string()).unwrap();
+        let terms = autocomplete("inch".to_string(), 5, SuggestionSource::Term).unwrap();
+        assert_eq!(terms.len(), 1);
+        assert_eq!(terms[0].text, "Incheon");
+        assert_eq!(terms[0].doc_count, 2);
+
+        // 같은 제목의 문서가 많아도 서로 다른 제목을 limit 개까지 채웁니다
+        clear_all_documents().unwrap();
+        let titles = std::iter::repeat_n("공항 가", 20).chain(["공항 나", "공항 다", "공항 라"]);
+        add_documents(
+            titles
+                .enumerate()
+                .map(|(i, title)| DocumentInput {
+                    id: format!("doc{}", i),
+                    title: title.to_string(),
+                    body: String::new(),
+                    metadata: "{}".to_string(),
+                })
+                .collect(),
+        )
+        .unwrap();
+        let titles = autocomplete("공항".to_string(), 3, SuggestionSource::Title).unwrap();
+        assert_eq!(titles.len(), 3);
+        assert_eq!(titles.iter().find(|s| s.text == "공항 가").unwrap().doc_count, 20);
+    }
+
+    // 문서 5만 개에서 키 입력마다 부를 수 있을 만큼 빠른지 측정합니다 (시간은 출력만 합니다)
+    // cargo test --release -- --ignored bench_autocomplete --nocapture
+    #[test]
+    #[ignore]
+    fn bench_autocomplete_50k_documents() {
+        let _guard = lock_index();
+        initialize_search_index(DictionaryType::Korean).unwrap();
+        clear_all_documents().unwrap();
+        let cities = ["인천", "김포", "제주", "부산", "대구", "광주", "청주", "울산"];
+        // 한 번에 커밋해야 측정 중에 세그먼트 병합이 돌지 않습니다
+        add_documents(
+            (0..50_000)
+                .map(|n| DocumentInput {
+                    id: format!("doc{}", n),
+                    title: format!("{}국제공항 안내 {}", cities[n % cities.len()], n),
+                    body: format!("{} 공항 이용 안내 문서 {}", cities[n % cities.len()], n),
+                    metadata: "{}".to_string(),
+                })
+                .collect(),
+        )
+        .unwrap();
+
+        let prefixes = ["인", "인천", "인천국", "김포국제", "제주국제공항 안내 1"];
+        let rounds = 100;
+        for source in [SuggestionSource::Title, SuggestionSource::Term] {
+            let started = std::time::Instant::now();
+            for _ in 0..rounds {
+                for prefix in prefixes {
+                    autocomplete(prefix.to_string(), 10, source.clone()).unwrap();
+                }
+            }
+            let average = started.elapsed() / (rounds * prefixes.len()) as u32;
+            println!("autocomplete {:?}: {:?} / call", source, average);
+        }
+    }
+
+    #[test]
+    fn test_search_with_structured_query() {
+        use crate::api::query::{RangeBound, RangeValue};
+
+        let _guard = lock_index();
+        initialize_search_index(DictionaryType::Korean).unwrap();
+        clear_all_documents().unwrap();
+        index_docs(&[
+            ("a", "인천 공항 (ICN)", "인천 국제 공항: 대한민국", r#"{"country":"한국","rank":1}"#),
+            ("b", "김포 공항", "김포 국제 공항 - 서울", r#"{"country":"한국","rank":5}"#),
+            ("c", "나리타 공항", "나리타 국제 공항", r#"{"country":"일본","rank":3}"#),
+        ]);
+
+        let ids = |query: QueryNode| -> Vec<String> {
+            let mut ids = hit_ids(&search_with_query(query, SearchOptions::default()).unwrap().hits);
+            ids.sort();
+            ids
+        };
+
+        // 쿼리 문법 문자가 들어 있어도 이스케이프 없이 검색됩니다
+        assert_eq!(
+            ids(QueryNode::Term {
+                field: "body".to_string(),
+                text: "인천 국제 공항:".to_string(),
+            }),
+            vec!["a"]
+        );
+        assert_eq!(
+            ids(QueryNode::Phrase {
+                field: "body".to_string(),
+                text: "인천 공항".to_string(),
+                slop: 1,
+            }),
+            vec!["a"]
+        );
+        assert_eq!(
+            ids(QueryNode::Boolean {
+                must: vec![QueryNode::Term {
+                    field: "metadata.country".to_string(),
+                    text: "한국".to_string(),
+                }],
+                should: vec![],
+                must_not: vec![QueryNode::Prefix {
+                    field: "title".to_string(),
+                    prefix: "김".to_string(),
+                }],
+            }),
+            vec!["a"]
+        );
+        assert_eq!(
+            ids(QueryNode::Range {
+                field: "metadata.rank".to_string(),
+                lower: Some(RangeBound {
+                    value: RangeValue::Integer(3),
+                    inclusive: true,
+                }),
+                upper: None,
+            }),
+            vec!["b", "c"]
+        );
+        assert_eq!(
+            ids(QueryNode::Fuzzy {
+                field: "title".to_string(),
+                text: "나리티".to_string(),
+                distance: 1,
+                transposition: false,
+            }),
+            vec!["c"]
+        );
+        assert_eq!(ids(QueryNode::MatchAll).len(), 3);
+
+        let hits = search_with_query(
+            QueryNode::Boolean {
+                must: vec![],
+                should: vec![
+                    QueryNode::Term {
+                        field: "title".to_string(),
+                        text: "공항".to_string(),
+                    },
+                    QueryNode::Boost {
+                        query: Box::new(QueryNode::Term {
+                            field: "title".to_string(),
+                            text: "나리타".to_string(),
+                        }),
+                        boost: 10.0,
+                    },
+                ],
+                must_not: vec![],
+            },
+            SearchOptions::default(),
+        )
+        .unwrap()
+        .hits;
+        assert_eq!(hits[0].id, "c");
+        for boost in [-1.0, f32::NAN, f32::INFINITY] {
+            assert!(search_with_query(
+                QueryNode::Boost {
+                    query: Box::new(QueryNode::MatchAll),
+                    boost,
+                },
+                SearchOptions::default(),
+            )
+            .is_err());
+        }
+
+        assert!(search_with_query(
+            QueryNode::Term {
+                field: "unknown".to_string(),
+                text: "x".to_string(),
+            },
+            SearchOptions::default(),
+        )
+        .is_err());
+
+        // 메타데이터 숫자는 정수와 실수가 섞여 있어도 경계 값의 타입과 상관없이 비교합니다
+        index_docs(&[("d", "청주 공항", "청주 국제 공항", r#"{"rank":4.5}"#)]);
+        let rank_range = |lower: Option<(RangeValue, bool)>, upper: Option<(RangeValue, bool)>| {
+            let bound = |bound: Option<(RangeValue, bool)>| {
+                bound.map(|(value, inclusive)| RangeBound { value, inclusive })
+            };
+            ids(QueryNode::Range {
+                field: "metadata.rank".to_string(),
+                lower: bound(lower),
+                upper: bound(upper),
+            })
+        };
+        assert_eq!(rank_range(Some((RangeValue::Integer(4), true)), None), vec!["b", "d"]);
+        assert_eq!(rank_range(None, Some((RangeValue::Float(4.5), true))), vec!["a", "c", "d"]);
+        assert_eq!(rank_range(None, Some((RangeValue::Float(4.5), false))), vec!["a", "c"]);
+        assert_eq!(
+            rank_range(
+                Some((RangeValue::Float(1.5), false)),
+                Some((RangeValue::Integer(5), false))
+            ),
+            vec!["c", "d"]
+        );
+        assert_eq!(rank_range(Some((RangeValue::Float(3.0), false)), None), vec!["b", "d"]);
+    }
+
+    #[test]
+    fn test_lenient_search_returns_diagnostics() {
+        let _guard = lock_index();
+        initialize_search_index(DictionaryType::Korean).unwrap();
+        clear_all_documents().unwrap();
+        add_document(
+            "인천국제공항".to_string(),
+            "인천국제공항은 대한민국의 국제공항이다.".to_string(),
+            "{}".to_string(),
+        )
+        .unwrap();
+
+        // 엄격 모드에서는 따옴표가 닫히지 않으면 오류입니다
+        assert!(search_documents("인천 \"공항".to_string(), 10).is_err());
+
+        let options = SearchOptions {
+            lenient: true,
+            ..Default::default()
+        };
+        let response = search_documents_with_options("인천 \"공항".to_string(), options).unwrap();
+        assert_eq!(response.hits.len(), 1);
+        assert!(!response.diagnostics.is_empty());
+        // 위치는 UTF-16 오프셋입니다 (닫는 따옴표가 필요한 문자열 끝)
+        assert!(response.diagnostics.iter().any(|d| d.position == Some(6)));
+
+        let options = SearchOptions {
+            lenient: true,
+            ..Default::default()
+        };
+        let response = search_documents_with_options("인천".to_string(), options).unwrap();
+        assert_eq!(response.hits.len(), 1);
+        assert!(response.diagnostics.is_empty());
+    }
+
+    #[test]
+    fn test_search_sorted_by_fast_fields() {
+        use crate::api::sort::{SortBy, SortOrder};
+
+        let _guard = lock_index();
+        initialize_search_index(DictionaryType::Korean).unwrap();
+        clear_all_documents().unwrap();
+        index_docs(&[
+            ("a", "인천 공항", "인천 국제 공항", r#"{"country":"한국","rank":2}"#),
+            ("b", "김포 공항", "김포 국제 공항", r#"{"country":"한국","rank":1}"#),
+            ("c", "나리타 공항", "나리타 국제 공항", r#"{"country":"일본","rank":3}"#),
+            ("d", "제주 공항", "제주 국제 공항", r#"{"country":"한국"}"#),
+        ]);
+
+        let search = |sort: Vec<SortKey>| -> Vec<SearchHit> {
+            let options = SearchOptions {
+                sort,
+                ..Default::default()
+            };
+            search_documents_with_options("공항".to_string(), options)
+                .unwrap()
+                .hits
+        };
+        let key = |field: &str, order: SortOrder| SortKey {
+            by: SortBy::Field(field.to_string()),
+            order,
+        };
+
+        // 값이 없는 문서는 방향과 관계없이 마지막입니다
+        let hits = search(vec![key("metadata.rank", SortOrder::Asc)]);
+        assert_eq!(hit_ids(&hits), vec!["b", "a", "c", "d"]);
+        assert_eq!(hits[0].sort_values, vec![SortValue::Integer(1)]);
+        assert_eq!(hits[3].sort_values, vec![SortValue::Missing]);
+        assert!(hits[0].score > 0.0);
+
+        let hits = search(vec![key("metadata.rank", SortOrder::Desc)]);
+        assert_eq!(hit_ids(&hits), vec!["c", "a", "b", "d"]);
+
+        // 여러 키: 국가(문자열) 내림차순 후 순위 오름차순
+        let hits = search(vec![
+            key("metadata.country", SortOrder::Desc),
+            key("metadata.rank", SortOrder::Asc),
+        ]);
+        assert_eq!(hit_ids(&hits), vec!["b", "a", "d", "c"]);
+        assert_eq!(
+            hits[0].sort_values,
+            vec![SortValue::Text("한국".to_string()), SortValue::Integer(1)]
+        );
+
+        // 기본 검색은 정렬 값이 비어 있습니다
+        assert!(search(vec![])[0].sort_values.is_empty());
+
+        // fast field 가 아닌 필드는 정렬할 수 없습니다
+        let options = SearchOptions {
+            sort: vec![key("body", SortOrder::Asc)],
+            ..Default::default()
+        };
+        assert!(search_documents_with_options("공항".to_string(), options).is_err());
+    }
+
+    #[test]
+    fn test_search_with_aggregations() {
+        use crate::api::aggregation::{AggregationKind, AggregationRange, AggregationValue};
+
+        let _guard = lock_index();
+        initialize_search_index(DictionaryType::Korean).unwrap();
+        clear_all_documents().unwrap();
+        let documents = [
+            ("인천 공항", r#"{"country":"한국","year":2001,"published":"2024-01-01T10:00:00Z"}"#),
+            ("김포 공항", r#"{"country":"한국","year":1958,"published":"2024-01-01T20:00:00Z"}"#),
+            ("제주 공항", r#"{"country":"한국","year":1968,"published":"2024-01-03T00:00:00Z"}"#),
+            ("나리타 공항", r#"{"country":"일본","year":1978,"published":"2024-01-03T12:00:00Z"}"#),
+            ("도쿄 문서", r#"{"country":"일본","year":1931}"#),
+        ];
+        index_docs(&documents.map(|(title, metadata)| (title, title, title, metadata)));
+
+        let request = |name: &str, kind: AggregationKind| AggregationRequest {
+            name: name.to_string(),
+            kind,
+        };
+        let options = SearchOptions {
+            limit: 1,
+            aggregations: vec![
+                request(
+                    "country",
+                    AggregationKind::Terms {
+                        field: "metadata.country".to_string(),
+                        size: 10,
+                    },
+                ),
+                request(
+                    "era",
+                    AggregationKind::Range {
+                        field: "metadata.year".to_string(),
+                        ranges: vec![
+                            AggregationRange {
+                                from: None,
+                                to: Some(1970.0),
+                            },
+                            AggregationRange {
+                                from: Some(1970.0),
+                                to: None,
+                            },
+                        ],
+                    },
+                ),
+                request(
+                    "decade",
+                    AggregationKind::Histogram {
+                        field: "metadata.year".to_string(),
+                        interval: 10.0,
+                    },
+                ),
+                request(
+                    "day",
+                    AggregationKind::DateHistogram {
+                        field: "metadata.published".to_string(),
+                        fixed_interval: "1d".to_string(),
+                    },
+                ),
+                request(
+                    "year_stats",
+                    AggregationKind::Stats {
+                        field: "metadata.year".to_string(),
+                    },
+                ),
+                request(
+                    "oldest",
+                    AggregationKind::Min {
+                        field: "metadata.year".to_string(),
+                    },
+                ),
+            ],
+            ..Default::default()
+        };
+        let response = search_documents_with_options("공항".to_string(), options).unwrap();
+
+        // 집계는 limit 와 관계없이 매칭된 문서 전체(공항 4개)에 대해 계산됩니다
+        assert_eq!(response.hits.len(), 1);
+        let names: Vec<&str> = response.aggregations.iter().map(|a| a.name.as_str()).collect();
+        assert_eq!(names, vec!["country", "era", "decade", "day", "year_stats", "oldest"]);
+
+        let buckets = |index: usize| -> Vec<(String, u64)> {
+            match &response.aggregations[index].value {
+                AggregationValue::Buckets(buckets) => buckets
+                    .iter()
+                    .map(|bucket| (bucket.key.clone(), bucket.doc_count))
+                    .collect(),
+                other => panic!("unexpected aggregation value: {:?}", other),
+            }
+        };
+        assert_eq!(
+            buckets(0),
+            vec![("한국".to_string(), 3), ("일본".to_string(), 1)]
+        );
+        assert_eq!(buckets(1).iter().map(|b| b.1).collect::<Vec<_>>(), vec![2, 2]);
+        assert_eq!(
+            buckets(2),
+            vec![
+                ("1950".to_string(), 1),
+                ("1960".to_string(), 1),
+                ("1970".to_string(), 1),
+                ("1980".to_string(), 0),
+                ("1990".to_string(), 0),
+                ("2000".to_string(), 1),
+            ]
+        );
+        let days = buckets(3);
+        assert_eq!(days.iter().map(|b| b.1).collect::<Vec<_>>(), vec![2, 0, 2]);
+        assert!(days[0].0.starts_with("2024-01-01"));
+
+        match &response.aggregations[4].value {
+            AggregationValue::Stats(stats) => {
+                assert_eq!(stats.count, 4);
+                assert_eq!(stats.min, Some(1958.0));
+                assert_eq!(stats.max, Some(2001.0));
+            }
+            other => panic!("unexpected aggregation value: {:?}", other),
+        }
+        assert!(matches!(
+            response.aggregations[5].value,
+            AggregationValue::Metric(Some(value)) if value == 1958.0
+        ));
+
+        // 집계를 요청하지 않으면 결과도 비어 있습니다
+        let response =
+            search_documents_with_options("공항".to_string(), SearchOptions::default()).unwrap();
+        assert!(response.aggregations.is_empty());
+    }
+
+    #[test]
+    fn test_category_filter_and_counts() {
+        let _guard = lock_index();
+        initialize_search_index(DictionaryType::Korean).unwrap();
+        clear_all_documents().unwrap();
+        let documents = [
+            ("나리타 공항", r#"{"category":"/travel/asia/japan"}"#),
+            ("간사이 공항", r#"{"category":["/travel/asia/japan","/transport/air"]}"#),
+            ("인천 공항", r#"{"category":"/travel/asia/korea"}"#),
+            ("제주 공항", r#"{"category":"/travel/europe"}"#),
+            ("김포 공항", r#"{"category":"잘못된 경로"}"#),
+        ];
+        index_docs(&documents.map(|(title, metadata)| (title, title, title, metadata)));
+
+        // 하위 카테고리까지 포함하여 필터링합니다
+        let options = SearchOptions {
+            category_filter: Some("/travel/asia".to_string()),
+            category_counts: vec![
+                "/".to_string(),
+                "/travel".to_string(),
+                "/travel/asia".to_string(),
+            ],
+            ..Default::default()
+        };
+        let response = search_documents_with_options("공항".to_string(), options).unwrap();
+        let mut ids = hit_ids(&response.hits);
+        ids.sort();
+        assert_eq!(ids, vec!["간사이 공항", "나리타 공항", "인천 공항"]);
+
+        let counts = |index: usize| -> Vec<(String, u64)> {
+            response.category_counts[index]
+                .children
+                .iter()
+                .map(|child| (child.path.clone(), child.count))
+                .collect()
+        };
+        assert_eq!(response.category_counts[0].parent, "/");
+        assert_eq!(
+            counts(0),
+            vec![("/transport".to_string(), 1), ("/travel".to_string(), 3)]
+        );
+        assert_eq!(counts(1), vec![("/travel/asia".to_string(), 3)]);
+        assert_eq!(
+            counts(2),
+            vec![
+                ("/travel/asia/japan".to_string(), 2),
+                ("/travel/asia/korea".to_string(), 1),
+            ]
+        );
+
+        // 구조화 쿼리에서도 카테고리로 검색할 수 있습니다
+        let response = search_with_query(
+            QueryNode::Facet {
+                field: "category".to_string(),
+                path: "/travel/europe".to_string(),
+            },
+            SearchOptions::default(),
+        )
+        .unwrap();
+        assert_eq!(response.hits.len(), 1);
+        assert_eq!(response.hits[0].id, "제주 공항");
+
+        let options = SearchOptions {
+            category_filter: Some("travel".to_string()),
+            ..Default::default()
+        };
+        assert!(search_documents_with_options("공항".to_string(), options).is_err());
+    }
+
+    #[test]
+    fn test_date_fields_range_sort_and_histogram() {
+        use crate::api::aggregation::{AggregationKind, AggregationValue};
+        use crate::api::query::{RangeBound, RangeValue};
+        use crate::api::sort::{SortBy, SortOrder};
+
+        let _guard = lock_index();
+        initialize_search_index(DictionaryType::Korean).unwrap();
+        clear_all_documents().unwrap();
+        configure_date_fields(vec!["createdAt".to_string(), "event.date".to_string()]).unwrap();
+        let documents = [
+            ("a", r#"{"createdAt":"2024-01-15"}"#),
+            ("b", r#"{"createdAt":"2024-02-20T09:30:00+09:00"}"#),
+            ("c", r#"{"createdAt":"2024-02-01","event":{"date":"2024-03-01"}}"#),
+            ("d", r#"{"createdAt":"날짜 아님"}"#),
+        ];
+        index_docs(&documents.map(|(id, metadata)| (id, "공항", "공항", metadata)));
+
+        let ids = |query: QueryNode, options: SearchOptions| -> Vec<String> {
+            hit_ids(&search_with_query(query, options).unwrap().hits)
+        };
+        let since = |field: &str, date: &str| QueryNode::Range {
+            field: field.to_string(),
+            lower: Some(RangeBound {
+                value: RangeValue::Date(date.to_string()),
+                inclusive: true,
+            }),
+            upper: None,
+        };
+
+        // 날짜 범위 + 날짜 내림차순 정렬 (날짜가 아닌 값은 마지막)
+        let options = SearchOptions {
+            sort: vec![SortKey {
+                by: SortBy::Field("dates.createdAt".to_string()),
+                order: SortOrder::Desc,
+            }],
+            ..Default::default()
+        };
+        assert_eq!(
+            ids(since("dates.createdAt", "2024-02-01"), options.clone()),
+            vec!["b", "c"]
+        );
+        assert_eq!(
+            ids(QueryNode::MatchAll, options.clone()),
+            vec!["b", "c", "a", "d"]
+        );
+        let hits = search_with_query(QueryNode::MatchAll, options).unwrap().hits;
+        // 2024-02-20T09:30:00+09:00 = 2024-02-20T00:30:00Z
+        assert_eq!(hits[0].sort_values, vec![SortValue::Integer(1_708_389_000_000)]);
+
+        assert_eq!(
+            ids(since("dates.event.date", "2024-02-15"), SearchOptions::default()),
+            vec!["c"]
+        );
+
+        // 일 단위 날짜 히스토그램
+        let options = SearchOptions {
+            aggregations: vec![AggregationRequest {
+                name: "created".to_string(),
+                kind: AggregationKind::DateHistogram {
+                    field: "dates.createdAt".to_string(),
+                    fixed_interval: "1d".to_string(),
+                },
+            }],
+            ..Default::default()
+        };
+        let response = search_with_query(QueryNode::MatchAll, options).unwrap();
+        match &response.aggregations[0].value {
+            AggregationValue::Buckets(buckets) => {
+                assert_eq!(buckets.iter().map(|b| b.doc_count).sum::<u64>(), 3);
+                assert!(buckets[0].key.starts_with("2024-01-15"));
+            }
+            other => panic!("unexpected aggregation value: {:?}", other),
+        }
+
+        assert!(search_with_query(since("dates.createdAt", "어제"), SearchOptions::default()).is_err());
+    }
+
+    #[test]
+    fn test_created_and_updated_timestamps() {
+        use crate::api::query::{RangeBound, RangeValue};
+        use crate::api::sort::{SortBy, SortOrder};
+        use std::thread::sleep;
+        use std::time::Duration;
+
+        let _guard = lock_index();
+        initialize_search_index(DictionaryType::Korean).unwrap();
+        clear_all_documents().unwrap();
+
+        let hit = |id: &str| -> SearchHit {
+            search_with_query(
+                QueryNode::Term {
+                    field: "id".to_string(),
+                    text: id.to_string(),
+                },
+                SearchOptions::default(),
+            )
+            .unwrap()
+            .hits
+            .remove(0)
+        };
+
+        index_docs(&[("a", "인천 공항", "인천", "{}")]);
+        let created = hit("a");
+        assert!(created.created_at > 0);
+        assert_eq!(created.created_at, created.updated_at);
+
+        sleep(Duration::from_millis(5));
+        index_docs(&[("b", "김포 공항", "김포", "{}")]);
+        sleep(Duration::from_millis(5));
+
+        // 수정해도 생성 시각은 유지되고 수정 시각만 바뀝니다
+        update_document(
+            "a".to_string(),
+            "인천국제공항".to_string(),
+            "인천".to_string(),
+            "{}".to_string(),
+        )
+        .unwrap();
+        let updated = hit("a");
+        assert_eq!(updated.created_at, created.created_at);
+        assert!(updated.updated_at > created.updated_at);
+
+        // 기존 search_documents 결과에도 같은 시각이 들어 있습니다
+        let result = search_documents("인천국제공항".to_string(), 10).unwrap().remove(0);
+        assert_eq!(result.id, "a");
+        assert_eq!(result.created_at, updated.created_at);
+        assert_eq!(result.updated_at, updated.updated_at);
+
+        // 생성 시각으로 필터링 (Unix 시간 밀리초)
+        let ids = |query: QueryNode, sort: Vec<SortKey>| -> Vec<String> {
+            let options = SearchOptions {
+                sort,
+                ..Default::default()
+            };
+            hit_ids(&search_with_query(query, options).unwrap().hits)
+        };
+        let created_after = QueryNode::Range {
+            field: "created_at".to_string(),
+            lower: Some(RangeBound {
+                value: RangeValue::Integer(created.created_at),
+                inclusive: false,
+            }),
+            upper: None,
+        };
+        assert_eq!(ids(created_after, vec![]), vec!["b"]);
+
+        // 생성 시각 / 수정 시각 정렬
+        let sort = |field: &str| {
+            vec![SortKey {
+                by: SortBy::Field(field.to_string()),
+                order: SortOrder::Desc,
+            }]
+        };
+        assert_eq!(ids(QueryNode::MatchAll, sort("created_at")), vec!["b", "a"]);
+        assert_eq!(ids(QueryNode::MatchAll, sort("updated_at")), vec!["a", "b"]);
+    }
+
+    #[test]
+    fn test_geo_filters_and_distance_sort() {
+        use crate::api::sort::SortOrder;
+
+        let _guard = lock_index();
+        initialize_search_index(DictionaryType::Korean).unwrap();
+        clear_all_documents().unwrap();
+        let documents = [
+            ("인천 공항", r#"{"location":{"lat":37.4602,"lon":126.4407}}"#),
+            ("김포 공항", r#"{"location":{"lat":37.5583,"lon":126.7906}}"#),
+            ("제주 공항", r#"{"location":{"lat":33.5113,"lon":126.4930}}"#),
+            ("나리타 공항", r#"{"location":{"lat":35.7720,"lon":140.3929}}"#),
+            ("공항 소개", r#"{}"#),
+        ];
+        index_docs(&documents.map(|(title, metadata)| (title, title, title, metadata)));
+
+        let seoul = GeoPoint {
+            lat: 37.5665,
+            lon: 126.9780,
+        };
+        let nearest_first = vec![SortKey {
+            by: SortBy::Distance(seoul),
+            order: SortOrder::Asc,
+        }];
+
+        // 서울에서 60km 이내, 가까운 순 (텍스트 검색과 함께)
+        let options = SearchOptions {
+            geo_filter: Some(GeoFilter::Radius {
+                center: seoul,
+                distance_km: 60.0,
+            }),
+            sort: nearest_first.clone(),
+            ..Default::default()
+        };
+        let hits = search_documents_with_options("공항".to_string(), options)
+            .unwrap()
+            .hits;
+        assert_eq!(hit_ids(&hits), vec!["김포 공항", "인천 공항"]);
+        let distance = hits[0].distance_km.unwrap();
+        assert!((15.0..20.0).contains(&distance), "{}", distance);
+        assert_eq!(hits[0].sort_values, vec![SortValue::Float(distance)]);
+
+        // 거리 정렬만 하면 좌표가 없는 문서는 마지막입니다
+        let options = SearchOptions {
+            sort: nearest_first,
+            ..Default::default()
+        };
+        let hits = search_documents_with_options("공항".to_string(), options)
+            .unwrap()
+            .hits;
+        assert_eq!(
+            hit_ids(&hits),
+            vec!["김포 공항", "인천 공항", "제주 공항", "나리타 공항", "공항 소개"]
+        );
+        assert_eq!(hits[4].distance_km, None);
+
+        // 영역 필터 (구조화 쿼리)
+        let mut korea = search_with_query(
+            QueryNode::Geo {
+                filter: GeoFilter::BoundingBox {
+                    top_left: GeoPoint { lat: 39.0, lon: 124.0 },
+                    bottom_right: GeoPoint { lat: 33.0, lon: 131.0 },
+                },
+            },
+            SearchOptions::default(),
+        )
+        .unwrap()
+        .hits;
+        korea.sort_by(|a, b| a.id.cmp(&b.id));
+        assert_eq!(hit_ids(&korea), vec!["김포 공항", "인천 공항", "제주 공항"]);
+        assert_eq!(korea[0].distance_km, None);
+
+        // 날짜 변경선을 넘는 반경 (후보 경도 범위가 양쪽으로 나뉩니다)
+        index_docs(&[
+            ("피지 공항", "피지 공항", "피지 공항", r#"{"location":{"lat":-17.7554,"lon":177.4434}}"#),
+            ("사모아 공항", "사모아 공항", "사모아 공항", r#"{"location":{"lat":-13.8300,"lon":-171.9973}}"#),
+        ]);
+        let mut pacific = search_with_query(
+            QueryNode::Geo {
+                filter: GeoFilter::Radius {
+                    center: GeoPoint { lat: -15.0, lon: 179.9 },
+                    distance_km: 1000.0,
+                },
+            },
+            SearchOptions::default(),
+        )
+        .unwrap()
+        .hits;
+        pacific.sort_by(|a, b| a.id.cmp(&b.id));
+        assert_eq!(hit_ids(&pacific), vec!["사모아 공항", "피지 공항"]);
+
+        let options = SearchOptions {
+            geo_filter: Some(GeoFilter::Radius {
+                center: GeoPoint { lat: 91.0, lon: 0.0 },
+                distance_km: 10.0,
+            }),
+            ..Default::default()
+        };
+        assert!(search_documents_with_options("공항".to_string(), options).is_err());
+
+        // 가중치를 준 좌표 필터의 설명은 검색 점수와 같습니다
+        let incheon_area = QueryNode::Geo {
+            filter: GeoFilter::Radius {
+                center: GeoPoint { lat: 37.46, lon: 126.44 },
+                distance_km: 5.0,
+            },
+        };
+        let boosted = QueryNode::Boost {
+            query: Box::new(incheon_area),
+            boost: 2.5,
+        };
+        let hits = search_with_query(boosted.clone(), SearchOptions::default())
+            .unwrap()
+            .hits;
+        assert_eq!(hit_ids(&hits), vec!["인천 공항"]);
+        let explanation =
+            explain_structured_query(boosted, "인천 공항".to_string(), SearchOptions::default())
+                .unwrap();
+        assert!((explanation.value - hits[0].score).abs() < 1e-4);
+        assert!((explanation.value - 2.5).abs() < 1e-4);
+
+        // 좌표를 읽을 메타데이터 경로를 바꾸면 이후에 추가한 문서부터 적용됩니다
+        assert!(configure_location_field("place..location".to_string()).is_err());
+        configure_location_field("place.location".to_string()).unwrap();
+        index_docs(&[(
+            "김해 공항",
+            "김해 공항",
+            "김해 공항",
+            r#"{"place":{"location":{"lat":35.1795,"lon":128.9382}}}"#,
+        )]);
+        let options = SearchOptions {
+            geo_filter: Some(GeoFilter::Radius {
+                center: GeoPoint { lat: 35.18, lon: 128.94 },
+                distance_km: 10.0,
+            }),
+            ..Default::default()
+        };
+        let hits = search_documents_with_options("공항".to_string(), options)
+            .unwrap()
+            .hits;
+        assert_eq!(hit_ids(&hits), vec!["김해 공항"]);
+        configure_location_field("location".to_string()).unwrap();
+    }
+
+    #[test]
+    fn test_collapse_and_group_by() {
+        use crate::api::sort::SortOrder;
+
+        let _guard = lock_index();
+        initialize_search_index(DictionaryType::Korean).unwrap();
+        clear_all_documents().unwrap();
+        let documents = [
+            ("a", "도쿄 공항 공항 공항", r#"{"city":"도쿄","rank":3}"#),
+            ("b", "도쿄 공항 공항", r#"{"city":"도쿄","rank":1}"#),
+            ("c", "도쿄 공항", r#"{"city":"도쿄","rank":2}"#),
+            ("d", "서울 공항 공항", r#"{"city":"서울","rank":5}"#),
+            ("e", "서울 공항", r#"{"city":"서울","rank":4}"#),
+            ("f", "공항", r#"{}"#),
+            ("g", "공항 공항", r#"{}"#),
+        ];
+        index_docs(&documents.map(|(id, body, metadata)| (id, "공항", body, metadata)));
+
+        // 도시마다 점수가 가장 높은 문서 하나만 (값이 없는 문서는 서로 합치지 않음)
+        let options = SearchOptions {
+            collapse: Some("metadata.city".to_string()),
+            ..Default::default()
+        };
+        let response = search_documents_with_options("공항".to_string(), options).unwrap();
+        let mut collapsed = hit_ids(&response.hits);
+        collapsed.sort();
+        assert_eq!(collapsed, vec!["a", "d", "f", "g"]);
+        assert!(response.hits[0].sort_values.is_empty());
+        assert!(response.groups.is_empty());
+
+        // 정렬 키를 따라 각 그룹의 대표 문서를 고릅니다
+        let by_rank = vec![SortKey {
+            by: SortBy::Field("metadata.rank".to_string()),
+            order: SortOrder::Asc,
+        }];
+        let options = SearchOptions {
+            collapse: Some("metadata.city".to_string()),
+            sort: by_rank.clone(),
+            limit: 2,
+            ..Default::default()
+        };
+        let response = search_documents_with_options("공항".to_string(), options).unwrap();
+        assert_eq!(hit_ids(&response.hits), vec!["b", "e"]);
+
+        // 상위 N개 그룹의 상위 K개 문서와 그룹별 문서 수
+        let options = SearchOptions {
+            sort: by_rank,
+            group_by: Some(GroupByOptions {
+                field: "metadata.city".to_string(),
+                groups_limit: 2,
+                hits_per_group: 2,
+            }),
+            ..Default::default()
+        };
+        let response = search_documents_with_options("공항".to_string(), options).unwrap();
+        assert_eq!(response.hits.len(), 7);
+        let groups: Vec<(Option<String>, u64, Vec<String>)> = response
+            .groups
+            .iter()
+            .map(|group| (group.key.clone(), group.count, hit_ids(&group.hits)))
+            .collect();
+        assert_eq!(
+            groups,
+            vec![
+                (Some("도쿄".to_string()), 3, vec!["b".to_string(), "c".to_string()]),
+                (Some("서울".to_string()), 2, vec!["e".to_string(), "d".to_string()]),
+            ]
+        );
+
+        // 값이 없는 문서는 문서마다 한 그룹
+        let options = SearchOptions {
+            group_by: Some(GroupByOptions {
+                field: "metadata.city".to_string(),
+                groups_limit: 10,
+                hits_per_group: 5,
+            }),
+            ..Default::default()
+        };
+        let response = search_documents_with_options("공항".to_string(), options).unwrap();
+        let missing: Vec<(u64, Vec<String>)> = response
+            .groups
+            .iter()
+            .filter(|group| group.key.is_none())
+            .map(|group| (group.count, hit_ids(&group.hits)))
+            .collect();
+        assert_eq!(response.groups.len(), 4);
+        assert_eq!(
+            missing,
+            vec![(1, vec!["g".to_string()]), (1, vec!["f".to_string()])]
+        );
+
+        let options = SearchOptions {
+            collapse: Some("body".to_string()),
+            ..Default::default()
+        };
+        assert!(search_documents_with_options("공항".to_string(), options).is_err());
+    }
+
+    #[test]
+    fn test_more_like_this() {
+        use crate::api::more_like_this::{MoreLikeThisOptions, MoreLikeThisSource};
+
+        let _guard = lock_index();
+        initialize_search_index(DictionaryType::Korean).unwrap();
+        clear_all_documents().unwrap();
+        let documents = [
+            ("a", "인천 국제공항", "인천 국제공항 은 한국 의 국제공항"),
+            ("b", "인천 공항", "인천 국제공항 서울"),
+            ("c", "나리타 공항", "도쿄 나리타 일본"),
+            ("d", "김포 공항", "김포 국제공항 서울"),
+            ("e", "제주", "제주 한국"),
+        ];
+        index_docs(&documents.map(|(id, title, body)| (id, title, body, "{}")));
+        let ids = |response: SearchResponse| hit_ids(&response.hits);
+
+        // 기준 문서는 제외되고 공통 색인어가 많은 문서가 앞에 옵니다
+        let related = ids(more_like_this(
+            MoreLikeThisSource::DocumentId("a".to_string()),
+            MoreLikeThisOptions::default(),
+            SearchOptions::default(),
+        )
+        .unwrap());
+        assert_eq!(related.first().map(String::as_str), Some("b"));
+        assert!(!related.contains(&"a".to_string()));
+        assert!(!related.contains(&"c".to_string()));
+
+        // 문서 빈도가 min_doc_freq 미만인 색인어(도쿄, 나리타, 일본)는 사용하지 않습니다
+        let mut related = ids(more_like_this(
+            MoreLikeThisSource::DocumentId("c".to_string()),
+            MoreLikeThisOptions::default(),
+            SearchOptions::default(),
+        )
+        .unwrap());
+        related.sort();
+        assert_eq!(related, vec!["b", "d"]);
+
+        // 임의의 텍스트 (기준이 색인되어 있지 않으므로 문서 빈도 1도 허용)
+        let options = MoreLikeThisOptions {
+            min_doc_freq: 1,
+            ..Default::default()
+        };
+        let related = ids(more_like_this(
+            MoreLikeThisSource::Text("도쿄 나리타".to_string()),
+            options,
+            SearchOptions::default(),
+        )
+        .unwrap());
+        assert_eq!(related, vec!["c"]);
+
+        assert!(more_like_this(
+            MoreLikeThisSource::DocumentId("없음".to_string()),
+            MoreLikeThisOptions::default(),
+            SearchOptions::default(),
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_explain_document_score() {
+        let _guard = lock_index();
+        initialize_search_index(DictionaryType::Korean).unwrap();
+        clear_all_documents().unwrap();
+        index_docs(&[
+            ("haneda", "도쿄 국제공항", "도쿄 국제공항 은 도쿄 에 있는 공항", "{}"),
+            ("narita", "나리타 국제공항", "나리타 국제공항 은 도쿄 에서 떨어져 있다", "{}"),
+        ]);
+
+        let hits = search_documents("도쿄".to_string(), 10).unwrap();
+        let explanation =
+            explain_document("도쿄".to_string(), "haneda".to_string(), SearchOptions::default())
+                .unwrap();
+
+        // 설명의 최상위 값은 검색 점수와 같습니다
+        let haneda = hits.iter().find(|hit| hit.id == "haneda").unwrap();
+        assert!((explanation.value - haneda.score).abs() < 1e-4);
+        assert!(!explanation.details.is_empty());
+        let pretty = explanation.to_pretty_string();
+        assert!(pretty.contains("TermQuery"), "{}", pretty);
+        assert!(pretty.contains("occurrences of term within document"), "{}", pretty);
+
+        // 일치하지 않는 문서, 없는 문서는 오류입니다
+        assert!(explain_document(
+            "김포".to_string(),
+            "haneda".to_string(),
+            SearchOptions::default()
+        )
+        .is_err());
+        assert!(explain_document("도쿄".to_string(), "없음".to_string(), SearchOptions::default()).is_err());
+    }
+
+    #[test]
+    fn test_score_modifiers() {
+        use crate::api::date::parse_date;
+        use crate::api::scoring::DecayFunction;
+
+        let _guard = lock_index();
+        initialize_search_index(DictionaryType::Korean).unwrap();
+        clear_all_documents().unwrap();
+        let documents = [
+            ("old", r#"{"date":"2020-01-01","views":0,"rank":0.0}"#),
+            ("recent", r#"{"date":"2024-01-01","views":0,"rank":0.5}"#),
+            ("popular", r#"{"date":"2020-01-01","views":10000}"#),
+        ];
+        index_docs(&documents.map(|(id, metadata)| (id, "공항", "공항", metadata)));
+        let origin = parse_date("2024-01-01").unwrap().into_timestamp_millis();
+        let base = search_documents("공항".to_string(), 1).unwrap()[0].score;
+        let search = |modifiers: Vec<ScoreModifier>| {
+            let options = SearchOptions {
+                score_modifiers: modifiers,
+                ..Default::default()
+            };
+            search_documents_with_options("공항".to_string(), options)
+        };
+        let decay = |function: DecayFunction, scale_days: f64| ScoreModifier::DateDecay {
+            field: "dates.date".to_string(),
+            function,
+            origin: Some(origin),
+            scale_days,
+            offset_days: 0.0,
+            decay: 0.5,
+        };
+
+        // 기준 시각에 가까운 문서가 앞서고 1461일 떨어진 문서는 0.5^(1461/365) 배
+        let hits = search(vec![decay(DecayFunction::Exponential, 365.0)])
+            .unwrap()
+            .hits;
+        assert_eq!(hits[0].id, "recent");
+        assert!((hits[0].score - base).abs() < 1e-4);
+        let expected = base * 0.5f32.powf(1461.0 / 365.0);
+        assert!((hits[1].score - expected).abs() < 1e-4, "{:?}", hits);
+
+        let hits = search(vec![decay(DecayFunction::Gaussian, 1000.0)])
+            .unwrap()
+            .hits;
+        let expected = base * 0.5f32.powf((1461.0f32 / 1000.0).powi(2));
+        assert!((hits[1].score - expected).abs() < 1e-4, "{:?}", hits);
+
+        // 조회수 부스트는 값이 없거나 0이면 1배
+        let hits = search(vec![ScoreModifier::LogBoost {
+            field: "metadata.views".to_string(),
+            factor: 1.0,
+        }])
+        .unwrap()
+        .hits;
+        assert_eq!(hits[0].id, "popular");
+        assert!((hits[0].score - base * (1.0 + 10001f32.ln())).abs() < 1e-3);
+        assert!((hits[1].score - base).abs() < 1e-4);
+
+        // 보정은 모두 곱하고 점수 정렬 키에도 적용됩니다
+        let options = SearchOptions {
+            score_modifiers: vec![
+                ScoreModifier::StaticRank {
+                    field: "metadata.rank".to_string(),
+                    weight: 2.0,
+                },
+                decay(DecayFunction::Exponential, 365.0),
+            ],
+            sort: vec![SortKey {
+                by: SortBy::Score,
+                order: crate::api::sort::SortOrder::Desc,
+            }],
+            ..Default::default()
+        };
+        let hits = search_documents_with_options("공항".to_string(), options)
+            .unwrap()
+            .hits;
+        assert_eq!(hits[0].id, "recent");
+        assert!((hits[0].score - base * 2.0).abs() < 1e-4);
+        assert_eq!(hits[0].sort_values, vec![SortValue::Float(hits[0].score as f64)]);
+
+        // 음수 가중치로 배수가 0보다 작아지면 점수가 음수가 되지 않도록 0배
+        let hits = search(vec![ScoreModifier::StaticRank {
+            field: "metadata.rank".to_string(),
+            weight: -4.0,
+        }])
+        .unwrap()
+        .hits;
+        assert_eq!(hits.last().unwrap().id, "recent");
+        assert_eq!(hits.last().unwrap().score, 0.0);
+        assert!(hits.iter().all(|hit| hit.score >= 0.0), "{:?}", hits);
+
+        let mut invalid = decay(DecayFunction::Exponential, 365.0);
+        if let ScoreModifier::DateDecay { decay, .. } = &mut invalid {
+            *decay = 1.5;
+        }
+        assert!(search(vec![invalid]).is_err());
+        assert!(search(vec![ScoreModifier::LogBoost {
+            field: "body".to_string(),
+            factor: 1.0,
+        }])
+        .is_err());
+        assert!(search(vec![ScoreModifier::LogBoost {
+            field: "metadata.views".to_string(),
+            factor: -1.0,
+        }])
+        .is_err());
+        assert!(search(vec![ScoreModifier::StaticRank {
+            field: "metadata.rank".to_string(),
+            weight: f64::NAN,
+        }])
+        .is_err());
+    }
+
+    #[test]
+    fn test_field_projection() {
+        let _guard = lock_index();
+        initialize_search_index(DictionaryType::Korean).unwrap();
+        clear_all_documents().unwrap();
+        index_docs(&[(
+            "doc-1",
+            "인천 국제공항",
+            "인천 국제공항 은 한국 의 공항",
+            r#"{"city":"인천","location":{"lat":37.4602,"lon":126.4407}}"#,
+        )]);
+        let search = |fields: FieldSelection, body_max_chars: Option<usize>| {
+            let options = SearchOptions {
+                fields,
+                body_max_chars,
+                snippet: Some(SnippetOptions::default()),
+                ..Default::default()
+            };
+            search_documents_with_options("공항".to_string(), options).map(|response| response.hits)
+        };
+
+        // ID 만 (저장 필드는 비어 있고 시스템 시각과 거리는 fast field 에서 읽으며, 스니펫은 만들 수 없음)
+        let all = search(FieldSelection::All, None).unwrap();
+        assert!(search(FieldSelection::IdOnly, None).is_err());
+        let options = SearchOptions {
+            fields: FieldSelection::IdOnly,
+            ..Default::default()
+        };
+        let hits = search_documents_with_options("공항".to_string(), options).unwrap().hits;
+        assert_eq!(hits[0].id, "doc-1");
+        assert!(hits[0].score > 0.0);
+        assert!(hits[0].title.is_empty() && hits[0].body.is_empty());
+        assert_eq!(hits[0].metadata, "{}");
+        assert!(hits[0].snippet.is_none());
+        assert!(hits[0].created_at > 0);
+        assert_eq!(hits[0].created_at, all[0].created_at);
+        assert_eq!(hits[0].updated_at, all[0].updated_at);
+
+        let gimpo = GeoPoint {
+            lat: 37.5583,
+            lon: 126.7906,
+        };
+        let distance = |fields: FieldSelection| {
+            let options = SearchOptions {
+                fields,
+                sort: vec![SortKey {
+                    by: SortBy::Distance(gimpo),
+                    order: crate::api::sort::SortOrder::Asc,
+                }],
+                ..Default::default()
+            };
+            search_documents_with_options("공항".to_string(), options).unwrap().hits[0].distance_km
+        };
+        let expected = distance(FieldSelection::All).unwrap();
+        assert!(expected > 0.0);
+        assert_eq!(distance(FieldSelection::IdOnly), Some(expected));
+
+        // 지정한 필드만, 본문은 글자 수로 자름
+        let hits = search(FieldSelection::Fields(vec!["body".to_string()]), Some(5)).unwrap();
+        assert_eq!(hits[0].id, "doc-1");
+        assert!(hits[0].title.is_empty());
+        assert_eq!(hits[0].metadata, "{}");
+        assert_eq!(hits[0].body, "인천 국제");
+        assert!(hits[0].snippet.is_some());
+        assert!(hits[0].created_at > 0);
+
+        let hits = search(FieldSelection::All, Some(100)).unwrap();
+        assert_eq!(hits[0].title, "인천 국제공항");
+        assert_eq!(hits[0].body, "인천 국제공항 은 한국 의 공항");
+        assert!(hits[0].metadata.contains("인천"));
+
+        assert!(search(FieldSelection::Fields(vec!["created_at".to_string()]), None).is_err());
+    }
+
+    #[test]
+    fn test_count_and_exists() {
+        let _guard = lock_index();
+        initialize_search_index(DictionaryType::Korean).unwrap();
+        clear_all_documents().unwrap();
+        let documents = [
+            ("a", "인천 국제공항", r#"{"category":"/travel/korea"}"#),
+            ("b", "김포 공항", r#"{"category":"/travel/korea"}"#),
+            ("c", "나리타 국제공항", r#"{"category":"/travel/japan"}"#),
+        ];
+        index_docs(&documents.map(|(id, title, metadata)| (id, title, "", metadata)));
+
+        // limit 와 무관하게 일치하는 모든 문서를 셉니다
+        let options = SearchOptions {
+            limit: 1,
+            ..Default::default()
+        };
+        assert_eq!(count_documents("공항".to_string(), options).unwrap(), 3);
+
+        let options = SearchOptions {
+            category_filter: Some("/travel/korea".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(count_documents("공항".to_string(), options.clone()).unwrap(), 2);
+        let query = QueryNode::Term {
+            field: "id".to_string(),
+            text: "c".to_string(),
+        };
+        assert_eq!(count_with_query(query, options).unwrap(), 0);
+        assert_eq!(count_documents("제주".to_string(), SearchOptions::default()).unwrap(), 0);
+
+        assert!(document_exists("a".to_string()).unwrap());
+        delete_document("a".to_string()).unwrap();
+        assert!(!document_exists("a".to_string()).unwrap());
+        assert!(!document_exists("없음".to_string()).unwrap());
+    }
+
+    #[test]
+    fn test_get_and_scan_documents() {
+        let _guard = lock_index();
+        initialize_search_index(DictionaryType::Korean).unwrap();
+        clear_all_documents().unwrap();
+        for id in ["d", "b", "e", "a", "c"] {
+            // 세그먼트가 여러 개여도 ID 순서가 유지되어야 합니다
+            add_documents(vec![DocumentInput {
+                id: id.to_string(),
+                title: format!("제목 {}", id),
+                body: format!("본문 {}", id),
+                metadata: r#"{"city":"서울"}"#.to_string(),
+            }])
+            .unwrap();
+        }
+
+        let document = get_document("b".to_string()).unwrap().unwrap();
+        assert_eq!(document.title, "제목 b");
+        assert_eq!(document.body, "본문 b");
+        assert!(document.metadata.contains("서울"));
+        assert!(document.created_at > 0 && document.updated_at >= document.created_at);
+        assert!(get_document("없음".to_string()).unwrap().is_none());
+
+        let ids = |documents: &[StoredDocument]| -> Vec<String> {
+            documents.iter().map(|document| document.id.clone()).collect()
+        };
+        let documents =
+            get_documents(vec!["e".to_string(), "없음".to_string(), "a".to_string()]).unwrap();
+        assert_eq!(ids(&documents), vec!["e", "a"]);
+
+        // 같은 ID 로 다시 추가하면 바뀌고, 한 번에 여러 번 넘기면 마지막 문서가 남습니다
+        let revised = |title: &str| DocumentInput {
+            id: "b".to_string(),
+            title: title.to_string(),
+            body: "본문 b".to_string(),
+            metadata: "{}".to_string(),
+        };
+        add_documents(vec![revised("고친 제목 b"), revised("다시 고친 제목 b")]).unwrap();
+        let revised_document = get_document("b".to_string()).unwrap().unwrap();
+        assert_eq!(revised_document.title, "다시 고친 제목 b");
+        assert_eq!(revised_document.created_at, document.created_at);
+        let page = scan_documents(None, 10).unwrap();
+        assert_eq!(ids(&page.documents), vec!["a", "b", "c", "d", "e"]);
+
+        delete_document("c".to_string()).unwrap();
+        let page = scan_documents(None, 2).unwrap();
+        assert_eq!(ids(&page.documents), vec!["a", "b"]);
+        let page = scan_documents(page.next_cursor, 2).unwrap();
+        assert_eq!(ids(&page.documents), vec!["d", "e"]);
+        assert_eq!(page.next_cursor, None);
+        assert!(scan_documents(None, 0).is_err());
+    }
+
+    #[test]
+    fn test_chosung_search() {
+        let _guard = lock_index();
+        initialize_search_index(DictionaryType::Korean).unwrap();
+        clear_all_documents().unwrap();
+        let documents = [
+            ("icn", "인천 국제공항", "한국 의 관문", "{}"),
+            ("gmp", "김포 국제공항", "서울 의 공항", "{}"),
+            ("cju", "제주 공항", "인천 에서 비행기", "{}"),
+        ];
+        index_docs(&documents);
+
+        // 공백 없이 이어 쓴 초성, 부분 초성, 띄어 쓴 초성
+        assert_eq!(sorted_hit_ids("ㅇㅊㄱㅈㄱㅎ"), vec!["icn"]);
+        assert_eq!(sorted_hit_ids("ㄱㅈㄱㅎ"), vec!["gmp", "icn"]);
+        assert_eq!(sorted_hit_ids("ㄱㅍ ㄱㅈ"), vec!["gmp"]);
+        assert_eq!(sorted_hit_ids("ㄱㅎ"), vec!["cju", "gmp", "icn"]);
+        assert!(sorted_hit_ids("ㅎㄱ").is_empty());
+        assert_eq!(count_documents("ㅈㅈ".to_string(), SearchOptions::default()).unwrap(), 1);
+
+        // 본문도 초성 필드에 넣으면 본문의 초성으로도 찾습니다
+        configure_chosung_fields(true, true).unwrap();
+        clear_all_documents().unwrap();
+        index_docs(&documents);
+        assert_eq!(sorted_hit_ids("ㅎㄱㅇ"), vec!["icn"]);
+        assert_eq!(sorted_hit_ids("ㅇㅊ"), vec!["cju", "icn"]);
+
+        // 조각이 모두 있어도 이어서 나오지 않으면 일치하지 않습니다 ("ㄱㅈㄱ" ... "ㅈㄱㅎ")
+        configure_chosung_fields(true, false).unwrap();
+        clear_all_documents().unwrap();
+        index_docs(&[("apart", "구조가 나 제가 하", "", "{}")]);
+        assert!(sorted_hit_ids("ㄱㅈㄱㅎ").is_empty());
+        assert_eq!(sorted_hit_ids("ㄴㅈㄱㅎ"), vec!["apart"]);
+    }
+
+    #[test]
+    fn test_search_as_you_type() {
+        let _guard = lock_index();
+        initialize_search_index(DictionaryType::Korean).unwrap();
+        clear_all_documents().unwrap();
+        let documents = [
+            ("ko", "한국어 사전"),
+            ("icn", "인천 국제공항"),
+            ("chicken", "닭갈비 맛집"),
+            ("fruit", "과일 가게"),
+        ];
+        index_docs(&documents.map(|(id, title)| (id, title, "", "{}")));
+        let complete = |query: &str| -> Vec<String> {
+            hit_ids(&search_as_you_type(query.to_string(), SearchOptions::default()).unwrap().hits)
+        };
+
+        // 마지막 음절이 입력 중이어도 접두어로 일치합니다
+        assert_eq!(complete("한구"), vec!["ko"]);
+        assert_eq!(complete("한ㄱ"), vec!["ko"]);
+        assert_eq!(complete("인천 국제고"), vec!["icn"]);
+        assert_eq!(complete("국제공하"), vec!["icn"]);
+        // 겹받침과 겹모음도 입력 순서대로 나눕니다
+        assert_eq!(complete("달"), vec!["chicken"]);
+        assert_eq!(complete("고"), vec!["fruit"]);
+        assert!(complete("한극").is_empty());
+        assert!(complete("인천 김").is_empty());
+        assert!(complete(" ").is_empty());
+    }
+
+    #[test]
+    fn test_jamo_fuzzy_search() {
+        let _guard = lock_index();
+        initialize_search_index(DictionaryType::Korean).unwrap();
+        clear_all_documents().unwrap();
+        index_docs(&[
+            ("airport", "김포 공항", "", "{}"),
+            ("seoul", "서울", "", "{}"),
+        ]);
+        let search = |query: &str, jamo_fuzzy: Option<FuzzyOptions>| -> Vec<String> {
+            let options = SearchOptions {
+                jamo_fuzzy,
+                ..Default::default()
+            };
+            hit_ids(&search_documents_with_options(query.to_string(), options).unwrap().hits)
+        };
+
+        // "공헝" 은 음절로는 한 글자가 다르지만 자모로는 하나만 다릅니다
+        assert!(search("공헝", None).is_empty());
+        assert_eq!(search("공헝", Some(FuzzyOptions::default())), vec!["airport"]);
+        assert_eq!(search("서올", Some(FuzzyOptions::default())), vec!["seoul"]);
+
+        // 거리는 검색마다 지정합니다 ("사올" 은 자모 둘 차이)
+        assert!(search("사올", Some(FuzzyOptions::default())).is_empty());
+        let distance_two = FuzzyOptions {
+            distance: 2,
+            ..Default::default()
+        };
+        assert_eq!(search("사올", Some(distance_two)), vec!["seoul"]);
+
+        let options = SearchOptions {
+            jamo_fuzzy: Some(FuzzyOptions {
+                distance: 3,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        assert!(search_documents_with_options("공헝".to_string(), options).is_err());
+    }
+
+    #[test]
+    fn test_hanja_hangul_matching() {
+        let _guard = lock_index();
+        initialize_search_index(DictionaryType::Korean).unwrap();
+        clear_all_documents().unwrap();
+        let documents = [
+            ("hanja", "공항", "仁川國際空港"),
+            ("hangul", "공항", "인천국제공항"),
+            ("gimpo", "공항", "金浦國際空港"),
+        ];
+        let add = || {
+            index_docs(&documents.map(|(id, title, body)| (id, title, body, "{}")));
+        };
+
+        // 기본값은 꺼져 있어 한글로 한자 본문을 찾지 못합니다
+        add();
+        assert_eq!(sorted_hit_ids("인천"), vec!["hangul"]);
+
+        configure_hanja_readings(true).unwrap();
+        clear_all_documents().unwrap();
+        add();
+        assert_eq!(sorted_hit_ids("인천"), vec!["hangul", "hanja"]);
+        assert_eq!(sorted_hit_ids("仁川"), vec!["hangul", "hanja"]);
+        // 여러 글자 항목의 독음 (金 은 한 글자로는 "금")
+        assert_eq!(sorted_hit_ids("김포"), vec!["gimpo"]);
+    }
+
+    #[test]
+    fn test_cjk_variant_folding() {
+        let _guard = lock_index();
+        initialize_search_index(DictionaryType::Chinese).unwrap();
+        clear_all_documents().unwrap();
+        let documents = [
+            ("simplified", "北京首都国际机场"),
+            ("traditional", "香港國際機場"),
+            ("shinjitai", "関西国際空港"),
+        ];
+        let add = || {
+            index_docs(&documents.map(|(id, title)| (id, title, "", "{}")));
+        };
+
+        add();
+        assert_eq!(sorted_hit_ids("title_ngram:國際"), vec!["traditional"]);
+
+        // 이미 색인된 문서와 검색어 분석이 어긋나므로 문서가 있으면 바꿀 수 없습니다
+        assert!(configure_variant_folding(vec!["title_ngram".to_string()], Vec::new()).is_err());
+        assert_eq!(sorted_hit_ids("title_ngram:國際"), vec!["traditional"]);
+
+        // 색인과 검색 모두 대표 글자로 통일합니다
+        clear_all_documents().unwrap();
+        configure_variant_folding(vec!["title_ngram".to_string()], Vec::new()).unwrap();
+        add();
+        assert_eq!(sorted_hit_ids("title_ngram:國際"), vec!["shinjitai", "simplified", "traditional"]);
+        assert_eq!(sorted_hit_ids("title_ngram:机场"), vec!["simplified", "traditional"]);
+        // 저장된 원문은 그대로입니다
+        let hits = search_documents_with_options("title_ngram:國際".to_string(), SearchOptions::default())
+            .unwrap()
+            .hits;
+        assert!(hits.iter().any(|hit| hit.title == "香港國際機場"));
+
+        // 사용자 표
+        let mapping = |variant: &str, canonical: &str| VariantMapping {
+            variant: variant.to_string(),
+            canonical: canonical.to_string(),
+        };
+        clear_all_documents().unwrap();
+        configure_variant_folding(vec!["title_ngram".to_string()], vec![mapping("関", "关")])
+            .unwrap();
+        add();
+        assert_eq!(sorted_hit_ids("title_ngram:关西"), vec!["shinjitai"]);
+        assert_eq!(sorted_hit_ids("title_ngram:國際"), vec!["traditional"]);
+
+        // 설정을 기록하지 못하면 이전 표로 계속 분석합니다
+        clear_all_documents().unwrap();
+        let index = SEARCH_INDEX.lock().unwrap().as_ref().unwrap().index.clone();
+        let index_writer: IndexWriter = index.writer(50_000_000).unwrap();
+        assert!(configure_variant_folding(vec!["title_ngram".to_string()], Vec::new()).is_err());
+        drop(index_writer);
+        add();
+        assert_eq!(sorted_hit_ids("title_ngram:关西"), vec!["shinjitai"]);
+        assert_eq!(sorted_hit_ids("title_ngram:國際"), vec!["traditional"]);
+
+        assert!(configure_variant_folding(vec!["metadata".to_string()], Vec::new()).is_err());
+        assert!(
+            configure_variant_folding(vec!["title".to_string()], vec![mapping("国際", "国际")])
+                .is_err()
+        );
+
+        // 설정은 인덱스에 저장되어 다시 열어도 같은 분석기로 검색합니다
+        let temp_dir = std::env::temp_dir().join("test_tantivy_index_variants");
+        let _ = std::fs::remove_dir_all(&temp_dir);
+        let path_str = temp_dir.to_str().unwrap().to_string();
+        initialize_search_index_with_path(DictionaryType::Chinese, path_str.clone()).unwrap();
+        configure_variant_folding(vec!["title_ngram".to_string()], vec![mapping("関", "关")])
+            .unwrap();
+        add();
+        initialize_search_index_with_path(DictionaryType::Chinese, path_str).unwrap();
+        assert_eq!(sorted_hit_ids("title_ngram:关西"), vec!["shinjitai"]);
+        *SEARCH_INDEX.lock().unwrap() = None;
+        let _ = std::fs::remove_dir_all(temp_dir);
+    }
+
+    #[test]
+    fn test_japanese_reading_search() {
+        let _guard = lock_index();
+        initialize_search_index(DictionaryType::JapaneseIpadic).unwrap();
+        clear_all_documents().unwrap();
+        configure_japanese_readings(true, true).unwrap();
+        index_docs(&[
+            ("kansai", "関西国際空港", "大阪湾にある空港です", "{}"),
+            ("tokyo", "東京駅", "丸の内にある駅です", "{}"),
+        ]);
+
+        // 히라가나, 가타카나, 로마자 (장음 무시)
+        assert_eq!(sorted_hit_ids("かんさい"), vec!["kansai"]);
+        assert_eq!(sorted_hit_ids("カンサイ"), vec!["kansai"]);
+        assert_eq!(sorted_hit_ids("kansai"), vec!["kansai"]);
+        assert_eq!(sorted_hit_ids("tokyo"), vec!["tokyo"]);
+        assert_eq!(sorted_hit_ids("とうきょう"), vec!["tokyo"]);
+
+        // 검색어의 조각이 모두 들어 있는 긴 본문이라도 이어서 나오지 않으면 일치하지 않습니다
+        index_docs(&[("memo", "メモ", "the test is over, the tin is hot, nothing else", "{}")]);
+        assert!(sorted_hit_ids("testing").is_empty());
+        assert!(sorted_hit_ids("かんさいこう").is_empty());
+        assert_eq!(sorted_hit_ids("kansaikoku"), vec!["kansai"]);
+
+        // 로마자를 끄면 영문 검색어는 읽기 필드에서 찾지 않습니다
+        configure_japanese_readings(true, false).unwrap();
+        assert!(sorted_hit_ids("kansai").is_empty());
+        assert_eq!(sorted_hit_ids("かんさい"), vec!["kansai"]);
+
+        // 일본어 사전이 아니면 사용할 수 없습니다
+        initialize_search_index(DictionaryType::Korean).unwrap();
+        assert!(configure_japanese_readings(true, false).is_err());
+    }
 
+    #[test]
+    fn test_pinyin_search() {
+        let _guard = lock_index();
+        initialize_search_index(DictionaryType::Chinese).unwrap();
+        clear_all_documents().unwrap();
+        configure_pinyin(true).unwrap();
+        index_docs(&[
+            ("beijing", "北京首都国际机场", "", "{}"),
+            ("shanghai", "上海", "中国的经济中心", "{}"),
+        ]);
+
+        // 전체 병음, 음절 첫 글자, 본문 병음 (성조와 대소문자 무시)
+        assert_eq!(sorted_hit_ids("beijing"), vec!["beijing"]);
+        assert_eq!(sorted_hit_ids("Bei Jing"), vec!["beijing"]);
+        assert_eq!(sorted_hit_ids("bjsd"), vec!["beijing"]);
+        assert_eq!(sorted_hit_ids("shanghai"), vec!["shanghai"]);
+        assert_eq!(sorted_hit_ids("zhongguo"), vec!["shanghai"]);
+        assert_eq!(sorted_hit_ids("beijingshoudu"), vec!["beijing"]);
+
+        // 검색어의 조각이 모두 들어 있는 긴 본문이라도 이어서 나오지 않으면 일치하지 않습니다
+        index_docs(&[("memo", "备忘", "the test is over, the tin is hot, nothing else", "{}")]);
+        assert!(sorted_hit_ids("testing").is_empty());
+        assert!(sorted_hit_ids("beijingzhongguo").is_empty());
+
+        // 설정을 기록하지 못하면 병음 검색이 켜진 채로 남습니다
+        let index = SEARCH_INDEX.lock().unwrap().as_ref().unwrap().index.clone();
+        let index_writer: IndexWriter = index.writer(50_000_000).unwrap();
+        assert!(configure_pinyin(false).is_err());
+        drop(index_writer);
+        assert_eq!(sorted_hit_ids("beijing"), vec!["beijing"]);
+
+        configure_pinyin(false).unwrap();
+        assert!(sorted_hit_ids("beijing").is_empty());
+
+        // 중국어 사전이 아니면 사용할 수 없습니다
+        initialize_search_index(DictionaryType::Korean).unwrap();
+        assert!(configure_pinyin(true).is_err());
+    }
+}
@@ -0,0 +1,155 @@
+use serde_json::{json, Value as JsonValue};
+
+use crate::api::date::DEFAULT_DATE_PATHS;
+use crate::api::geo::DEFAULT_LOCATION_PATH;
+use crate::api::variant::VariantMapping;
+
+// 인덱스 스키마 버전 (필드를 추가하거나 필드 옵션을 바꾸면 올립니다)
+// 1: 버전을 기록하기 전의 인덱스 (id, title, body, metadata, N-gram 필드)
+// 2: 접두어, 카테고리, 날짜, 시스템 시각, 좌표, 초성, 자모, 한자 독음, 읽기, 병음 필드와
+//    빠른 필드(metadata, id, title), 필드별 분석기
+pub(crate) const SCHEMA_VERSION: u64 = 2;
+
+// 버전을 기록하지 않은 인덱스의 스키마 버전
+const UNVERSIONED_SCHEMA: u64 = 1;
+
+// 인덱스와 함께 저장하는 정보 (커밋 payload 에 JSON 으로 기록합니다)
+#[flutter_rust_bridge::frb(ignore)]
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct IndexSettings {
+    pub(crate) schema_version: u64,
+    // configure_date_fields
+    pub(crate) date_paths: Vec<String>,
+    // configure_location_field
+    pub(crate) location_path: String,
+    // configure_chosung_fields
+    pub(crate) chosung_title: bool,
+    pub(crate) chosung_body: bool,
+    // configure_hanja_readings
+    pub(crate) hanja_readings: bool,
+    // configure_japanese_readings
+    pub(crate) japanese_readings: bool,
+    pub(crate) romaji: bool,
+    // configure_pinyin
+    pub(crate) pinyin: bool,
+    // configure_variant_folding (색인된 문서와 검색 분석기가 어긋나지 않도록 함께 저장합니다)
+    pub(crate) variant_fields: Vec<String>,
+    pub(crate) variant_mappings: Vec<VariantMapping>,
+}
+
+impl Default for IndexSettings {
+    fn default() -> Self {
+        IndexSettings {
+            schema_version: SCHEMA_VERSION,
+            date_paths: DEFAULT_DATE_PATHS.iter().map(|path| path.to_string()).collect(),
+            location_path: DEFAULT_LOCATION_PATH.to_string(),
+            chosung_title: true,
+            chosung_body: false,
+            hanja_readings: false,
+            japanese_readings: false,
+            romaji: false,
+            pinyin: false,
+            variant_fields: Vec::new(),
+            variant_mappings: Vec::new(),
+        }
+    }
+}
+
+impl IndexSettings {
+    pub(crate) fn to_payload(&self) -> String {
+        json!({
+            "schema_version": self.schema_version,
+            "date_paths": self.date_paths,
+            "location_path": self.location_path,
+            "chosung_title": self.chosung_title,
+            "chosung_body": self.chosung_body,
+            "hanja_readings": self.hanja_readings,
+            "japanese_readings": self.japanese_readings,
+            "romaji": self.romaji,
+            "pinyin": self.pinyin,
+            "variant_fields": self.variant_fields,
+            "variant_mappings": self
+                .variant_mappings
+                .iter()
+                .map(|mapping| [&mapping.variant, &mapping.canonical])
+                .collect::<Vec<_>>(),
+        })
+        .to_string()
+    }
+
+    // 마지막 커밋의 payload 를 읽습니다 (스키마 버전이 다르면 오류, 없는 값은 기본값)
+    pub(crate) fn from_payload(payload: Option<&str>) -> Result<Self, String> {
+        let payload: JsonValue = match payload {
+            Some(payload) => serde_json::from_str(payload)
+                .map_err(|e| format!("인덱스 설정을 읽을 수 없습니다: {}", e))?,
+            None => json!({}),
+        };
+        let schema_version = payload
+            .get("schema_version")
+            .and_then(JsonValue::as_u64)
+            .unwrap_or(UNVERSIONED_SCHEMA);
+        if schema_version != SCHEMA_VERSION {
+            return Err(format!(
+                "인덱스 스키마 버전({})이 현재 버전({})과 다릅니다. 인덱스 디렉토리를 삭제하고 다시 생성하세요.",
+                schema_version, SCHEMA_VERSION
+            ));
+        }
+
+        let defaults = IndexSettings::default();
+        let flag = |key: &str, default: bool| {
+            payload.get(key).and_then(JsonValue::as_bool).unwrap_or(default)
+        };
+        let strings = |value: &JsonValue| -> Vec<String> {
+            value
+                .as_array()
+                .map(|values| {
+                    values
+                        .iter()
+                        .filter_map(JsonValue::as_str)
+                        .map(str::to_string)
+                        .collect()
+                })
+                .unwrap_or_default()
+        };
+        let date_paths = match payload.get("date_paths") {
+            Some(paths) => strings(paths),
+            None => defaults.date_paths,
+        };
+        let location_path = payload
+            .get("location_path")
+            .and_then(JsonValue::as_str)
+            .map_or(defaults.location_path, str::to_string);
+        let variant_fields = payload.get("variant_fields").map(strings).unwrap_or_default();
+        let variant_mappings = payload
+            .get("variant_mappings")
+            .and_then(JsonValue::as_array)
+            .map(|mappings| {
+                mappings
+                    .iter()
+                    .map(strings)
+                    .filter_map(|pair| match pair.as_slice() {
+                        [variant, canonical] => Some(VariantMapping {
+                            variant: variant.clone(),
+                            canonical: canonical.clone(),
+                        }),
+                        _ => None,
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Ok(IndexSettings {
+            schema_version,
+            date_paths,
+            location_path,
+            chosung_title: flag("chosung_title", defaults.chosung_title),
+            chosung_body: flag("chosung_body", defaults.chosung_body),
+            hanja_readings: flag("hanja_readings", defaults.hanja_readings),
+            japanese_readings: flag("japanese_readings", defaults.japanese_readings),
+            romaji: flag("romaji", defaults.romaji),
+            pinyin: flag("pinyin", defaults.pinyin),
+            variant_fields,
+            variant_mappings,
+        })
+    }
+}
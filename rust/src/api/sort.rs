@@ -0,0 +1,245 @@
+use std::cmp::Ordering;
+use std::sync::Arc;
+
+use tantivy::collector::{ScoreSegmentTweaker, ScoreTweaker};
+use tantivy::columnar::DynamicColumn;
+use tantivy::{DocId, Index, Score, SegmentReader};
+
+use crate::api::geo::{GeoColumns, GeoPoint};
+use crate::api::scoring::{ScoreModifier, ScoreModifiers, SegmentScoreModifiers};
+
+/// 정렬 기준
+#[derive(Clone, Debug)]
+pub enum SortBy {
+    /// BM25 관련도 점수 (점수 보정이 있으면 보정한 점수)
+    Score,
+    /// fast field 이름 또는 메타데이터 JSON 경로 (예: `metadata.price`)
+    Field(String),
+    /// 문서 좌표(기본값 `metadata.location`)와 기준 좌표 사이의 거리 (km)
+    Distance(GeoPoint),
+}
+
+/// 정렬 방향
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SortOrder {
+    Asc,
+    Desc,
+}
+
+/// 정렬 키 (여러 개를 지정하면 앞의 키가 같을 때 다음 키로 비교합니다)
+#[derive(Clone, Debug)]
+pub struct SortKey {
+    pub by: SortBy,
+    pub order: SortOrder,
+}
+
+/// 검색 결과에 함께 반환되는 정렬 값
+#[derive(Clone, Debug, PartialEq)]
+pub enum SortValue {
+    /// 정수 값 (날짜 필드는 Unix 시간 밀리초)
+    Integer(i64),
+    Float(f64),
+    Text(String),
+    /// 문서에 해당 필드 값이 없음 (방향과 관계없이 항상 마지막)
+    Missing,
+}
+
+impl SortValue {
+    // 숫자 < 문자열 순서로 자연 순서를 비교합니다 (Missing 은 호출 전에 처리)
+    fn natural_cmp(&self, other: &SortValue) -> Ordering {
+        match (self, other) {
+            (SortValue::Integer(a), SortValue::Integer(b)) => a.cmp(b),
+            (SortValue::Text(a), SortValue::Text(b)) => a.cmp(b),
+            (SortValue::Text(_), _) => Ordering::Greater,
+            (_, SortValue::Text(_)) => Ordering::Less,
+            (a, b) => a.as_f64().total_cmp(&b.as_f64()),
+        }
+    }
+
+    fn as_f64(&self) -> f64 {
+        match self {
+            SortValue::Integer(value) => *value as f64,
+            SortValue::Float(value) => *value,
+            _ => f64::NAN,
+        }
+    }
+}
+
+// 한 문서의 정렬 값 묶음
+// TopDocs 는 큰 값을 남기므로, "앞에 와야 하는" 문서가 더 크도록 비교합니다
+#[derive(Clone, Debug)]
+pub(crate) struct SortValues {
+    pub(crate) values: Vec<SortValue>,
+    pub(crate) score: Score,
+    orders: Arc<Vec<SortOrder>>,
+}
+
+impl PartialEq for SortValues {
+    fn eq(&self, other: &Self) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
+
+impl PartialOrd for SortValues {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        for ((a, b), order) in self.values.iter().zip(&other.values).zip(self.orders.iter()) {
+            let ordering = match (a, b) {
+                (SortValue::Missing, SortValue::Missing) => Ordering::Equal,
+                (SortValue::Missing, _) => Ordering::Less,
+                (_, SortValue::Missing) => Ordering::Greater,
+                (a, b) => match order {
+                    SortOrder::Desc => a.natural_cmp(b),
+                    SortOrder::Asc => a.natural_cmp(b).reverse(),
+                },
+            };
+            if ordering != Ordering::Equal {
+                return Some(ordering);
+            }
+        }
+        Some(Ordering::Equal)
+    }
+}
+
+// 정렬 키에 따라 fast field 값을 읽어 점수를 대신하는 ScoreTweaker
+// (정렬 키가 없으면 점수 내림차순, 점수 보정은 정렬 전에 적용합니다)
+pub(crate) struct FastFieldSorter {
+    keys: Vec<SortBy>,
+    orders: Arc<Vec<SortOrder>>,
+    modifiers: ScoreModifiers,
+}
+
+impl FastFieldSorter {
+    pub(crate) fn new(
+        index: &Index,
+        keys: &[SortKey],
+        modifiers: &[ScoreModifier],
+    ) -> Result<Self, String> {
+        let score_key = [SortKey {
+            by: SortBy::Score,
+            order: SortOrder::Desc,
+        }];
+        let keys = if keys.is_empty() { &score_key[..] } else { keys };
+        for key in keys {
+            if let SortBy::Field(name) = &key.by {
+                ensure_fast_field(index, name)
+                    .map_err(|_| format!("정렬할 수 없는 필드입니다 (fast field 아님): {}", name))?;
+            }
+        }
+
+        Ok(FastFieldSorter {
+            keys: keys.iter().map(|key| key.by.clone()).collect(),
+            orders: Arc::new(keys.iter().map(|key| key.order).collect()),
+            modifiers: ScoreModifiers::new(index, modifiers)?,
+        })
+    }
+}
+
+impl ScoreTweaker<SortValues> for FastFieldSorter {
+    type Child = SegmentSorter;
+
+    fn segment_tweaker(&self, segment_reader: &SegmentReader) -> tantivy::Result<SegmentSorter> {
+        let fast_fields = segment_reader.fast_fields();
+        let mut columns = Vec::with_capacity(self.keys.len());
+        for key in &self.keys {
+            columns.push(match key {
+                SortBy::Score => SegmentColumns::Score,
+                SortBy::Field(name) => SegmentColumns::Field(
+                    fast_fields
+                        .dynamic_column_handles(name)?
+                        .iter()
+                        .map(|handle| handle.open())
+                        .collect::<std::io::Result<Vec<DynamicColumn>>>()?,
+                ),
+                SortBy::Distance(origin) => {
+                    SegmentColumns::Distance(GeoColumns::open(segment_reader)?, *origin)
+                }
+            });
+        }
+
+        Ok(SegmentSorter {
+            columns,
+            orders: self.orders.clone(),
+            modifiers: self.modifiers.for_segment(segment_reader)?,
+        })
+    }
+}
+
+pub(crate) struct SegmentSorter {
+    // 정렬 키마다 세그먼트에서 값을 읽을 컬럼
+    columns: Vec<SegmentColumns>,
+    orders: Arc<Vec<SortOrder>>,
+    modifiers: SegmentScoreModifiers,
+}
+
+enum SegmentColumns {
+    Score,
+    Field(Vec<DynamicColumn>),
+    Distance(GeoColumns, GeoPoint),
+}
+
+impl ScoreSegmentTweaker<SortValues> for SegmentSorter {
+    fn score(&mut self, doc: DocId, score: Score) -> SortValues {
+        let score = self.modifiers.apply(doc, score);
+        let values = self
+            .columns
+            .iter()
+            .map(|columns| match columns {
+                SegmentColumns::Score => SortValue::Float(score as f64),
+                SegmentColumns::Field(columns) => first_value(columns, doc),
+                SegmentColumns::Distance(geo_columns, origin) => match geo_columns.point(doc) {
+                    Some(point) => SortValue::Float(origin.distance_km(&point)),
+                    None => SortValue::Missing,
+                },
+            })
+            .collect();
+
+        SortValues {
+            values,
+            score,
+            orders: self.orders.clone(),
+        }
+    }
+}
+
+// fast field(또는 fast field 인 JSON 필드의 경로)인지 확인합니다
+pub(crate) fn ensure_fast_field(index: &Index, name: &str) -> Result<(), String> {
+    let schema = index.schema();
+    let is_fast = schema
+        .find_field(name)
+        .map(|(field, _)| schema.get_field_entry(field).is_fast())
+        .unwrap_or(false);
+    if is_fast {
+        Ok(())
+    } else {
+        Err(format!("fast field 가 아닌 필드입니다: {}", name))
+    }
+}
+
+// 문서의 첫 번째 값을 읽습니다 (JSON 경로는 타입별로 컬럼이 여러 개일 수 있음)
+pub(crate) fn first_value(columns: &[DynamicColumn], doc: DocId) -> SortValue {
+    for column in columns {
+        let value = match column {
+            DynamicColumn::I64(column) => column.first(doc).map(SortValue::Integer),
+            DynamicColumn::U64(column) => column
+                .first(doc)
+                .map(|value| SortValue::Integer(value.min(i64::MAX as u64) as i64)),
+            DynamicColumn::F64(column) => column.first(doc).map(SortValue::Float),
+            DynamicColumn::Bool(column) => column.first(doc).map(|value| SortValue::Integer(value as i64)),
+            DynamicColumn::DateTime(column) => column
+                .first(doc)
+                .map(|value| SortValue::Integer(value.into_timestamp_millis())),
+            DynamicColumn::Str(column) => column.term_ords(doc).next().and_then(|ord| {
+                let mut text = String::new();
+                match column.ord_to_str(ord, &mut text) {
+                    Ok(true) => Some(SortValue::Text(text)),
+                    _ => None,
+                }
+            }),
+            DynamicColumn::IpAddr(_) | DynamicColumn::Bytes(_) => None,
+        };
+        if let Some(value) = value {
+            return value;
+        }
+    }
+    SortValue::Missing
+}
@@ -0,0 +1,126 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, RwLock};
+
+use crate::api::char_filter::CharFilterFn;
+
+// 기본 이체자 표 (번체자, 일본 신자체 -> 간체자)
+const DEFAULT_VARIANTS: &str = include_str!("cjk_variants.tsv");
+
+// 이체자 통일을 켤 수 있는 필드
+pub(crate) const FOLDABLE_FIELDS: [&str; 4] = ["title", "body", "title_ngram", "body_ngram"];
+
+/// 이체자와 대표 글자 (각각 한 글자)
+#[derive(Clone, Debug, PartialEq)]
+pub struct VariantMapping {
+    pub variant: String,
+    pub canonical: String,
+}
+
+// 필드별 이체자 통일 설정 (색인과 검색 분석기가 함께 읽습니다)
+#[flutter_rust_bridge::frb(ignore)]
+pub(crate) struct VariantFolding {
+    fields: HashSet<String>,
+    // 사용자 표 (비어 있으면 기본 표, 인덱스 설정에 저장합니다)
+    mappings: Vec<VariantMapping>,
+    table: HashMap<char, char>,
+}
+
+pub(crate) type SharedVariantFolding = Arc<RwLock<VariantFolding>>;
+
+impl Default for VariantFolding {
+    fn default() -> Self {
+        VariantFolding {
+            fields: HashSet::new(),
+            mappings: Vec::new(),
+            table: default_table(),
+        }
+    }
+}
+
+impl VariantFolding {
+    // 사용할 필드와 표를 바꿉니다 (표가 비어 있으면 기본 표)
+    pub(crate) fn configure(
+        &mut self,
+        fields: Vec<String>,
+        mappings: Vec<VariantMapping>,
+    ) -> Result<(), String> {
+        if let Some(field) = fields
+            .iter()
+            .find(|field| !FOLDABLE_FIELDS.contains(&field.as_str()))
+        {
+            return Err(format!("이체자 통일을 지원하지 않는 필드입니다: {}", field));
+        }
+        let table = if mappings.is_empty() {
+            default_table()
+        } else {
+            mapping_table(&mappings)?
+        };
+
+        self.fields = fields.into_iter().collect();
+        self.mappings = mappings;
+        self.table = table;
+        Ok(())
+    }
+
+    // 켠 필드 (이름순)
+    pub(crate) fn fields(&self) -> Vec<String> {
+        let mut fields: Vec<String> = self.fields.iter().cloned().collect();
+        fields.sort();
+        fields
+    }
+
+    pub(crate) fn mappings(&self) -> &[VariantMapping] {
+        &self.mappings
+    }
+
+    fn fold(&self, field: &str, text: &str) -> String {
+        if !self.fields.contains(field) {
+            return text.to_string();
+        }
+        text.chars()
+            .map(|c| self.table.get(&c).copied().unwrap_or(c))
+            .collect()
+    }
+}
+
+fn default_table() -> HashMap<char, char> {
+    DEFAULT_VARIANTS
+        .lines()
+        .filter(|line| !line.starts_with('#'))
+        .filter_map(|line| {
+            let (variant, canonical) = line.split_once('\t')?;
+            Some((variant.chars().next()?, canonical.chars().next()?))
+        })
+        .collect()
+}
+
+// 사용자 표를 검사합니다 (토큰 위치가 원문과 맞도록 UTF-8 길이가 같은 한 글자끼리만)
+fn mapping_table(mappings: &[VariantMapping]) -> Result<HashMap<char, char>, String> {
+    let single_char = |text: &str| -> Option<char> {
+        let mut chars = text.chars();
+        let c = chars.next()?;
+        chars.next().is_none().then_some(c)
+    };
+
+    let mut table = HashMap::new();
+    for mapping in mappings {
+        match (single_char(&mapping.variant), single_char(&mapping.canonical)) {
+            (Some(variant), Some(canonical)) if variant.len_utf8() == canonical.len_utf8() => {
+                table.insert(variant, canonical);
+            }
+            _ => {
+                return Err(format!(
+                    "잘못된 이체자 항목입니다: {} -> {}",
+                    mapping.variant, mapping.canonical
+                ))
+            }
+        }
+    }
+    Ok(table)
+}
+
+// 필드의 분석기에 넣을 문자 필터 (설정이 바뀌면 바로 반영됩니다)
+pub(crate) fn folding_filter(folding: &SharedVariantFolding, field: &'static str) -> CharFilterFn {
+    let folding = folding.clone();
+    Arc::new(move |text: &str| folding.read().unwrap().fold(field, text))
+}
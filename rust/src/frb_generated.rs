@@ -38,7 +38,7 @@ flutter_rust_bridge::frb_generated_boilerplate!(
     default_rust_auto_opaque = RustAutoOpaqueMoi,
 );
 pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_VERSION: &str = "2.12.0";
-pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = -604279136;
+pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = -1344905961;
 
 // Section: executor
 
@@ -110,6 +110,39 @@ fn wire__crate__api__search__add_documents_impl(
         },
     )
 }
+fn wire__crate__api__search__autocomplete_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "autocomplete",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_prefix = <String>::sse_decode(&mut deserializer);
+            let api_limit = <usize>::sse_decode(&mut deserializer);
+            let api_source = <crate::api::search::SuggestionSource>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok =
+                    crate::api::search::autocomplete(api_prefix, api_limit, api_source)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
 fn wire__crate__api__search__clear_all_documents_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -139,14 +172,14 @@ fn wire__crate__api__search__clear_all_documents_impl(
         },
     )
 }
-fn wire__crate__api__search__delete_document_impl(
+fn wire__crate__api__search__configure_chosung_fields_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "delete_document",
+            debug_name: "configure_chosung_fields",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -160,23 +193,24 @@ fn wire__crate__api__search__delete_document_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_id = <String>::sse_decode(&mut deserializer);
+            let api_title = <bool>::sse_decode(&mut deserializer);
+            let api_body = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
             transform_result_sse::<_, String>((move || {
-                let output_ok = crate::api::search::delete_document(api_id)?;
+                let output_ok = crate::api::search::configure_chosung_fields(api_title, api_body)?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__search__delete_documents_impl(
+fn wire__crate__api__search__configure_date_fields_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "delete_documents",
+            debug_name: "configure_date_fields",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -190,26 +224,25 @@ fn wire__crate__api__search__delete_documents_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_ids = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_paths = <Vec<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             transform_result_sse::<_, String>((move || {
-                let output_ok = crate::api::search::delete_documents(api_ids)?;
+                let output_ok = crate::api::search::configure_date_fields(api_paths)?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__search__dictionary_type_to_embedded_path_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
+fn wire__crate__api__search__configure_hanja_readings_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "dictionary_type_to_embedded_path",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            debug_name: "configure_hanja_readings",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
             let message = unsafe {
@@ -221,30 +254,25 @@ fn wire__crate__api__search__dictionary_type_to_embedded_path_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <crate::api::search::DictionaryType>::sse_decode(&mut deserializer);
+            let api_enabled = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok({
-                        crate::api::search::DictionaryType::to_embedded_path(&api_that);
-                    })?;
-                    Ok(output_ok)
-                })())
-            }
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::search::configure_hanja_readings(api_enabled)?;
+                Ok(output_ok)
+            })())
         },
     )
 }
-fn wire__crate__api__search__dictionary_type_to_tokenizer_name_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
+fn wire__crate__api__search__configure_japanese_readings_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "dictionary_type_to_tokenizer_name",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            debug_name: "configure_japanese_readings",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
             let message = unsafe {
@@ -256,27 +284,25 @@ fn wire__crate__api__search__dictionary_type_to_tokenizer_name_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_that = <crate::api::search::DictionaryType>::sse_decode(&mut deserializer);
+            let api_enabled = <bool>::sse_decode(&mut deserializer);
+            let api_romaji = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok({
-                        crate::api::search::DictionaryType::to_tokenizer_name(&api_that);
-                    })?;
-                    Ok(output_ok)
-                })())
-            }
+            transform_result_sse::<_, String>((move || {
+                let output_ok =
+                    crate::api::search::configure_japanese_readings(api_enabled, api_romaji)?;
+                Ok(output_ok)
+            })())
         },
     )
 }
-fn wire__crate__api__search__get_document_count_impl(
+fn wire__crate__api__search__configure_location_field_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_document_count",
+            debug_name: "configure_location_field",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -290,22 +316,23 @@ fn wire__crate__api__search__get_document_count_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             transform_result_sse::<_, String>((move || {
-                let output_ok = crate::api::search::get_document_count()?;
+                let output_ok = crate::api::search::configure_location_field(api_path)?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__simple__greet_impl(
+fn wire__crate__api__search__configure_pinyin_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "greet",
+            debug_name: "configure_pinyin",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -319,23 +346,23 @@ fn wire__crate__api__simple__greet_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_name = <String>::sse_decode(&mut deserializer);
+            let api_enabled = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(crate::api::simple::greet(api_name))?;
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::search::configure_pinyin(api_enabled)?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__search__index_sample_documents_impl(
+fn wire__crate__api__search__configure_variant_folding_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "index_sample_documents",
+            debug_name: "configure_variant_folding",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -349,25 +376,28 @@ fn wire__crate__api__search__index_sample_documents_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_fields = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_mappings =
+                <Vec<crate::api::variant::VariantMapping>>::sse_decode(&mut deserializer);
             deserializer.end();
             transform_result_sse::<_, String>((move || {
-                let output_ok = crate::api::search::index_sample_documents()?;
+                let output_ok =
+                    crate::api::search::configure_variant_folding(api_fields, api_mappings)?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__simple__init_app_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
+fn wire__crate__api__search__count_documents_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "init_app",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            debug_name: "count_documents",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
             let message = unsafe {
@@ -379,26 +409,24 @@ fn wire__crate__api__simple__init_app_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_query_str = <String>::sse_decode(&mut deserializer);
+            let api_options = <crate::api::search::SearchOptions>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok({
-                        crate::api::simple::init_app();
-                    })?;
-                    Ok(output_ok)
-                })())
-            }
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::search::count_documents(api_query_str, api_options)?;
+                Ok(output_ok)
+            })())
         },
     )
 }
-fn wire__crate__api__search__initialize_search_index_impl(
+fn wire__crate__api__search__count_with_query_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "initialize_search_index",
+            debug_name: "count_with_query",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -412,24 +440,24 @@ fn wire__crate__api__search__initialize_search_index_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_dictionary_type =
-                <crate::api::search::DictionaryType>::sse_decode(&mut deserializer);
+            let api_query = <crate::api::query::QueryNode>::sse_decode(&mut deserializer);
+            let api_options = <crate::api::search::SearchOptions>::sse_decode(&mut deserializer);
             deserializer.end();
             transform_result_sse::<_, String>((move || {
-                let output_ok = crate::api::search::initialize_search_index(api_dictionary_type)?;
+                let output_ok = crate::api::search::count_with_query(api_query, api_options)?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__search__initialize_search_index_with_path_impl(
+fn wire__crate__api__search__delete_document_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "initialize_search_index_with_path",
+            debug_name: "delete_document",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -443,28 +471,23 @@ fn wire__crate__api__search__initialize_search_index_with_path_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_dictionary_type =
-                <crate::api::search::DictionaryType>::sse_decode(&mut deserializer);
-            let api_index_path = <String>::sse_decode(&mut deserializer);
+            let api_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             transform_result_sse::<_, String>((move || {
-                let output_ok = crate::api::search::initialize_search_index_with_path(
-                    api_dictionary_type,
-                    api_index_path,
-                )?;
+                let output_ok = crate::api::search::delete_document(api_id)?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__search__search_documents_impl(
+fn wire__crate__api__search__delete_documents_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "search_documents",
+            debug_name: "delete_documents",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -478,24 +501,93 @@ fn wire__crate__api__search__search_documents_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_query_str = <String>::sse_decode(&mut deserializer);
-            let api_limit = <usize>::sse_decode(&mut deserializer);
+            let api_ids = <Vec<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             transform_result_sse::<_, String>((move || {
-                let output_ok = crate::api::search::search_documents(api_query_str, api_limit)?;
+                let output_ok = crate::api::search::delete_documents(api_ids)?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__search__tokenize_text_impl(
+fn wire__crate__api__search__dictionary_type_to_embedded_path_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dictionary_type_to_embedded_path",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <crate::api::search::DictionaryType>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::search::DictionaryType::to_embedded_path(&api_that);
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__search__dictionary_type_to_tokenizer_name_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dictionary_type_to_tokenizer_name",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <crate::api::search::DictionaryType>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::search::DictionaryType::to_tokenizer_name(&api_that);
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__search__document_exists_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "tokenize_text",
+            debug_name: "document_exists",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -509,27 +601,23 @@ fn wire__crate__api__search__tokenize_text_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_dictionary_type =
-                <crate::api::search::DictionaryType>::sse_decode(&mut deserializer);
-            let api_text = <String>::sse_decode(&mut deserializer);
-            let api_mode = <crate::api::search::TokenMode>::sse_decode(&mut deserializer);
+            let api_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             transform_result_sse::<_, String>((move || {
-                let output_ok =
-                    crate::api::search::tokenize_text(api_dictionary_type, api_text, api_mode)?;
+                let output_ok = crate::api::search::document_exists(api_id)?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__search__tokenize_text_detailed_impl(
+fn wire__crate__api__search__explain_document_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "tokenize_text_detailed",
+            debug_name: "explain_document",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -543,30 +631,26 @@ fn wire__crate__api__search__tokenize_text_detailed_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_dictionary_type =
-                <crate::api::search::DictionaryType>::sse_decode(&mut deserializer);
-            let api_text = <String>::sse_decode(&mut deserializer);
-            let api_mode = <crate::api::search::TokenMode>::sse_decode(&mut deserializer);
+            let api_query_str = <String>::sse_decode(&mut deserializer);
+            let api_id = <String>::sse_decode(&mut deserializer);
+            let api_options = <crate::api::search::SearchOptions>::sse_decode(&mut deserializer);
             deserializer.end();
             transform_result_sse::<_, String>((move || {
-                let output_ok = crate::api::search::tokenize_text_detailed(
-                    api_dictionary_type,
-                    api_text,
-                    api_mode,
-                )?;
+                let output_ok =
+                    crate::api::search::explain_document(api_query_str, api_id, api_options)?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__search__update_document_impl(
+fn wire__crate__api__search__explain_structured_query_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "update_document",
+            debug_name: "explain_structured_query",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -580,493 +664,4503 @@ fn wire__crate__api__search__update_document_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_query = <crate::api::query::QueryNode>::sse_decode(&mut deserializer);
             let api_id = <String>::sse_decode(&mut deserializer);
-            let api_title = <String>::sse_decode(&mut deserializer);
-            let api_body = <String>::sse_decode(&mut deserializer);
-            let api_metadata_json = <String>::sse_decode(&mut deserializer);
+            let api_options = <crate::api::search::SearchOptions>::sse_decode(&mut deserializer);
             deserializer.end();
             transform_result_sse::<_, String>((move || {
-                let output_ok = crate::api::search::update_document(
-                    api_id,
-                    api_title,
-                    api_body,
-                    api_metadata_json,
-                )?;
+                let output_ok =
+                    crate::api::search::explain_structured_query(api_query, api_id, api_options)?;
                 Ok(output_ok)
             })())
         },
     )
 }
-
-// Section: dart2rust
-
-impl SseDecode for String {
-    // Codec=Sse (Serialization based), see doc to use other codecs
+fn wire__crate__api__projection__field_selection_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "field_selection_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::projection::FieldSelection::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__search__fuzzy_options_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "fuzzy_options_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::search::FuzzyOptions::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__search__get_document_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_document",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::search::get_document(api_id)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__search__get_document_count_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_document_count",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::search::get_document_count()?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__search__get_documents_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_documents",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_ids = <Vec<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::search::get_documents(api_ids)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__simple__greet_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "greet",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_name = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::simple::greet(api_name))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__search__index_sample_documents_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "index_sample_documents",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::search::index_sample_documents()?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__simple__init_app_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "init_app",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::simple::init_app();
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__search__initialize_search_index_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "initialize_search_index",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_dictionary_type =
+                <crate::api::search::DictionaryType>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::search::initialize_search_index(api_dictionary_type)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__search__initialize_search_index_with_path_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "initialize_search_index_with_path",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_dictionary_type =
+                <crate::api::search::DictionaryType>::sse_decode(&mut deserializer);
+            let api_index_path = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::search::initialize_search_index_with_path(
+                    api_dictionary_type,
+                    api_index_path,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__search__more_like_this_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "more_like_this",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_source =
+                <crate::api::more_like_this::MoreLikeThisSource>::sse_decode(&mut deserializer);
+            let api_mlt_options =
+                <crate::api::more_like_this::MoreLikeThisOptions>::sse_decode(&mut deserializer);
+            let api_options = <crate::api::search::SearchOptions>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok =
+                    crate::api::search::more_like_this(api_source, api_mlt_options, api_options)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__more_like_this__more_like_this_options_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "more_like_this_options_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::more_like_this::MoreLikeThisOptions::default(),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__search__scan_documents_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "scan_documents",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_cursor = <Option<String>>::sse_decode(&mut deserializer);
+            let api_limit = <usize>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::search::scan_documents(api_cursor, api_limit)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__explain__score_explanation_to_pretty_string_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "score_explanation_to_pretty_string",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_that = <crate::api::explain::ScoreExplanation>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::explain::ScoreExplanation::to_pretty_string(&api_that),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__search__search_as_you_type_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "search_as_you_type",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_query_str = <String>::sse_decode(&mut deserializer);
+            let api_options = <crate::api::search::SearchOptions>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::search::search_as_you_type(api_query_str, api_options)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__search__search_documents_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "search_documents",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_query_str = <String>::sse_decode(&mut deserializer);
+            let api_limit = <usize>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::search::search_documents(api_query_str, api_limit)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__search__search_documents_with_options_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "search_documents_with_options",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_query_str = <String>::sse_decode(&mut deserializer);
+            let api_options = <crate::api::search::SearchOptions>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok =
+                    crate::api::search::search_documents_with_options(api_query_str, api_options)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__search__search_options_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "search_options_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::search::SearchOptions::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__search__search_with_query_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "search_with_query",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_query = <crate::api::query::QueryNode>::sse_decode(&mut deserializer);
+            let api_options = <crate::api::search::SearchOptions>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::search::search_with_query(api_query, api_options)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__search__snippet_options_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "snippet_options_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::search::SnippetOptions::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__search__tokenize_text_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "tokenize_text",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_dictionary_type =
+                <crate::api::search::DictionaryType>::sse_decode(&mut deserializer);
+            let api_text = <String>::sse_decode(&mut deserializer);
+            let api_mode = <crate::api::search::TokenMode>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok =
+                    crate::api::search::tokenize_text(api_dictionary_type, api_text, api_mode)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__search__tokenize_text_detailed_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "tokenize_text_detailed",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_dictionary_type =
+                <crate::api::search::DictionaryType>::sse_decode(&mut deserializer);
+            let api_text = <String>::sse_decode(&mut deserializer);
+            let api_mode = <crate::api::search::TokenMode>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::search::tokenize_text_detailed(
+                    api_dictionary_type,
+                    api_text,
+                    api_mode,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__search__update_document_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "update_document",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <String>::sse_decode(&mut deserializer);
+            let api_title = <String>::sse_decode(&mut deserializer);
+            let api_body = <String>::sse_decode(&mut deserializer);
+            let api_metadata_json = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::search::update_document(
+                    api_id,
+                    api_title,
+                    api_body,
+                    api_metadata_json,
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+
+// Section: dart2rust
+
+impl SseDecode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <Vec<u8>>::sse_decode(deserializer);
+        return String::from_utf8(inner).unwrap();
+    }
+}
+
+impl SseDecode for crate::api::aggregation::AggregationBucket {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_key = <String>::sse_decode(deserializer);
+        let mut var_docCount = <u64>::sse_decode(deserializer);
+        let mut var_from = <Option<f64>>::sse_decode(deserializer);
+        let mut var_to = <Option<f64>>::sse_decode(deserializer);
+        return crate::api::aggregation::AggregationBucket {
+            key: var_key,
+            doc_count: var_docCount,
+            from: var_from,
+            to: var_to,
+        };
+    }
+}
+
+impl SseDecode for crate::api::aggregation::AggregationKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_field = <String>::sse_decode(deserializer);
+                let mut var_size = <u32>::sse_decode(deserializer);
+                return crate::api::aggregation::AggregationKind::Terms {
+                    field: var_field,
+                    size: var_size,
+                };
+            }
+            1 => {
+                let mut var_field = <String>::sse_decode(deserializer);
+                let mut var_ranges =
+                    <Vec<crate::api::aggregation::AggregationRange>>::sse_decode(deserializer);
+                return crate::api::aggregation::AggregationKind::Range {
+                    field: var_field,
+                    ranges: var_ranges,
+                };
+            }
+            2 => {
+                let mut var_field = <String>::sse_decode(deserializer);
+                let mut var_interval = <f64>::sse_decode(deserializer);
+                return crate::api::aggregation::AggregationKind::Histogram {
+                    field: var_field,
+                    interval: var_interval,
+                };
+            }
+            3 => {
+                let mut var_field = <String>::sse_decode(deserializer);
+                let mut var_fixedInterval = <String>::sse_decode(deserializer);
+                return crate::api::aggregation::AggregationKind::DateHistogram {
+                    field: var_field,
+                    fixed_interval: var_fixedInterval,
+                };
+            }
+            4 => {
+                let mut var_field = <String>::sse_decode(deserializer);
+                return crate::api::aggregation::AggregationKind::Min { field: var_field };
+            }
+            5 => {
+                let mut var_field = <String>::sse_decode(deserializer);
+                return crate::api::aggregation::AggregationKind::Max { field: var_field };
+            }
+            6 => {
+                let mut var_field = <String>::sse_decode(deserializer);
+                return crate::api::aggregation::AggregationKind::Avg { field: var_field };
+            }
+            7 => {
+                let mut var_field = <String>::sse_decode(deserializer);
+                return crate::api::aggregation::AggregationKind::Stats { field: var_field };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::aggregation::AggregationRange {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_from = <Option<f64>>::sse_decode(deserializer);
+        let mut var_to = <Option<f64>>::sse_decode(deserializer);
+        return crate::api::aggregation::AggregationRange {
+            from: var_from,
+            to: var_to,
+        };
+    }
+}
+
+impl SseDecode for crate::api::aggregation::AggregationRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_kind = <crate::api::aggregation::AggregationKind>::sse_decode(deserializer);
+        return crate::api::aggregation::AggregationRequest {
+            name: var_name,
+            kind: var_kind,
+        };
+    }
+}
+
+impl SseDecode for crate::api::aggregation::AggregationResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_value = <crate::api::aggregation::AggregationValue>::sse_decode(deserializer);
+        return crate::api::aggregation::AggregationResult {
+            name: var_name,
+            value: var_value,
+        };
+    }
+}
+
+impl SseDecode for crate::api::aggregation::AggregationStats {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_count = <u64>::sse_decode(deserializer);
+        let mut var_sum = <f64>::sse_decode(deserializer);
+        let mut var_min = <Option<f64>>::sse_decode(deserializer);
+        let mut var_max = <Option<f64>>::sse_decode(deserializer);
+        let mut var_avg = <Option<f64>>::sse_decode(deserializer);
+        return crate::api::aggregation::AggregationStats {
+            count: var_count,
+            sum: var_sum,
+            min: var_min,
+            max: var_max,
+            avg: var_avg,
+        };
+    }
+}
+
+impl SseDecode for crate::api::aggregation::AggregationValue {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_field0 =
+                    <Vec<crate::api::aggregation::AggregationBucket>>::sse_decode(deserializer);
+                return crate::api::aggregation::AggregationValue::Buckets(var_field0);
+            }
+            1 => {
+                let mut var_field0 = <Option<f64>>::sse_decode(deserializer);
+                return crate::api::aggregation::AggregationValue::Metric(var_field0);
+            }
+            2 => {
+                let mut var_field0 =
+                    <crate::api::aggregation::AggregationStats>::sse_decode(deserializer);
+                return crate::api::aggregation::AggregationValue::Stats(var_field0);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap() != 0
+    }
+}
+
+impl SseDecode for Box<crate::api::query::QueryNode> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        return Box::new(<crate::api::query::QueryNode>::sse_decode(deserializer));
+    }
+}
+
+impl SseDecode for crate::api::facet::CategoryCount {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_path = <String>::sse_decode(deserializer);
+        let mut var_count = <u64>::sse_decode(deserializer);
+        return crate::api::facet::CategoryCount {
+            path: var_path,
+            count: var_count,
+        };
+    }
+}
+
+impl SseDecode for crate::api::facet::CategoryCounts {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_parent = <String>::sse_decode(deserializer);
+        let mut var_children = <Vec<crate::api::facet::CategoryCount>>::sse_decode(deserializer);
+        return crate::api::facet::CategoryCounts {
+            parent: var_parent,
+            children: var_children,
+        };
+    }
+}
+
+impl SseDecode for crate::api::scoring::DecayFunction {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::scoring::DecayFunction::Exponential,
+            1 => crate::api::scoring::DecayFunction::Gaussian,
+            _ => unreachable!("Invalid variant for DecayFunction: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::search::DictionaryType {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::search::DictionaryType::Korean,
+            1 => crate::api::search::DictionaryType::JapaneseIpadic,
+            2 => crate::api::search::DictionaryType::JapaneseUnidic,
+            3 => crate::api::search::DictionaryType::Chinese,
+            _ => unreachable!("Invalid variant for DictionaryType: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::search::DocumentInput {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_title = <String>::sse_decode(deserializer);
+        let mut var_body = <String>::sse_decode(deserializer);
+        let mut var_metadata = <String>::sse_decode(deserializer);
+        return crate::api::search::DocumentInput {
+            id: var_id,
+            title: var_title,
+            body: var_body,
+            metadata: var_metadata,
+        };
+    }
+}
+
+impl SseDecode for crate::api::search::DocumentPage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_documents = <Vec<crate::api::search::StoredDocument>>::sse_decode(deserializer);
+        let mut var_nextCursor = <Option<String>>::sse_decode(deserializer);
+        return crate::api::search::DocumentPage {
+            documents: var_documents,
+            next_cursor: var_nextCursor,
+        };
+    }
+}
+
+impl SseDecode for f32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_f32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for f64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_f64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for crate::api::projection::FieldSelection {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                return crate::api::projection::FieldSelection::All;
+            }
+            1 => {
+                return crate::api::projection::FieldSelection::IdOnly;
+            }
+            2 => {
+                let mut var_field0 = <Vec<String>>::sse_decode(deserializer);
+                return crate::api::projection::FieldSelection::Fields(var_field0);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::search::FuzzyOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_distance = <u8>::sse_decode(deserializer);
+        let mut var_transposition = <bool>::sse_decode(deserializer);
+        let mut var_prefixLength = <usize>::sse_decode(deserializer);
+        return crate::api::search::FuzzyOptions {
+            distance: var_distance,
+            transposition: var_transposition,
+            prefix_length: var_prefixLength,
+        };
+    }
+}
+
+impl SseDecode for crate::api::geo::GeoFilter {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_topLeft = <crate::api::geo::GeoPoint>::sse_decode(deserializer);
+                let mut var_bottomRight = <crate::api::geo::GeoPoint>::sse_decode(deserializer);
+                return crate::api::geo::GeoFilter::BoundingBox {
+                    top_left: var_topLeft,
+                    bottom_right: var_bottomRight,
+                };
+            }
+            1 => {
+                let mut var_center = <crate::api::geo::GeoPoint>::sse_decode(deserializer);
+                let mut var_distanceKm = <f64>::sse_decode(deserializer);
+                return crate::api::geo::GeoFilter::Radius {
+                    center: var_center,
+                    distance_km: var_distanceKm,
+                };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::geo::GeoPoint {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_lat = <f64>::sse_decode(deserializer);
+        let mut var_lon = <f64>::sse_decode(deserializer);
+        return crate::api::geo::GeoPoint {
+            lat: var_lat,
+            lon: var_lon,
+        };
+    }
+}
+
+impl SseDecode for crate::api::group::GroupByOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_field = <String>::sse_decode(deserializer);
+        let mut var_groupsLimit = <usize>::sse_decode(deserializer);
+        let mut var_hitsPerGroup = <usize>::sse_decode(deserializer);
+        return crate::api::group::GroupByOptions {
+            field: var_field,
+            groups_limit: var_groupsLimit,
+            hits_per_group: var_hitsPerGroup,
+        };
+    }
+}
+
+impl SseDecode for crate::api::search::HighlightRange {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_start = <u32>::sse_decode(deserializer);
+        let mut var_end = <u32>::sse_decode(deserializer);
+        return crate::api::search::HighlightRange {
+            start: var_start,
+            end: var_end,
+        };
+    }
+}
+
+impl SseDecode for i32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for i64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<String>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::aggregation::AggregationBucket> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::aggregation::AggregationBucket>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::aggregation::AggregationRange> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::aggregation::AggregationRange>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::aggregation::AggregationRequest> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::aggregation::AggregationRequest>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::aggregation::AggregationResult> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::aggregation::AggregationResult>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::facet::CategoryCount> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::facet::CategoryCount>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::facet::CategoryCounts> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::facet::CategoryCounts>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::search::DocumentInput> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::search::DocumentInput>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::search::HighlightRange> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::search::HighlightRange>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<u8>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::search::QueryDiagnostic> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::search::QueryDiagnostic>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::query::QueryNode> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::query::QueryNode>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::explain::ScoreExplanation> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::explain::ScoreExplanation>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::scoring::ScoreModifier> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::scoring::ScoreModifier>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::search::SearchGroup> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::search::SearchGroup>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::search::SearchHit> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::search::SearchHit>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::search::SearchResult> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::search::SearchResult>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::sort::SortKey> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::sort::SortKey>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::sort::SortValue> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::sort::SortValue>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::search::StoredDocument> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::search::StoredDocument>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::search::Suggestion> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::search::Suggestion>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::search::TokenDetail> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::search::TokenDetail>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::variant::VariantMapping> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = Vec::with_capacity(len_ as usize);
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::variant::VariantMapping>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for crate::api::more_like_this::MoreLikeThisOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_minDocFreq = <u64>::sse_decode(deserializer);
+        let mut var_minTermFreq = <usize>::sse_decode(deserializer);
+        let mut var_maxQueryTerms = <usize>::sse_decode(deserializer);
+        return crate::api::more_like_this::MoreLikeThisOptions {
+            min_doc_freq: var_minDocFreq,
+            min_term_freq: var_minTermFreq,
+            max_query_terms: var_maxQueryTerms,
+        };
+    }
+}
+
+impl SseDecode for crate::api::more_like_this::MoreLikeThisSource {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::more_like_this::MoreLikeThisSource::DocumentId(var_field0);
+            }
+            1 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::more_like_this::MoreLikeThisSource::Text(var_field0);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for Option<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<String>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<f64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<f64>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::search::FuzzyOptions> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::search::FuzzyOptions>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::geo::GeoFilter> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::geo::GeoFilter>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::group::GroupByOptions> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::group::GroupByOptions>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<i64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<i64>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::query::RangeBound> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::query::RangeBound>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::search::SearchSnippet> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::search::SearchSnippet>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::search::SnippetOptions> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::search::SnippetOptions>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::search::StoredDocument> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::search::StoredDocument>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u32>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<usize> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<usize>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for crate::api::search::QueryDiagnostic {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_position = <Option<u32>>::sse_decode(deserializer);
+        let mut var_message = <String>::sse_decode(deserializer);
+        return crate::api::search::QueryDiagnostic {
+            position: var_position,
+            message: var_message,
+        };
+    }
+}
+
+impl SseDecode for crate::api::query::QueryNode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                return crate::api::query::QueryNode::MatchAll;
+            }
+            1 => {
+                let mut var_field = <String>::sse_decode(deserializer);
+                let mut var_text = <String>::sse_decode(deserializer);
+                return crate::api::query::QueryNode::Term {
+                    field: var_field,
+                    text: var_text,
+                };
+            }
+            2 => {
+                let mut var_field = <String>::sse_decode(deserializer);
+                let mut var_text = <String>::sse_decode(deserializer);
+                let mut var_slop = <u32>::sse_decode(deserializer);
+                return crate::api::query::QueryNode::Phrase {
+                    field: var_field,
+                    text: var_text,
+                    slop: var_slop,
+                };
+            }
+            3 => {
+                let mut var_must = <Vec<crate::api::query::QueryNode>>::sse_decode(deserializer);
+                let mut var_should = <Vec<crate::api::query::QueryNode>>::sse_decode(deserializer);
+                let mut var_mustNot = <Vec<crate::api::query::QueryNode>>::sse_decode(deserializer);
+                return crate::api::query::QueryNode::Boolean {
+                    must: var_must,
+                    should: var_should,
+                    must_not: var_mustNot,
+                };
+            }
+            4 => {
+                let mut var_query = <Box<crate::api::query::QueryNode>>::sse_decode(deserializer);
+                let mut var_boost = <f32>::sse_decode(deserializer);
+                return crate::api::query::QueryNode::Boost {
+                    query: var_query,
+                    boost: var_boost,
+                };
+            }
+            5 => {
+                let mut var_field = <String>::sse_decode(deserializer);
+                let mut var_text = <String>::sse_decode(deserializer);
+                let mut var_distance = <u8>::sse_decode(deserializer);
+                let mut var_transposition = <bool>::sse_decode(deserializer);
+                return crate::api::query::QueryNode::Fuzzy {
+                    field: var_field,
+                    text: var_text,
+                    distance: var_distance,
+                    transposition: var_transposition,
+                };
+            }
+            6 => {
+                let mut var_field = <String>::sse_decode(deserializer);
+                let mut var_prefix = <String>::sse_decode(deserializer);
+                return crate::api::query::QueryNode::Prefix {
+                    field: var_field,
+                    prefix: var_prefix,
+                };
+            }
+            7 => {
+                let mut var_field = <String>::sse_decode(deserializer);
+                let mut var_lower =
+                    <Option<crate::api::query::RangeBound>>::sse_decode(deserializer);
+                let mut var_upper =
+                    <Option<crate::api::query::RangeBound>>::sse_decode(deserializer);
+                return crate::api::query::QueryNode::Range {
+                    field: var_field,
+                    lower: var_lower,
+                    upper: var_upper,
+                };
+            }
+            8 => {
+                let mut var_field = <String>::sse_decode(deserializer);
+                let mut var_path = <String>::sse_decode(deserializer);
+                return crate::api::query::QueryNode::Facet {
+                    field: var_field,
+                    path: var_path,
+                };
+            }
+            9 => {
+                let mut var_filter = <crate::api::geo::GeoFilter>::sse_decode(deserializer);
+                return crate::api::query::QueryNode::Geo { filter: var_filter };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::query::RangeBound {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_value = <crate::api::query::RangeValue>::sse_decode(deserializer);
+        let mut var_inclusive = <bool>::sse_decode(deserializer);
+        return crate::api::query::RangeBound {
+            value: var_value,
+            inclusive: var_inclusive,
+        };
+    }
+}
+
+impl SseDecode for crate::api::query::RangeValue {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::query::RangeValue::Text(var_field0);
+            }
+            1 => {
+                let mut var_field0 = <i64>::sse_decode(deserializer);
+                return crate::api::query::RangeValue::Integer(var_field0);
+            }
+            2 => {
+                let mut var_field0 = <f64>::sse_decode(deserializer);
+                return crate::api::query::RangeValue::Float(var_field0);
+            }
+            3 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::query::RangeValue::Date(var_field0);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::explain::ScoreExplanation {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_value = <f32>::sse_decode(deserializer);
+        let mut var_description = <String>::sse_decode(deserializer);
+        let mut var_details =
+            <Vec<crate::api::explain::ScoreExplanation>>::sse_decode(deserializer);
+        let mut var_context = <Vec<String>>::sse_decode(deserializer);
+        return crate::api::explain::ScoreExplanation {
+            value: var_value,
+            description: var_description,
+            details: var_details,
+            context: var_context,
+        };
+    }
+}
+
+impl SseDecode for crate::api::scoring::ScoreModifier {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_field = <String>::sse_decode(deserializer);
+                let mut var_function =
+                    <crate::api::scoring::DecayFunction>::sse_decode(deserializer);
+                let mut var_origin = <Option<i64>>::sse_decode(deserializer);
+                let mut var_scaleDays = <f64>::sse_decode(deserializer);
+                let mut var_offsetDays = <f64>::sse_decode(deserializer);
+                let mut var_decay = <f64>::sse_decode(deserializer);
+                return crate::api::scoring::ScoreModifier::DateDecay {
+                    field: var_field,
+                    function: var_function,
+                    origin: var_origin,
+                    scale_days: var_scaleDays,
+                    offset_days: var_offsetDays,
+                    decay: var_decay,
+                };
+            }
+            1 => {
+                let mut var_field = <String>::sse_decode(deserializer);
+                let mut var_factor = <f64>::sse_decode(deserializer);
+                return crate::api::scoring::ScoreModifier::LogBoost {
+                    field: var_field,
+                    factor: var_factor,
+                };
+            }
+            2 => {
+                let mut var_field = <String>::sse_decode(deserializer);
+                let mut var_weight = <f64>::sse_decode(deserializer);
+                return crate::api::scoring::ScoreModifier::StaticRank {
+                    field: var_field,
+                    weight: var_weight,
+                };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::search::SearchGroup {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_key = <Option<String>>::sse_decode(deserializer);
+        let mut var_count = <u64>::sse_decode(deserializer);
+        let mut var_hits = <Vec<crate::api::search::SearchHit>>::sse_decode(deserializer);
+        return crate::api::search::SearchGroup {
+            key: var_key,
+            count: var_count,
+            hits: var_hits,
+        };
+    }
+}
+
+impl SseDecode for crate::api::search::SearchHit {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_title = <String>::sse_decode(deserializer);
+        let mut var_body = <String>::sse_decode(deserializer);
+        let mut var_score = <f32>::sse_decode(deserializer);
+        let mut var_metadata = <String>::sse_decode(deserializer);
+        let mut var_snippet = <Option<crate::api::search::SearchSnippet>>::sse_decode(deserializer);
+        let mut var_sortValues = <Vec<crate::api::sort::SortValue>>::sse_decode(deserializer);
+        let mut var_createdAt = <i64>::sse_decode(deserializer);
+        let mut var_updatedAt = <i64>::sse_decode(deserializer);
+        let mut var_distanceKm = <Option<f64>>::sse_decode(deserializer);
+        return crate::api::search::SearchHit {
+            id: var_id,
+            title: var_title,
+            body: var_body,
+            score: var_score,
+            metadata: var_metadata,
+            snippet: var_snippet,
+            sort_values: var_sortValues,
+            created_at: var_createdAt,
+            updated_at: var_updatedAt,
+            distance_km: var_distanceKm,
+        };
+    }
+}
+
+impl SseDecode for crate::api::search::SearchOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_limit = <usize>::sse_decode(deserializer);
+        let mut var_snippet =
+            <Option<crate::api::search::SnippetOptions>>::sse_decode(deserializer);
+        let mut var_fuzzy = <Option<crate::api::search::FuzzyOptions>>::sse_decode(deserializer);
+        let mut var_jamoFuzzy =
+            <Option<crate::api::search::FuzzyOptions>>::sse_decode(deserializer);
+        let mut var_lenient = <bool>::sse_decode(deserializer);
+        let mut var_sort = <Vec<crate::api::sort::SortKey>>::sse_decode(deserializer);
+        let mut var_aggregations =
+            <Vec<crate::api::aggregation::AggregationRequest>>::sse_decode(deserializer);
+        let mut var_categoryFilter = <Option<String>>::sse_decode(deserializer);
+        let mut var_categoryCounts = <Vec<String>>::sse_decode(deserializer);
+        let mut var_geoFilter = <Option<crate::api::geo::GeoFilter>>::sse_decode(deserializer);
+        let mut var_collapse = <Option<String>>::sse_decode(deserializer);
+        let mut var_groupBy = <Option<crate::api::group::GroupByOptions>>::sse_decode(deserializer);
+        let mut var_scoreModifiers =
+            <Vec<crate::api::scoring::ScoreModifier>>::sse_decode(deserializer);
+        let mut var_fields = <crate::api::projection::FieldSelection>::sse_decode(deserializer);
+        let mut var_bodyMaxChars = <Option<usize>>::sse_decode(deserializer);
+        return crate::api::search::SearchOptions {
+            limit: var_limit,
+            snippet: var_snippet,
+            fuzzy: var_fuzzy,
+            jamo_fuzzy: var_jamoFuzzy,
+            lenient: var_lenient,
+            sort: var_sort,
+            aggregations: var_aggregations,
+            category_filter: var_categoryFilter,
+            category_counts: var_categoryCounts,
+            geo_filter: var_geoFilter,
+            collapse: var_collapse,
+            group_by: var_groupBy,
+            score_modifiers: var_scoreModifiers,
+            fields: var_fields,
+            body_max_chars: var_bodyMaxChars,
+        };
+    }
+}
+
+impl SseDecode for crate::api::search::SearchResponse {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_hits = <Vec<crate::api::search::SearchHit>>::sse_decode(deserializer);
+        let mut var_diagnostics =
+            <Vec<crate::api::search::QueryDiagnostic>>::sse_decode(deserializer);
+        let mut var_aggregations =
+            <Vec<crate::api::aggregation::AggregationResult>>::sse_decode(deserializer);
+        let mut var_categoryCounts =
+            <Vec<crate::api::facet::CategoryCounts>>::sse_decode(deserializer);
+        let mut var_groups = <Vec<crate::api::search::SearchGroup>>::sse_decode(deserializer);
+        return crate::api::search::SearchResponse {
+            hits: var_hits,
+            diagnostics: var_diagnostics,
+            aggregations: var_aggregations,
+            category_counts: var_categoryCounts,
+            groups: var_groups,
+        };
+    }
+}
+
+impl SseDecode for crate::api::search::SearchResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_title = <String>::sse_decode(deserializer);
+        let mut var_body = <String>::sse_decode(deserializer);
+        let mut var_score = <f32>::sse_decode(deserializer);
+        let mut var_metadata = <String>::sse_decode(deserializer);
+        let mut var_createdAt = <i64>::sse_decode(deserializer);
+        let mut var_updatedAt = <i64>::sse_decode(deserializer);
+        return crate::api::search::SearchResult {
+            id: var_id,
+            title: var_title,
+            body: var_body,
+            score: var_score,
+            metadata: var_metadata,
+            created_at: var_createdAt,
+            updated_at: var_updatedAt,
+        };
+    }
+}
+
+impl SseDecode for crate::api::search::SearchSnippet {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_fragment = <String>::sse_decode(deserializer);
+        let mut var_highlights =
+            <Vec<crate::api::search::HighlightRange>>::sse_decode(deserializer);
+        let mut var_html = <Option<String>>::sse_decode(deserializer);
+        return crate::api::search::SearchSnippet {
+            fragment: var_fragment,
+            highlights: var_highlights,
+            html: var_html,
+        };
+    }
+}
+
+impl SseDecode for crate::api::search::SnippetOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_field = <String>::sse_decode(deserializer);
+        let mut var_maxNumChars = <usize>::sse_decode(deserializer);
+        let mut var_html = <bool>::sse_decode(deserializer);
+        return crate::api::search::SnippetOptions {
+            field: var_field,
+            max_num_chars: var_maxNumChars,
+            html: var_html,
+        };
+    }
+}
+
+impl SseDecode for crate::api::sort::SortBy {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                return crate::api::sort::SortBy::Score;
+            }
+            1 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::sort::SortBy::Field(var_field0);
+            }
+            2 => {
+                let mut var_field0 = <crate::api::geo::GeoPoint>::sse_decode(deserializer);
+                return crate::api::sort::SortBy::Distance(var_field0);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::sort::SortKey {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_by = <crate::api::sort::SortBy>::sse_decode(deserializer);
+        let mut var_order = <crate::api::sort::SortOrder>::sse_decode(deserializer);
+        return crate::api::sort::SortKey {
+            by: var_by,
+            order: var_order,
+        };
+    }
+}
+
+impl SseDecode for crate::api::sort::SortOrder {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::sort::SortOrder::Asc,
+            1 => crate::api::sort::SortOrder::Desc,
+            _ => unreachable!("Invalid variant for SortOrder: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::sort::SortValue {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_field0 = <i64>::sse_decode(deserializer);
+                return crate::api::sort::SortValue::Integer(var_field0);
+            }
+            1 => {
+                let mut var_field0 = <f64>::sse_decode(deserializer);
+                return crate::api::sort::SortValue::Float(var_field0);
+            }
+            2 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::sort::SortValue::Text(var_field0);
+            }
+            3 => {
+                return crate::api::sort::SortValue::Missing;
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::search::StoredDocument {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_title = <String>::sse_decode(deserializer);
+        let mut var_body = <String>::sse_decode(deserializer);
+        let mut var_metadata = <String>::sse_decode(deserializer);
+        let mut var_createdAt = <i64>::sse_decode(deserializer);
+        let mut var_updatedAt = <i64>::sse_decode(deserializer);
+        return crate::api::search::StoredDocument {
+            id: var_id,
+            title: var_title,
+            body: var_body,
+            metadata: var_metadata,
+            created_at: var_createdAt,
+            updated_at: var_updatedAt,
+        };
+    }
+}
+
+impl SseDecode for crate::api::search::Suggestion {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_text = <String>::sse_decode(deserializer);
+        let mut var_source = <crate::api::search::SuggestionSource>::sse_decode(deserializer);
+        let mut var_docCount = <u64>::sse_decode(deserializer);
+        return crate::api::search::Suggestion {
+            text: var_text,
+            source: var_source,
+            doc_count: var_docCount,
+        };
+    }
+}
+
+impl SseDecode for crate::api::search::SuggestionSource {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::search::SuggestionSource::Title,
+            1 => crate::api::search::SuggestionSource::Term,
+            _ => unreachable!("Invalid variant for SuggestionSource: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::search::TokenDetail {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_surface = <String>::sse_decode(deserializer);
+        let mut var_pos = <String>::sse_decode(deserializer);
+        let mut var_details = <Vec<String>>::sse_decode(deserializer);
+        return crate::api::search::TokenDetail {
+            surface: var_surface,
+            pos: var_pos,
+            details: var_details,
+        };
+    }
+}
+
+impl SseDecode for crate::api::search::TokenMode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <Vec<u8>>::sse_decode(deserializer);
-        return String::from_utf8(inner).unwrap();
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::search::TokenMode::Normal,
+            1 => crate::api::search::TokenMode::Decompose,
+            _ => unreachable!("Invalid variant for TokenMode: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for u32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u8 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap()
+    }
+}
+
+impl SseDecode for () {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+}
+
+impl SseDecode for usize {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u64::<NativeEndian>().unwrap() as _
+    }
+}
+
+impl SseDecode for crate::api::variant::VariantMapping {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_variant = <String>::sse_decode(deserializer);
+        let mut var_canonical = <String>::sse_decode(deserializer);
+        return crate::api::variant::VariantMapping {
+            variant: var_variant,
+            canonical: var_canonical,
+        };
+    }
+}
+
+fn pde_ffi_dispatcher_primary_impl(
+    func_id: i32,
+    port: flutter_rust_bridge::for_generated::MessagePort,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        16 => wire__crate__api__search__dictionary_type_to_embedded_path_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        17 => wire__crate__api__search__dictionary_type_to_tokenizer_name_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        21 => wire__crate__api__projection__field_selection_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        22 => {
+            wire__crate__api__search__fuzzy_options_default_impl(port, ptr, rust_vec_len, data_len)
+        }
+        28 => wire__crate__api__simple__init_app_impl(port, ptr, rust_vec_len, data_len),
+        32 => wire__crate__api__more_like_this__more_like_this_options_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        34 => wire__crate__api__explain__score_explanation_to_pretty_string_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        38 => {
+            wire__crate__api__search__search_options_default_impl(port, ptr, rust_vec_len, data_len)
+        }
+        40 => wire__crate__api__search__snippet_options_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        _ => unreachable!(),
+    }
+}
+
+fn pde_ffi_dispatcher_sync_impl(
+    func_id: i32,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        1 => wire__crate__api__search__add_document_impl(ptr, rust_vec_len, data_len),
+        2 => wire__crate__api__search__add_documents_impl(ptr, rust_vec_len, data_len),
+        3 => wire__crate__api__search__autocomplete_impl(ptr, rust_vec_len, data_len),
+        4 => wire__crate__api__search__clear_all_documents_impl(ptr, rust_vec_len, data_len),
+        5 => wire__crate__api__search__configure_chosung_fields_impl(ptr, rust_vec_len, data_len),
+        6 => wire__crate__api__search__configure_date_fields_impl(ptr, rust_vec_len, data_len),
+        7 => wire__crate__api__search__configure_hanja_readings_impl(ptr, rust_vec_len, data_len),
+        8 => {
+            wire__crate__api__search__configure_japanese_readings_impl(ptr, rust_vec_len, data_len)
+        }
+        9 => wire__crate__api__search__configure_location_field_impl(ptr, rust_vec_len, data_len),
+        10 => wire__crate__api__search__configure_pinyin_impl(ptr, rust_vec_len, data_len),
+        11 => wire__crate__api__search__configure_variant_folding_impl(ptr, rust_vec_len, data_len),
+        12 => wire__crate__api__search__count_documents_impl(ptr, rust_vec_len, data_len),
+        13 => wire__crate__api__search__count_with_query_impl(ptr, rust_vec_len, data_len),
+        14 => wire__crate__api__search__delete_document_impl(ptr, rust_vec_len, data_len),
+        15 => wire__crate__api__search__delete_documents_impl(ptr, rust_vec_len, data_len),
+        18 => wire__crate__api__search__document_exists_impl(ptr, rust_vec_len, data_len),
+        19 => wire__crate__api__search__explain_document_impl(ptr, rust_vec_len, data_len),
+        20 => wire__crate__api__search__explain_structured_query_impl(ptr, rust_vec_len, data_len),
+        23 => wire__crate__api__search__get_document_impl(ptr, rust_vec_len, data_len),
+        24 => wire__crate__api__search__get_document_count_impl(ptr, rust_vec_len, data_len),
+        25 => wire__crate__api__search__get_documents_impl(ptr, rust_vec_len, data_len),
+        26 => wire__crate__api__simple__greet_impl(ptr, rust_vec_len, data_len),
+        27 => wire__crate__api__search__index_sample_documents_impl(ptr, rust_vec_len, data_len),
+        29 => wire__crate__api__search__initialize_search_index_impl(ptr, rust_vec_len, data_len),
+        30 => wire__crate__api__search__initialize_search_index_with_path_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        31 => wire__crate__api__search__more_like_this_impl(ptr, rust_vec_len, data_len),
+        33 => wire__crate__api__search__scan_documents_impl(ptr, rust_vec_len, data_len),
+        35 => wire__crate__api__search__search_as_you_type_impl(ptr, rust_vec_len, data_len),
+        36 => wire__crate__api__search__search_documents_impl(ptr, rust_vec_len, data_len),
+        37 => wire__crate__api__search__search_documents_with_options_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        39 => wire__crate__api__search__search_with_query_impl(ptr, rust_vec_len, data_len),
+        41 => wire__crate__api__search__tokenize_text_impl(ptr, rust_vec_len, data_len),
+        42 => wire__crate__api__search__tokenize_text_detailed_impl(ptr, rust_vec_len, data_len),
+        43 => wire__crate__api__search__update_document_impl(ptr, rust_vec_len, data_len),
+        _ => unreachable!(),
+    }
+}
+
+// Section: rust2dart
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::aggregation::AggregationBucket {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.key.into_into_dart().into_dart(),
+            self.doc_count.into_into_dart().into_dart(),
+            self.from.into_into_dart().into_dart(),
+            self.to.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::aggregation::AggregationBucket
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::aggregation::AggregationBucket>
+    for crate::api::aggregation::AggregationBucket
+{
+    fn into_into_dart(self) -> crate::api::aggregation::AggregationBucket {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::aggregation::AggregationKind {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::aggregation::AggregationKind::Terms { field, size } => [
+                0.into_dart(),
+                field.into_into_dart().into_dart(),
+                size.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::aggregation::AggregationKind::Range { field, ranges } => [
+                1.into_dart(),
+                field.into_into_dart().into_dart(),
+                ranges.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::aggregation::AggregationKind::Histogram { field, interval } => [
+                2.into_dart(),
+                field.into_into_dart().into_dart(),
+                interval.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::aggregation::AggregationKind::DateHistogram {
+                field,
+                fixed_interval,
+            } => [
+                3.into_dart(),
+                field.into_into_dart().into_dart(),
+                fixed_interval.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::aggregation::AggregationKind::Min { field } => {
+                [4.into_dart(), field.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::aggregation::AggregationKind::Max { field } => {
+                [5.into_dart(), field.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::aggregation::AggregationKind::Avg { field } => {
+                [6.into_dart(), field.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::aggregation::AggregationKind::Stats { field } => {
+                [7.into_dart(), field.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::aggregation::AggregationKind
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::aggregation::AggregationKind>
+    for crate::api::aggregation::AggregationKind
+{
+    fn into_into_dart(self) -> crate::api::aggregation::AggregationKind {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::aggregation::AggregationRange {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.from.into_into_dart().into_dart(),
+            self.to.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::aggregation::AggregationRange
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::aggregation::AggregationRange>
+    for crate::api::aggregation::AggregationRange
+{
+    fn into_into_dart(self) -> crate::api::aggregation::AggregationRange {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::aggregation::AggregationRequest {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.name.into_into_dart().into_dart(),
+            self.kind.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::aggregation::AggregationRequest
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::aggregation::AggregationRequest>
+    for crate::api::aggregation::AggregationRequest
+{
+    fn into_into_dart(self) -> crate::api::aggregation::AggregationRequest {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::aggregation::AggregationResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.name.into_into_dart().into_dart(),
+            self.value.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::aggregation::AggregationResult
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::aggregation::AggregationResult>
+    for crate::api::aggregation::AggregationResult
+{
+    fn into_into_dart(self) -> crate::api::aggregation::AggregationResult {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::aggregation::AggregationStats {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.count.into_into_dart().into_dart(),
+            self.sum.into_into_dart().into_dart(),
+            self.min.into_into_dart().into_dart(),
+            self.max.into_into_dart().into_dart(),
+            self.avg.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::aggregation::AggregationStats
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::aggregation::AggregationStats>
+    for crate::api::aggregation::AggregationStats
+{
+    fn into_into_dart(self) -> crate::api::aggregation::AggregationStats {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::aggregation::AggregationValue {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::aggregation::AggregationValue::Buckets(field0) => {
+                [0.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::aggregation::AggregationValue::Metric(field0) => {
+                [1.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::aggregation::AggregationValue::Stats(field0) => {
+                [2.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::aggregation::AggregationValue
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::aggregation::AggregationValue>
+    for crate::api::aggregation::AggregationValue
+{
+    fn into_into_dart(self) -> crate::api::aggregation::AggregationValue {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::facet::CategoryCount {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.path.into_into_dart().into_dart(),
+            self.count.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::facet::CategoryCount
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::facet::CategoryCount>
+    for crate::api::facet::CategoryCount
+{
+    fn into_into_dart(self) -> crate::api::facet::CategoryCount {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::facet::CategoryCounts {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.parent.into_into_dart().into_dart(),
+            self.children.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::facet::CategoryCounts
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::facet::CategoryCounts>
+    for crate::api::facet::CategoryCounts
+{
+    fn into_into_dart(self) -> crate::api::facet::CategoryCounts {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::scoring::DecayFunction {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Exponential => 0.into_dart(),
+            Self::Gaussian => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::scoring::DecayFunction
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::scoring::DecayFunction>
+    for crate::api::scoring::DecayFunction
+{
+    fn into_into_dart(self) -> crate::api::scoring::DecayFunction {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::search::DictionaryType {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Korean => 0.into_dart(),
+            Self::JapaneseIpadic => 1.into_dart(),
+            Self::JapaneseUnidic => 2.into_dart(),
+            Self::Chinese => 3.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::search::DictionaryType
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::search::DictionaryType>
+    for crate::api::search::DictionaryType
+{
+    fn into_into_dart(self) -> crate::api::search::DictionaryType {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::search::DocumentInput {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.title.into_into_dart().into_dart(),
+            self.body.into_into_dart().into_dart(),
+            self.metadata.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::search::DocumentInput
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::search::DocumentInput>
+    for crate::api::search::DocumentInput
+{
+    fn into_into_dart(self) -> crate::api::search::DocumentInput {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::search::DocumentPage {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.documents.into_into_dart().into_dart(),
+            self.next_cursor.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::search::DocumentPage
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::search::DocumentPage>
+    for crate::api::search::DocumentPage
+{
+    fn into_into_dart(self) -> crate::api::search::DocumentPage {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::projection::FieldSelection {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::projection::FieldSelection::All => [0.into_dart()].into_dart(),
+            crate::api::projection::FieldSelection::IdOnly => [1.into_dart()].into_dart(),
+            crate::api::projection::FieldSelection::Fields(field0) => {
+                [2.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::projection::FieldSelection
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::projection::FieldSelection>
+    for crate::api::projection::FieldSelection
+{
+    fn into_into_dart(self) -> crate::api::projection::FieldSelection {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::search::FuzzyOptions {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.distance.into_into_dart().into_dart(),
+            self.transposition.into_into_dart().into_dart(),
+            self.prefix_length.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::search::FuzzyOptions
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::search::FuzzyOptions>
+    for crate::api::search::FuzzyOptions
+{
+    fn into_into_dart(self) -> crate::api::search::FuzzyOptions {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::geo::GeoFilter {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::geo::GeoFilter::BoundingBox {
+                top_left,
+                bottom_right,
+            } => [
+                0.into_dart(),
+                top_left.into_into_dart().into_dart(),
+                bottom_right.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::geo::GeoFilter::Radius {
+                center,
+                distance_km,
+            } => [
+                1.into_dart(),
+                center.into_into_dart().into_dart(),
+                distance_km.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::geo::GeoFilter {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::geo::GeoFilter> for crate::api::geo::GeoFilter {
+    fn into_into_dart(self) -> crate::api::geo::GeoFilter {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::geo::GeoPoint {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.lat.into_into_dart().into_dart(),
+            self.lon.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::geo::GeoPoint {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::geo::GeoPoint> for crate::api::geo::GeoPoint {
+    fn into_into_dart(self) -> crate::api::geo::GeoPoint {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::group::GroupByOptions {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.field.into_into_dart().into_dart(),
+            self.groups_limit.into_into_dart().into_dart(),
+            self.hits_per_group.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::group::GroupByOptions
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::group::GroupByOptions>
+    for crate::api::group::GroupByOptions
+{
+    fn into_into_dart(self) -> crate::api::group::GroupByOptions {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::search::HighlightRange {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.start.into_into_dart().into_dart(),
+            self.end.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::search::HighlightRange
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::search::HighlightRange>
+    for crate::api::search::HighlightRange
+{
+    fn into_into_dart(self) -> crate::api::search::HighlightRange {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::more_like_this::MoreLikeThisOptions {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.min_doc_freq.into_into_dart().into_dart(),
+            self.min_term_freq.into_into_dart().into_dart(),
+            self.max_query_terms.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::more_like_this::MoreLikeThisOptions
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::more_like_this::MoreLikeThisOptions>
+    for crate::api::more_like_this::MoreLikeThisOptions
+{
+    fn into_into_dart(self) -> crate::api::more_like_this::MoreLikeThisOptions {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::more_like_this::MoreLikeThisSource {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::more_like_this::MoreLikeThisSource::DocumentId(field0) => {
+                [0.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::more_like_this::MoreLikeThisSource::Text(field0) => {
+                [1.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::more_like_this::MoreLikeThisSource
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::more_like_this::MoreLikeThisSource>
+    for crate::api::more_like_this::MoreLikeThisSource
+{
+    fn into_into_dart(self) -> crate::api::more_like_this::MoreLikeThisSource {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::search::QueryDiagnostic {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.position.into_into_dart().into_dart(),
+            self.message.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::search::QueryDiagnostic
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::search::QueryDiagnostic>
+    for crate::api::search::QueryDiagnostic
+{
+    fn into_into_dart(self) -> crate::api::search::QueryDiagnostic {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::query::QueryNode {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::query::QueryNode::MatchAll => [0.into_dart()].into_dart(),
+            crate::api::query::QueryNode::Term { field, text } => [
+                1.into_dart(),
+                field.into_into_dart().into_dart(),
+                text.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::query::QueryNode::Phrase { field, text, slop } => [
+                2.into_dart(),
+                field.into_into_dart().into_dart(),
+                text.into_into_dart().into_dart(),
+                slop.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::query::QueryNode::Boolean {
+                must,
+                should,
+                must_not,
+            } => [
+                3.into_dart(),
+                must.into_into_dart().into_dart(),
+                should.into_into_dart().into_dart(),
+                must_not.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::query::QueryNode::Boost { query, boost } => [
+                4.into_dart(),
+                query.into_into_dart().into_dart(),
+                boost.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::query::QueryNode::Fuzzy {
+                field,
+                text,
+                distance,
+                transposition,
+            } => [
+                5.into_dart(),
+                field.into_into_dart().into_dart(),
+                text.into_into_dart().into_dart(),
+                distance.into_into_dart().into_dart(),
+                transposition.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::query::QueryNode::Prefix { field, prefix } => [
+                6.into_dart(),
+                field.into_into_dart().into_dart(),
+                prefix.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::query::QueryNode::Range {
+                field,
+                lower,
+                upper,
+            } => [
+                7.into_dart(),
+                field.into_into_dart().into_dart(),
+                lower.into_into_dart().into_dart(),
+                upper.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::query::QueryNode::Facet { field, path } => [
+                8.into_dart(),
+                field.into_into_dart().into_dart(),
+                path.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::query::QueryNode::Geo { filter } => {
+                [9.into_dart(), filter.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::query::QueryNode {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::query::QueryNode>
+    for crate::api::query::QueryNode
+{
+    fn into_into_dart(self) -> crate::api::query::QueryNode {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::query::RangeBound {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.value.into_into_dart().into_dart(),
+            self.inclusive.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::query::RangeBound {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::query::RangeBound>
+    for crate::api::query::RangeBound
+{
+    fn into_into_dart(self) -> crate::api::query::RangeBound {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::query::RangeValue {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::query::RangeValue::Text(field0) => {
+                [0.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::query::RangeValue::Integer(field0) => {
+                [1.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::query::RangeValue::Float(field0) => {
+                [2.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::query::RangeValue::Date(field0) => {
+                [3.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::query::RangeValue {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::query::RangeValue>
+    for crate::api::query::RangeValue
+{
+    fn into_into_dart(self) -> crate::api::query::RangeValue {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::explain::ScoreExplanation {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.value.into_into_dart().into_dart(),
+            self.description.into_into_dart().into_dart(),
+            self.details.into_into_dart().into_dart(),
+            self.context.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::explain::ScoreExplanation
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::explain::ScoreExplanation>
+    for crate::api::explain::ScoreExplanation
+{
+    fn into_into_dart(self) -> crate::api::explain::ScoreExplanation {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::scoring::ScoreModifier {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::scoring::ScoreModifier::DateDecay {
+                field,
+                function,
+                origin,
+                scale_days,
+                offset_days,
+                decay,
+            } => [
+                0.into_dart(),
+                field.into_into_dart().into_dart(),
+                function.into_into_dart().into_dart(),
+                origin.into_into_dart().into_dart(),
+                scale_days.into_into_dart().into_dart(),
+                offset_days.into_into_dart().into_dart(),
+                decay.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::scoring::ScoreModifier::LogBoost { field, factor } => [
+                1.into_dart(),
+                field.into_into_dart().into_dart(),
+                factor.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::scoring::ScoreModifier::StaticRank { field, weight } => [
+                2.into_dart(),
+                field.into_into_dart().into_dart(),
+                weight.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::scoring::ScoreModifier
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::scoring::ScoreModifier>
+    for crate::api::scoring::ScoreModifier
+{
+    fn into_into_dart(self) -> crate::api::scoring::ScoreModifier {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::search::SearchGroup {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.key.into_into_dart().into_dart(),
+            self.count.into_into_dart().into_dart(),
+            self.hits.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::search::SearchGroup
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::search::SearchGroup>
+    for crate::api::search::SearchGroup
+{
+    fn into_into_dart(self) -> crate::api::search::SearchGroup {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::search::SearchHit {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.title.into_into_dart().into_dart(),
+            self.body.into_into_dart().into_dart(),
+            self.score.into_into_dart().into_dart(),
+            self.metadata.into_into_dart().into_dart(),
+            self.snippet.into_into_dart().into_dart(),
+            self.sort_values.into_into_dart().into_dart(),
+            self.created_at.into_into_dart().into_dart(),
+            self.updated_at.into_into_dart().into_dart(),
+            self.distance_km.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::search::SearchHit {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::search::SearchHit>
+    for crate::api::search::SearchHit
+{
+    fn into_into_dart(self) -> crate::api::search::SearchHit {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::search::SearchOptions {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.limit.into_into_dart().into_dart(),
+            self.snippet.into_into_dart().into_dart(),
+            self.fuzzy.into_into_dart().into_dart(),
+            self.jamo_fuzzy.into_into_dart().into_dart(),
+            self.lenient.into_into_dart().into_dart(),
+            self.sort.into_into_dart().into_dart(),
+            self.aggregations.into_into_dart().into_dart(),
+            self.category_filter.into_into_dart().into_dart(),
+            self.category_counts.into_into_dart().into_dart(),
+            self.geo_filter.into_into_dart().into_dart(),
+            self.collapse.into_into_dart().into_dart(),
+            self.group_by.into_into_dart().into_dart(),
+            self.score_modifiers.into_into_dart().into_dart(),
+            self.fields.into_into_dart().into_dart(),
+            self.body_max_chars.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::search::SearchOptions
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::search::SearchOptions>
+    for crate::api::search::SearchOptions
+{
+    fn into_into_dart(self) -> crate::api::search::SearchOptions {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::search::SearchResponse {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.hits.into_into_dart().into_dart(),
+            self.diagnostics.into_into_dart().into_dart(),
+            self.aggregations.into_into_dart().into_dart(),
+            self.category_counts.into_into_dart().into_dart(),
+            self.groups.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::search::SearchResponse
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::search::SearchResponse>
+    for crate::api::search::SearchResponse
+{
+    fn into_into_dart(self) -> crate::api::search::SearchResponse {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::search::SearchResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.title.into_into_dart().into_dart(),
+            self.body.into_into_dart().into_dart(),
+            self.score.into_into_dart().into_dart(),
+            self.metadata.into_into_dart().into_dart(),
+            self.created_at.into_into_dart().into_dart(),
+            self.updated_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::search::SearchResult
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::search::SearchResult>
+    for crate::api::search::SearchResult
+{
+    fn into_into_dart(self) -> crate::api::search::SearchResult {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::search::SearchSnippet {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.fragment.into_into_dart().into_dart(),
+            self.highlights.into_into_dart().into_dart(),
+            self.html.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::search::SearchSnippet
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::search::SearchSnippet>
+    for crate::api::search::SearchSnippet
+{
+    fn into_into_dart(self) -> crate::api::search::SearchSnippet {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::search::SnippetOptions {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.field.into_into_dart().into_dart(),
+            self.max_num_chars.into_into_dart().into_dart(),
+            self.html.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::search::SnippetOptions
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::search::SnippetOptions>
+    for crate::api::search::SnippetOptions
+{
+    fn into_into_dart(self) -> crate::api::search::SnippetOptions {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::sort::SortBy {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::sort::SortBy::Score => [0.into_dart()].into_dart(),
+            crate::api::sort::SortBy::Field(field0) => {
+                [1.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::sort::SortBy::Distance(field0) => {
+                [2.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::sort::SortBy {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::sort::SortBy> for crate::api::sort::SortBy {
+    fn into_into_dart(self) -> crate::api::sort::SortBy {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::sort::SortKey {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.by.into_into_dart().into_dart(),
+            self.order.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::sort::SortKey {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::sort::SortKey> for crate::api::sort::SortKey {
+    fn into_into_dart(self) -> crate::api::sort::SortKey {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::sort::SortOrder {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Asc => 0.into_dart(),
+            Self::Desc => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::sort::SortOrder {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::sort::SortOrder>
+    for crate::api::sort::SortOrder
+{
+    fn into_into_dart(self) -> crate::api::sort::SortOrder {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::sort::SortValue {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::sort::SortValue::Integer(field0) => {
+                [0.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::sort::SortValue::Float(field0) => {
+                [1.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::sort::SortValue::Text(field0) => {
+                [2.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::sort::SortValue::Missing => [3.into_dart()].into_dart(),
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::sort::SortValue {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::sort::SortValue>
+    for crate::api::sort::SortValue
+{
+    fn into_into_dart(self) -> crate::api::sort::SortValue {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::search::StoredDocument {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.title.into_into_dart().into_dart(),
+            self.body.into_into_dart().into_dart(),
+            self.metadata.into_into_dart().into_dart(),
+            self.created_at.into_into_dart().into_dart(),
+            self.updated_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::search::StoredDocument
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::search::StoredDocument>
+    for crate::api::search::StoredDocument
+{
+    fn into_into_dart(self) -> crate::api::search::StoredDocument {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::search::Suggestion {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.text.into_into_dart().into_dart(),
+            self.source.into_into_dart().into_dart(),
+            self.doc_count.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::search::Suggestion
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::search::Suggestion>
+    for crate::api::search::Suggestion
+{
+    fn into_into_dart(self) -> crate::api::search::Suggestion {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::search::SuggestionSource {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Title => 0.into_dart(),
+            Self::Term => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::search::SuggestionSource
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::search::SuggestionSource>
+    for crate::api::search::SuggestionSource
+{
+    fn into_into_dart(self) -> crate::api::search::SuggestionSource {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::search::TokenDetail {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.surface.into_into_dart().into_dart(),
+            self.pos.into_into_dart().into_dart(),
+            self.details.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::search::TokenDetail
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::search::TokenDetail>
+    for crate::api::search::TokenDetail
+{
+    fn into_into_dart(self) -> crate::api::search::TokenDetail {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::search::TokenMode {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Normal => 0.into_dart(),
+            Self::Decompose => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::search::TokenMode {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::search::TokenMode>
+    for crate::api::search::TokenMode
+{
+    fn into_into_dart(self) -> crate::api::search::TokenMode {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::variant::VariantMapping {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.variant.into_into_dart().into_dart(),
+            self.canonical.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::variant::VariantMapping
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::variant::VariantMapping>
+    for crate::api::variant::VariantMapping
+{
+    fn into_into_dart(self) -> crate::api::variant::VariantMapping {
+        self
+    }
+}
+
+impl SseEncode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
+    }
+}
+
+impl SseEncode for crate::api::aggregation::AggregationBucket {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.key, serializer);
+        <u64>::sse_encode(self.doc_count, serializer);
+        <Option<f64>>::sse_encode(self.from, serializer);
+        <Option<f64>>::sse_encode(self.to, serializer);
+    }
+}
+
+impl SseEncode for crate::api::aggregation::AggregationKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::aggregation::AggregationKind::Terms { field, size } => {
+                <i32>::sse_encode(0, serializer);
+                <String>::sse_encode(field, serializer);
+                <u32>::sse_encode(size, serializer);
+            }
+            crate::api::aggregation::AggregationKind::Range { field, ranges } => {
+                <i32>::sse_encode(1, serializer);
+                <String>::sse_encode(field, serializer);
+                <Vec<crate::api::aggregation::AggregationRange>>::sse_encode(ranges, serializer);
+            }
+            crate::api::aggregation::AggregationKind::Histogram { field, interval } => {
+                <i32>::sse_encode(2, serializer);
+                <String>::sse_encode(field, serializer);
+                <f64>::sse_encode(interval, serializer);
+            }
+            crate::api::aggregation::AggregationKind::DateHistogram {
+                field,
+                fixed_interval,
+            } => {
+                <i32>::sse_encode(3, serializer);
+                <String>::sse_encode(field, serializer);
+                <String>::sse_encode(fixed_interval, serializer);
+            }
+            crate::api::aggregation::AggregationKind::Min { field } => {
+                <i32>::sse_encode(4, serializer);
+                <String>::sse_encode(field, serializer);
+            }
+            crate::api::aggregation::AggregationKind::Max { field } => {
+                <i32>::sse_encode(5, serializer);
+                <String>::sse_encode(field, serializer);
+            }
+            crate::api::aggregation::AggregationKind::Avg { field } => {
+                <i32>::sse_encode(6, serializer);
+                <String>::sse_encode(field, serializer);
+            }
+            crate::api::aggregation::AggregationKind::Stats { field } => {
+                <i32>::sse_encode(7, serializer);
+                <String>::sse_encode(field, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseEncode for crate::api::aggregation::AggregationRange {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<f64>>::sse_encode(self.from, serializer);
+        <Option<f64>>::sse_encode(self.to, serializer);
+    }
+}
+
+impl SseEncode for crate::api::aggregation::AggregationRequest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.name, serializer);
+        <crate::api::aggregation::AggregationKind>::sse_encode(self.kind, serializer);
+    }
+}
+
+impl SseEncode for crate::api::aggregation::AggregationResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.name, serializer);
+        <crate::api::aggregation::AggregationValue>::sse_encode(self.value, serializer);
+    }
+}
+
+impl SseEncode for crate::api::aggregation::AggregationStats {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.count, serializer);
+        <f64>::sse_encode(self.sum, serializer);
+        <Option<f64>>::sse_encode(self.min, serializer);
+        <Option<f64>>::sse_encode(self.max, serializer);
+        <Option<f64>>::sse_encode(self.avg, serializer);
+    }
+}
+
+impl SseEncode for crate::api::aggregation::AggregationValue {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::aggregation::AggregationValue::Buckets(field0) => {
+                <i32>::sse_encode(0, serializer);
+                <Vec<crate::api::aggregation::AggregationBucket>>::sse_encode(field0, serializer);
+            }
+            crate::api::aggregation::AggregationValue::Metric(field0) => {
+                <i32>::sse_encode(1, serializer);
+                <Option<f64>>::sse_encode(field0, serializer);
+            }
+            crate::api::aggregation::AggregationValue::Stats(field0) => {
+                <i32>::sse_encode(2, serializer);
+                <crate::api::aggregation::AggregationStats>::sse_encode(field0, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseEncode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u8(self as _).unwrap();
+    }
+}
+
+impl SseEncode for Box<crate::api::query::QueryNode> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::query::QueryNode>::sse_encode(*self, serializer);
+    }
+}
+
+impl SseEncode for crate::api::facet::CategoryCount {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.path, serializer);
+        <u64>::sse_encode(self.count, serializer);
+    }
+}
+
+impl SseEncode for crate::api::facet::CategoryCounts {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.parent, serializer);
+        <Vec<crate::api::facet::CategoryCount>>::sse_encode(self.children, serializer);
+    }
+}
+
+impl SseEncode for crate::api::scoring::DecayFunction {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::scoring::DecayFunction::Exponential => 0,
+                crate::api::scoring::DecayFunction::Gaussian => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::search::DictionaryType {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::search::DictionaryType::Korean => 0,
+                crate::api::search::DictionaryType::JapaneseIpadic => 1,
+                crate::api::search::DictionaryType::JapaneseUnidic => 2,
+                crate::api::search::DictionaryType::Chinese => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::search::DocumentInput {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.title, serializer);
+        <String>::sse_encode(self.body, serializer);
+        <String>::sse_encode(self.metadata, serializer);
+    }
+}
+
+impl SseEncode for crate::api::search::DocumentPage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<crate::api::search::StoredDocument>>::sse_encode(self.documents, serializer);
+        <Option<String>>::sse_encode(self.next_cursor, serializer);
+    }
+}
+
+impl SseEncode for f32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_f32::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for f64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_f64::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for crate::api::projection::FieldSelection {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::projection::FieldSelection::All => {
+                <i32>::sse_encode(0, serializer);
+            }
+            crate::api::projection::FieldSelection::IdOnly => {
+                <i32>::sse_encode(1, serializer);
+            }
+            crate::api::projection::FieldSelection::Fields(field0) => {
+                <i32>::sse_encode(2, serializer);
+                <Vec<String>>::sse_encode(field0, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseEncode for crate::api::search::FuzzyOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u8>::sse_encode(self.distance, serializer);
+        <bool>::sse_encode(self.transposition, serializer);
+        <usize>::sse_encode(self.prefix_length, serializer);
+    }
+}
+
+impl SseEncode for crate::api::geo::GeoFilter {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::geo::GeoFilter::BoundingBox {
+                top_left,
+                bottom_right,
+            } => {
+                <i32>::sse_encode(0, serializer);
+                <crate::api::geo::GeoPoint>::sse_encode(top_left, serializer);
+                <crate::api::geo::GeoPoint>::sse_encode(bottom_right, serializer);
+            }
+            crate::api::geo::GeoFilter::Radius {
+                center,
+                distance_km,
+            } => {
+                <i32>::sse_encode(1, serializer);
+                <crate::api::geo::GeoPoint>::sse_encode(center, serializer);
+                <f64>::sse_encode(distance_km, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseEncode for crate::api::geo::GeoPoint {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <f64>::sse_encode(self.lat, serializer);
+        <f64>::sse_encode(self.lon, serializer);
+    }
+}
+
+impl SseEncode for crate::api::group::GroupByOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.field, serializer);
+        <usize>::sse_encode(self.groups_limit, serializer);
+        <usize>::sse_encode(self.hits_per_group, serializer);
+    }
+}
+
+impl SseEncode for crate::api::search::HighlightRange {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.start, serializer);
+        <u32>::sse_encode(self.end, serializer);
+    }
+}
+
+impl SseEncode for i32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for i64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_i64::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <String>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::aggregation::AggregationBucket> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::aggregation::AggregationBucket>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::aggregation::AggregationRange> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::aggregation::AggregationRange>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::aggregation::AggregationRequest> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::aggregation::AggregationRequest>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::aggregation::AggregationResult> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::aggregation::AggregationResult>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::facet::CategoryCount> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::facet::CategoryCount>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::facet::CategoryCounts> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::facet::CategoryCounts>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for crate::api::search::DictionaryType {
+impl SseEncode for Vec<crate::api::search::DocumentInput> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <i32>::sse_decode(deserializer);
-        return match inner {
-            0 => crate::api::search::DictionaryType::Korean,
-            1 => crate::api::search::DictionaryType::JapaneseIpadic,
-            2 => crate::api::search::DictionaryType::JapaneseUnidic,
-            3 => crate::api::search::DictionaryType::Chinese,
-            _ => unreachable!("Invalid variant for DictionaryType: {}", inner),
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::search::DocumentInput>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for crate::api::search::DocumentInput {
+impl SseEncode for Vec<crate::api::search::HighlightRange> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_id = <String>::sse_decode(deserializer);
-        let mut var_title = <String>::sse_decode(deserializer);
-        let mut var_body = <String>::sse_decode(deserializer);
-        let mut var_metadata = <String>::sse_decode(deserializer);
-        return crate::api::search::DocumentInput {
-            id: var_id,
-            title: var_title,
-            body: var_body,
-            metadata: var_metadata,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::search::HighlightRange>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for f32 {
+impl SseEncode for Vec<u8> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_f32::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <u8>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for i32 {
+impl SseEncode for Vec<crate::api::search::QueryDiagnostic> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::search::QueryDiagnostic>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for Vec<String> {
+impl SseEncode for Vec<crate::api::query::QueryNode> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = Vec::with_capacity(len_ as usize);
-        for idx_ in 0..len_ {
-            ans_.push(<String>::sse_decode(deserializer));
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::query::QueryNode>::sse_encode(item, serializer);
         }
-        return ans_;
     }
 }
 
-impl SseDecode for Vec<crate::api::search::DocumentInput> {
+impl SseEncode for Vec<crate::api::explain::ScoreExplanation> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = Vec::with_capacity(len_ as usize);
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::search::DocumentInput>::sse_decode(
-                deserializer,
-            ));
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::explain::ScoreExplanation>::sse_encode(item, serializer);
         }
-        return ans_;
     }
 }
 
-impl SseDecode for Vec<u8> {
+impl SseEncode for Vec<crate::api::scoring::ScoreModifier> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = Vec::with_capacity(len_ as usize);
-        for idx_ in 0..len_ {
-            ans_.push(<u8>::sse_decode(deserializer));
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::scoring::ScoreModifier>::sse_encode(item, serializer);
         }
-        return ans_;
     }
 }
 
-impl SseDecode for Vec<crate::api::search::SearchResult> {
+impl SseEncode for Vec<crate::api::search::SearchGroup> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = Vec::with_capacity(len_ as usize);
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::search::SearchResult>::sse_decode(deserializer));
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::search::SearchGroup>::sse_encode(item, serializer);
         }
-        return ans_;
     }
 }
 
-impl SseDecode for Vec<crate::api::search::TokenDetail> {
+impl SseEncode for Vec<crate::api::search::SearchHit> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = Vec::with_capacity(len_ as usize);
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::search::TokenDetail>::sse_decode(deserializer));
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::search::SearchHit>::sse_encode(item, serializer);
         }
-        return ans_;
     }
 }
 
-impl SseDecode for crate::api::search::SearchResult {
+impl SseEncode for Vec<crate::api::search::SearchResult> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_id = <String>::sse_decode(deserializer);
-        let mut var_title = <String>::sse_decode(deserializer);
-        let mut var_body = <String>::sse_decode(deserializer);
-        let mut var_score = <f32>::sse_decode(deserializer);
-        let mut var_metadata = <String>::sse_decode(deserializer);
-        return crate::api::search::SearchResult {
-            id: var_id,
-            title: var_title,
-            body: var_body,
-            score: var_score,
-            metadata: var_metadata,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::search::SearchResult>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for crate::api::search::TokenDetail {
+impl SseEncode for Vec<crate::api::sort::SortKey> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_surface = <String>::sse_decode(deserializer);
-        let mut var_pos = <String>::sse_decode(deserializer);
-        let mut var_details = <Vec<String>>::sse_decode(deserializer);
-        return crate::api::search::TokenDetail {
-            surface: var_surface,
-            pos: var_pos,
-            details: var_details,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::sort::SortKey>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for crate::api::search::TokenMode {
+impl SseEncode for Vec<crate::api::sort::SortValue> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <i32>::sse_decode(deserializer);
-        return match inner {
-            0 => crate::api::search::TokenMode::Normal,
-            1 => crate::api::search::TokenMode::Decompose,
-            _ => unreachable!("Invalid variant for TokenMode: {}", inner),
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::sort::SortValue>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for u64 {
+impl SseEncode for Vec<crate::api::search::StoredDocument> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u64::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::search::StoredDocument>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for u8 {
+impl SseEncode for Vec<crate::api::search::Suggestion> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::search::Suggestion>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for () {
+impl SseEncode for Vec<crate::api::search::TokenDetail> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::search::TokenDetail>::sse_encode(item, serializer);
+        }
+    }
 }
 
-impl SseDecode for usize {
+impl SseEncode for Vec<crate::api::variant::VariantMapping> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u64::<NativeEndian>().unwrap() as _
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::variant::VariantMapping>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for bool {
+impl SseEncode for crate::api::more_like_this::MoreLikeThisOptions {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap() != 0
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.min_doc_freq, serializer);
+        <usize>::sse_encode(self.min_term_freq, serializer);
+        <usize>::sse_encode(self.max_query_terms, serializer);
     }
 }
 
-fn pde_ffi_dispatcher_primary_impl(
-    func_id: i32,
-    port: flutter_rust_bridge::for_generated::MessagePort,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        6 => wire__crate__api__search__dictionary_type_to_embedded_path_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        7 => wire__crate__api__search__dictionary_type_to_tokenizer_name_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        11 => wire__crate__api__simple__init_app_impl(port, ptr, rust_vec_len, data_len),
-        _ => unreachable!(),
+impl SseEncode for crate::api::more_like_this::MoreLikeThisSource {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::more_like_this::MoreLikeThisSource::DocumentId(field0) => {
+                <i32>::sse_encode(0, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::more_like_this::MoreLikeThisSource::Text(field0) => {
+                <i32>::sse_encode(1, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
     }
 }
 
-fn pde_ffi_dispatcher_sync_impl(
-    func_id: i32,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        1 => wire__crate__api__search__add_document_impl(ptr, rust_vec_len, data_len),
-        2 => wire__crate__api__search__add_documents_impl(ptr, rust_vec_len, data_len),
-        3 => wire__crate__api__search__clear_all_documents_impl(ptr, rust_vec_len, data_len),
-        4 => wire__crate__api__search__delete_document_impl(ptr, rust_vec_len, data_len),
-        5 => wire__crate__api__search__delete_documents_impl(ptr, rust_vec_len, data_len),
-        8 => wire__crate__api__search__get_document_count_impl(ptr, rust_vec_len, data_len),
-        9 => wire__crate__api__simple__greet_impl(ptr, rust_vec_len, data_len),
-        10 => wire__crate__api__search__index_sample_documents_impl(ptr, rust_vec_len, data_len),
-        12 => wire__crate__api__search__initialize_search_index_impl(ptr, rust_vec_len, data_len),
-        13 => wire__crate__api__search__initialize_search_index_with_path_impl(
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        14 => wire__crate__api__search__search_documents_impl(ptr, rust_vec_len, data_len),
-        15 => wire__crate__api__search__tokenize_text_impl(ptr, rust_vec_len, data_len),
-        16 => wire__crate__api__search__tokenize_text_detailed_impl(ptr, rust_vec_len, data_len),
-        17 => wire__crate__api__search__update_document_impl(ptr, rust_vec_len, data_len),
-        _ => unreachable!(),
+impl SseEncode for Option<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <String>::sse_encode(value, serializer);
+        }
     }
 }
 
-// Section: rust2dart
+impl SseEncode for Option<f64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <f64>::sse_encode(value, serializer);
+        }
+    }
+}
 
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::search::DictionaryType {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        match self {
-            Self::Korean => 0.into_dart(),
-            Self::JapaneseIpadic => 1.into_dart(),
-            Self::JapaneseUnidic => 2.into_dart(),
-            Self::Chinese => 3.into_dart(),
-            _ => unreachable!(),
+impl SseEncode for Option<crate::api::search::FuzzyOptions> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::search::FuzzyOptions>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::geo::GeoFilter> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::geo::GeoFilter>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::group::GroupByOptions> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::group::GroupByOptions>::sse_encode(value, serializer);
         }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::search::DictionaryType
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::search::DictionaryType>
-    for crate::api::search::DictionaryType
-{
-    fn into_into_dart(self) -> crate::api::search::DictionaryType {
-        self
+
+impl SseEncode for Option<i64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <i64>::sse_encode(value, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::search::DocumentInput {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.id.into_into_dart().into_dart(),
-            self.title.into_into_dart().into_dart(),
-            self.body.into_into_dart().into_dart(),
-            self.metadata.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for Option<crate::api::query::RangeBound> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::query::RangeBound>::sse_encode(value, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::search::DocumentInput
-{
+
+impl SseEncode for Option<crate::api::search::SearchSnippet> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::search::SearchSnippet>::sse_encode(value, serializer);
+        }
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::search::DocumentInput>
-    for crate::api::search::DocumentInput
-{
-    fn into_into_dart(self) -> crate::api::search::DocumentInput {
-        self
+
+impl SseEncode for Option<crate::api::search::SnippetOptions> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::search::SnippetOptions>::sse_encode(value, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::search::SearchResult {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.id.into_into_dart().into_dart(),
-            self.title.into_into_dart().into_dart(),
-            self.body.into_into_dart().into_dart(),
-            self.score.into_into_dart().into_dart(),
-            self.metadata.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for Option<crate::api::search::StoredDocument> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::search::StoredDocument>::sse_encode(value, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::search::SearchResult
-{
+
+impl SseEncode for Option<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u32>::sse_encode(value, serializer);
+        }
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::search::SearchResult>
-    for crate::api::search::SearchResult
-{
-    fn into_into_dart(self) -> crate::api::search::SearchResult {
-        self
+
+impl SseEncode for Option<usize> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <usize>::sse_encode(value, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::search::TokenDetail {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.surface.into_into_dart().into_dart(),
-            self.pos.into_into_dart().into_dart(),
-            self.details.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for crate::api::search::QueryDiagnostic {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<u32>>::sse_encode(self.position, serializer);
+        <String>::sse_encode(self.message, serializer);
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::search::TokenDetail
-{
+
+impl SseEncode for crate::api::query::QueryNode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::query::QueryNode::MatchAll => {
+                <i32>::sse_encode(0, serializer);
+            }
+            crate::api::query::QueryNode::Term { field, text } => {
+                <i32>::sse_encode(1, serializer);
+                <String>::sse_encode(field, serializer);
+                <String>::sse_encode(text, serializer);
+            }
+            crate::api::query::QueryNode::Phrase { field, text, slop } => {
+                <i32>::sse_encode(2, serializer);
+                <String>::sse_encode(field, serializer);
+                <String>::sse_encode(text, serializer);
+                <u32>::sse_encode(slop, serializer);
+            }
+            crate::api::query::QueryNode::Boolean {
+                must,
+                should,
+                must_not,
+            } => {
+                <i32>::sse_encode(3, serializer);
+                <Vec<crate::api::query::QueryNode>>::sse_encode(must, serializer);
+                <Vec<crate::api::query::QueryNode>>::sse_encode(should, serializer);
+                <Vec<crate::api::query::QueryNode>>::sse_encode(must_not, serializer);
+            }
+            crate::api::query::QueryNode::Boost { query, boost } => {
+                <i32>::sse_encode(4, serializer);
+                <Box<crate::api::query::QueryNode>>::sse_encode(query, serializer);
+                <f32>::sse_encode(boost, serializer);
+            }
+            crate::api::query::QueryNode::Fuzzy {
+                field,
+                text,
+                distance,
+                transposition,
+            } => {
+                <i32>::sse_encode(5, serializer);
+                <String>::sse_encode(field, serializer);
+                <String>::sse_encode(text, serializer);
+                <u8>::sse_encode(distance, serializer);
+                <bool>::sse_encode(transposition, serializer);
+            }
+            crate::api::query::QueryNode::Prefix { field, prefix } => {
+                <i32>::sse_encode(6, serializer);
+                <String>::sse_encode(field, serializer);
+                <String>::sse_encode(prefix, serializer);
+            }
+            crate::api::query::QueryNode::Range {
+                field,
+                lower,
+                upper,
+            } => {
+                <i32>::sse_encode(7, serializer);
+                <String>::sse_encode(field, serializer);
+                <Option<crate::api::query::RangeBound>>::sse_encode(lower, serializer);
+                <Option<crate::api::query::RangeBound>>::sse_encode(upper, serializer);
+            }
+            crate::api::query::QueryNode::Facet { field, path } => {
+                <i32>::sse_encode(8, serializer);
+                <String>::sse_encode(field, serializer);
+                <String>::sse_encode(path, serializer);
+            }
+            crate::api::query::QueryNode::Geo { filter } => {
+                <i32>::sse_encode(9, serializer);
+                <crate::api::geo::GeoFilter>::sse_encode(filter, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::search::TokenDetail>
-    for crate::api::search::TokenDetail
-{
-    fn into_into_dart(self) -> crate::api::search::TokenDetail {
-        self
+
+impl SseEncode for crate::api::query::RangeBound {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::query::RangeValue>::sse_encode(self.value, serializer);
+        <bool>::sse_encode(self.inclusive, serializer);
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::search::TokenMode {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+
+impl SseEncode for crate::api::query::RangeValue {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         match self {
-            Self::Normal => 0.into_dart(),
-            Self::Decompose => 1.into_dart(),
-            _ => unreachable!(),
+            crate::api::query::RangeValue::Text(field0) => {
+                <i32>::sse_encode(0, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::query::RangeValue::Integer(field0) => {
+                <i32>::sse_encode(1, serializer);
+                <i64>::sse_encode(field0, serializer);
+            }
+            crate::api::query::RangeValue::Float(field0) => {
+                <i32>::sse_encode(2, serializer);
+                <f64>::sse_encode(field0, serializer);
+            }
+            crate::api::query::RangeValue::Date(field0) => {
+                <i32>::sse_encode(3, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
         }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::search::TokenMode {}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::search::TokenMode>
-    for crate::api::search::TokenMode
-{
-    fn into_into_dart(self) -> crate::api::search::TokenMode {
-        self
+
+impl SseEncode for crate::api::explain::ScoreExplanation {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <f32>::sse_encode(self.value, serializer);
+        <String>::sse_encode(self.description, serializer);
+        <Vec<crate::api::explain::ScoreExplanation>>::sse_encode(self.details, serializer);
+        <Vec<String>>::sse_encode(self.context, serializer);
     }
 }
 
-impl SseEncode for String {
+impl SseEncode for crate::api::scoring::ScoreModifier {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
+        match self {
+            crate::api::scoring::ScoreModifier::DateDecay {
+                field,
+                function,
+                origin,
+                scale_days,
+                offset_days,
+                decay,
+            } => {
+                <i32>::sse_encode(0, serializer);
+                <String>::sse_encode(field, serializer);
+                <crate::api::scoring::DecayFunction>::sse_encode(function, serializer);
+                <Option<i64>>::sse_encode(origin, serializer);
+                <f64>::sse_encode(scale_days, serializer);
+                <f64>::sse_encode(offset_days, serializer);
+                <f64>::sse_encode(decay, serializer);
+            }
+            crate::api::scoring::ScoreModifier::LogBoost { field, factor } => {
+                <i32>::sse_encode(1, serializer);
+                <String>::sse_encode(field, serializer);
+                <f64>::sse_encode(factor, serializer);
+            }
+            crate::api::scoring::ScoreModifier::StaticRank { field, weight } => {
+                <i32>::sse_encode(2, serializer);
+                <String>::sse_encode(field, serializer);
+                <f64>::sse_encode(weight, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
     }
 }
 
-impl SseEncode for crate::api::search::DictionaryType {
+impl SseEncode for crate::api::search::SearchGroup {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(
-            match self {
-                crate::api::search::DictionaryType::Korean => 0,
-                crate::api::search::DictionaryType::JapaneseIpadic => 1,
-                crate::api::search::DictionaryType::JapaneseUnidic => 2,
-                crate::api::search::DictionaryType::Chinese => 3,
-                _ => {
-                    unimplemented!("");
-                }
-            },
-            serializer,
-        );
+        <Option<String>>::sse_encode(self.key, serializer);
+        <u64>::sse_encode(self.count, serializer);
+        <Vec<crate::api::search::SearchHit>>::sse_encode(self.hits, serializer);
     }
 }
 
-impl SseEncode for crate::api::search::DocumentInput {
+impl SseEncode for crate::api::search::SearchHit {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <String>::sse_encode(self.id, serializer);
         <String>::sse_encode(self.title, serializer);
         <String>::sse_encode(self.body, serializer);
+        <f32>::sse_encode(self.score, serializer);
         <String>::sse_encode(self.metadata, serializer);
+        <Option<crate::api::search::SearchSnippet>>::sse_encode(self.snippet, serializer);
+        <Vec<crate::api::sort::SortValue>>::sse_encode(self.sort_values, serializer);
+        <i64>::sse_encode(self.created_at, serializer);
+        <i64>::sse_encode(self.updated_at, serializer);
+        <Option<f64>>::sse_encode(self.distance_km, serializer);
     }
 }
 
-impl SseEncode for f32 {
+impl SseEncode for crate::api::search::SearchOptions {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_f32::<NativeEndian>(self).unwrap();
+        <usize>::sse_encode(self.limit, serializer);
+        <Option<crate::api::search::SnippetOptions>>::sse_encode(self.snippet, serializer);
+        <Option<crate::api::search::FuzzyOptions>>::sse_encode(self.fuzzy, serializer);
+        <Option<crate::api::search::FuzzyOptions>>::sse_encode(self.jamo_fuzzy, serializer);
+        <bool>::sse_encode(self.lenient, serializer);
+        <Vec<crate::api::sort::SortKey>>::sse_encode(self.sort, serializer);
+        <Vec<crate::api::aggregation::AggregationRequest>>::sse_encode(
+            self.aggregations,
+            serializer,
+        );
+        <Option<String>>::sse_encode(self.category_filter, serializer);
+        <Vec<String>>::sse_encode(self.category_counts, serializer);
+        <Option<crate::api::geo::GeoFilter>>::sse_encode(self.geo_filter, serializer);
+        <Option<String>>::sse_encode(self.collapse, serializer);
+        <Option<crate::api::group::GroupByOptions>>::sse_encode(self.group_by, serializer);
+        <Vec<crate::api::scoring::ScoreModifier>>::sse_encode(self.score_modifiers, serializer);
+        <crate::api::projection::FieldSelection>::sse_encode(self.fields, serializer);
+        <Option<usize>>::sse_encode(self.body_max_chars, serializer);
     }
 }
 
-impl SseEncode for i32 {
+impl SseEncode for crate::api::search::SearchResponse {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
+        <Vec<crate::api::search::SearchHit>>::sse_encode(self.hits, serializer);
+        <Vec<crate::api::search::QueryDiagnostic>>::sse_encode(self.diagnostics, serializer);
+        <Vec<crate::api::aggregation::AggregationResult>>::sse_encode(
+            self.aggregations,
+            serializer,
+        );
+        <Vec<crate::api::facet::CategoryCounts>>::sse_encode(self.category_counts, serializer);
+        <Vec<crate::api::search::SearchGroup>>::sse_encode(self.groups, serializer);
     }
 }
 
-impl SseEncode for Vec<String> {
+impl SseEncode for crate::api::search::SearchResult {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <String>::sse_encode(item, serializer);
-        }
+        <String>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.title, serializer);
+        <String>::sse_encode(self.body, serializer);
+        <f32>::sse_encode(self.score, serializer);
+        <String>::sse_encode(self.metadata, serializer);
+        <i64>::sse_encode(self.created_at, serializer);
+        <i64>::sse_encode(self.updated_at, serializer);
     }
 }
 
-impl SseEncode for Vec<crate::api::search::DocumentInput> {
+impl SseEncode for crate::api::search::SearchSnippet {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::search::DocumentInput>::sse_encode(item, serializer);
-        }
+        <String>::sse_encode(self.fragment, serializer);
+        <Vec<crate::api::search::HighlightRange>>::sse_encode(self.highlights, serializer);
+        <Option<String>>::sse_encode(self.html, serializer);
     }
 }
 
-impl SseEncode for Vec<u8> {
+impl SseEncode for crate::api::search::SnippetOptions {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <u8>::sse_encode(item, serializer);
-        }
+        <String>::sse_encode(self.field, serializer);
+        <usize>::sse_encode(self.max_num_chars, serializer);
+        <bool>::sse_encode(self.html, serializer);
     }
 }
 
-impl SseEncode for Vec<crate::api::search::SearchResult> {
+impl SseEncode for crate::api::sort::SortBy {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::search::SearchResult>::sse_encode(item, serializer);
+        match self {
+            crate::api::sort::SortBy::Score => {
+                <i32>::sse_encode(0, serializer);
+            }
+            crate::api::sort::SortBy::Field(field0) => {
+                <i32>::sse_encode(1, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::sort::SortBy::Distance(field0) => {
+                <i32>::sse_encode(2, serializer);
+                <crate::api::geo::GeoPoint>::sse_encode(field0, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
         }
     }
 }
 
-impl SseEncode for Vec<crate::api::search::TokenDetail> {
+impl SseEncode for crate::api::sort::SortKey {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::search::TokenDetail>::sse_encode(item, serializer);
+        <crate::api::sort::SortBy>::sse_encode(self.by, serializer);
+        <crate::api::sort::SortOrder>::sse_encode(self.order, serializer);
+    }
+}
+
+impl SseEncode for crate::api::sort::SortOrder {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::sort::SortOrder::Asc => 0,
+                crate::api::sort::SortOrder::Desc => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::sort::SortValue {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::sort::SortValue::Integer(field0) => {
+                <i32>::sse_encode(0, serializer);
+                <i64>::sse_encode(field0, serializer);
+            }
+            crate::api::sort::SortValue::Float(field0) => {
+                <i32>::sse_encode(1, serializer);
+                <f64>::sse_encode(field0, serializer);
+            }
+            crate::api::sort::SortValue::Text(field0) => {
+                <i32>::sse_encode(2, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::sort::SortValue::Missing => {
+                <i32>::sse_encode(3, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
         }
     }
 }
 
-impl SseEncode for crate::api::search::SearchResult {
+impl SseEncode for crate::api::search::StoredDocument {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <String>::sse_encode(self.id, serializer);
         <String>::sse_encode(self.title, serializer);
         <String>::sse_encode(self.body, serializer);
-        <f32>::sse_encode(self.score, serializer);
         <String>::sse_encode(self.metadata, serializer);
+        <i64>::sse_encode(self.created_at, serializer);
+        <i64>::sse_encode(self.updated_at, serializer);
+    }
+}
+
+impl SseEncode for crate::api::search::Suggestion {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.text, serializer);
+        <crate::api::search::SuggestionSource>::sse_encode(self.source, serializer);
+        <u64>::sse_encode(self.doc_count, serializer);
+    }
+}
+
+impl SseEncode for crate::api::search::SuggestionSource {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::search::SuggestionSource::Title => 0,
+                crate::api::search::SuggestionSource::Term => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
@@ -1095,6 +5189,13 @@ impl SseEncode for crate::api::search::TokenMode {
     }
 }
 
+impl SseEncode for u32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u32::<NativeEndian>(self).unwrap();
+    }
+}
+
 impl SseEncode for u64 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -1124,10 +5225,11 @@ impl SseEncode for usize {
     }
 }
 
-impl SseEncode for bool {
+impl SseEncode for crate::api::variant::VariantMapping {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_u8(self as _).unwrap();
+        <String>::sse_encode(self.variant, serializer);
+        <String>::sse_encode(self.canonical, serializer);
     }
 }
 
@@ -0,0 +1,107 @@
+#!/usr/bin/env python3
+# ko-dic(mecab-ko-dic) 의 Hanja.csv 에서 rust/src/api/hanja_readings.tsv 를 만듭니다
+#
+# 사용법: python3 rust/tools/hanja_readings.py mecab-ko-dic-2.1.1-20180720/Hanja.csv \
+#           > rust/src/api/hanja_readings.tsv
+#
+# Hanja.csv 한 줄: 표층형,왼쪽 문맥 ID,오른쪽 문맥 ID,비용,품사,의미 부류,종성 유무,읽기,...
+# - 한 글자 항목은 비용이 가장 낮은 읽기를 고릅니다. 그 읽기가 다른 읽기에 두음법칙을 적용한
+#   것이면 (예: 六 의 "육" 과 "륙") 본음을 고릅니다 (두음법칙은 검색 시 적용합니다)
+# - 여러 글자 항목은 한 글자씩 읽은 결과와 다를 때만 남깁니다 (예: 金浦 -> 김포)
+# - OVERRIDES 는 ko-dic 에 없거나 다르게 읽는 항목입니다
+
+import csv
+import sys
+
+SYLLABLE_START = 0xAC00
+SYLLABLE_END = 0xD7A3
+SYLLABLES_PER_CHOSUNG = 21 * 28
+NIEUN, RIEUL, IEUNG = 2, 5, 11
+# ㅑ ㅒ ㅕ ㅖ ㅛ ㅠ ㅣ
+Y_VOWELS = (2, 3, 6, 7, 12, 17, 20)
+
+OVERRIDES = {
+    "金浦": "김포",
+    "金海": "김해",
+    "金泉": "김천",
+}
+
+
+# korean.rs 의 initial_sound_rule 과 같은 두음법칙 (예: 리 -> 이, 녀 -> 여, 로 -> 노)
+def initial_sound_rule(c):
+    code = ord(c)
+    if not SYLLABLE_START <= code <= SYLLABLE_END:
+        return c
+    index = code - SYLLABLE_START
+    cho = index // SYLLABLES_PER_CHOSUNG
+    jung = index % SYLLABLES_PER_CHOSUNG // 28
+    if cho in (RIEUL, NIEUN) and jung in Y_VOWELS:
+        new_cho = IEUNG
+    elif cho == RIEUL:
+        new_cho = NIEUN
+    else:
+        return c
+    return chr(SYLLABLE_START + new_cho * SYLLABLES_PER_CHOSUNG + index % SYLLABLES_PER_CHOSUNG)
+
+
+def is_hanja(c):
+    code = ord(c)
+    return 0x3400 <= code <= 0x9FFF or 0xF900 <= code <= 0xFAFF or 0x20000 <= code <= 0x2FA1F
+
+
+def read_entries(path):
+    # 표층형 -> [(비용, 읽기)]
+    entries = {}
+    with open(path, encoding="utf-8", newline="") as f:
+        for row in csv.reader(f):
+            if len(row) < 8:
+                continue
+            surface, cost, reading = row[0], int(row[3]), row[7]
+            if not all(is_hanja(c) for c in surface) or reading == "*":
+                continue
+            if len(reading) != len(surface):
+                continue
+            entries.setdefault(surface, []).append((cost, reading))
+    return entries
+
+
+def choose_reading(candidates):
+    readings = [reading for _, reading in sorted(candidates)]
+    best = readings[0]
+    for reading in readings:
+        if reading != best and initial_sound_rule(reading[0]) + reading[1:] == best:
+            return reading
+    return best
+
+
+def main(path):
+    entries = read_entries(path)
+    chars = {
+        surface: choose_reading(candidates)
+        for surface, candidates in entries.items()
+        if len(surface) == 1
+    }
+    words = {}
+    for surface, candidates in entries.items():
+        if len(surface) == 1 or not all(c in chars for c in surface):
+            continue
+        reading = choose_reading(candidates)
+        if reading != "".join(chars[c] for c in surface):
+            words[surface] = reading
+    words.update(OVERRIDES)
+
+    out = sys.stdout
+    out.write("# 한자 -> 한글 독음 (본음, 두음법칙은 검색 시 적용합니다)\n")
+    out.write("# rust/tools/hanja_readings.py 로 ko-dic 의 Hanja.csv 에서 만든 표입니다 (직접 고치지 마세요)\n")
+    out.write(f"# 한자 {len(chars)}자, 한 글자씩 읽으면 독음이 달라지는 단어 {len(words)}개\n")
+    out.write("# 형식: 한자<TAB>독음, 여러 글자 항목은 한 글자씩 읽는 것보다 먼저 적용됩니다\n")
+    for surface in sorted(words):
+        out.write(f"{surface}\t{words[surface]}\n")
+    for surface in sorted(chars):
+        out.write(f"{surface}\t{chars[surface]}\n")
+
+
+if __name__ == "__main__":
+    if len(sys.argv) != 2:
+        sys.exit("usage: hanja_readings.py <mecab-ko-dic/Hanja.csv>")
+    main(sys.argv[1])